@@ -16,6 +16,8 @@ pub trait Cholesky_: Sized {
     fn inv_cholesky(l: MatrixLayout, uplo: UPLO, a: &mut [Self]) -> Result<()>;
 
     /// Wrapper of `*potrs`
+    ///
+    /// `b` is a column-major `n x nrhs` matrix overwritten by the solution.
     fn solve_cholesky(l: MatrixLayout, uplo: UPLO, a: &[Self], b: &mut [Self]) -> Result<()>;
 }
 
@@ -61,7 +63,11 @@ macro_rules! impl_cholesky {
                 b: &mut [Self],
             ) -> Result<()> {
                 let (n, _) = l.size();
-                let nrhs = 1;
+                if n == 0 {
+                    // Do nothing for empty matrices.
+                    return Ok(());
+                }
+                let nrhs = b.len() as i32 / n;
                 let mut info = 0;
                 if matches!(l, MatrixLayout::C { .. }) {
                     uplo = uplo.t();
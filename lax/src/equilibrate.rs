@@ -0,0 +1,127 @@
+//! Row and column scaling to equilibrate matrices
+
+use crate::{error::*, layout::*, *};
+use cauchy::*;
+use num_traits::Zero;
+
+/// Scale factors $R$, $C$ which make the largest element in each row and column of $RAC$ close to 1
+pub struct EquilibrateOutput<A: Scalar> {
+    /// Row scale factors
+    pub r: Vec<A::Real>,
+    /// Column scale factors
+    pub c: Vec<A::Real>,
+    /// Ratio of the smallest to the largest row scale factor
+    pub rowcnd: A::Real,
+    /// Ratio of the smallest to the largest column scale factor
+    pub colcnd: A::Real,
+    /// Absolute value of the largest matrix element
+    pub amax: A::Real,
+}
+
+/// Wraps `*geequ`, `*geequb` and `*poequ`
+pub trait Equilibrate_: Scalar + Sized {
+    /// Computes the row and column scale factors of a general `m x n` matrix
+    ///
+    /// Error
+    /// ------
+    /// - `LapackComputationalFailure { return_code }` when a row or a column of the matrix is exactly zero.
+    fn equilibrate(l: MatrixLayout, a: &[Self]) -> Result<EquilibrateOutput<Self>>;
+
+    /// Same as `equilibrate`, but the scale factors are restricted to powers of the radix
+    /// so that the scaling does not introduce any rounding error.
+    fn equilibrate_power_of_two(l: MatrixLayout, a: &[Self]) -> Result<EquilibrateOutput<Self>>;
+
+    /// Computes the symmetric scale factors $S$ of a Hermitian positive definite matrix from its diagonal,
+    /// so that $SAS$ has unit diagonal. `r` and `c` of the output are the same.
+    ///
+    /// Error
+    /// ------
+    /// - `LapackComputationalFailure { return_code }` when the diagonal element
+    ///   at `return_code - 1` is not positive.
+    fn equilibrate_hermitian(l: MatrixLayout, a: &[Self]) -> Result<EquilibrateOutput<Self>>;
+}
+
+macro_rules! impl_equilibrate {
+    ($scalar:ty, $geequ:path, $geequb:path, $poequ:path) => {
+        impl Equilibrate_ for $scalar {
+            fn equilibrate(l: MatrixLayout, a: &[Self]) -> Result<EquilibrateOutput<Self>> {
+                impl_equilibrate!(@general $geequ, l, a)
+            }
+
+            fn equilibrate_power_of_two(
+                l: MatrixLayout,
+                a: &[Self],
+            ) -> Result<EquilibrateOutput<Self>> {
+                impl_equilibrate!(@general $geequb, l, a)
+            }
+
+            fn equilibrate_hermitian(
+                l: MatrixLayout,
+                a: &[Self],
+            ) -> Result<EquilibrateOutput<Self>> {
+                let (n, _) = l.size();
+                let mut s = unsafe { vec_uninit(n as usize) };
+                let mut scond = [Self::Real::zero()];
+                let mut amax = Self::Real::zero();
+                let mut info = 0;
+                // Only the diagonal elements are used, thus the layout does not matter
+                unsafe { $poequ(n, a, l.lda(), &mut s, &mut scond, &mut amax, &mut info) };
+                info.as_lapack_result()?;
+                Ok(EquilibrateOutput {
+                    r: s.clone(),
+                    c: s,
+                    rowcnd: scond[0],
+                    colcnd: scond[0],
+                    amax,
+                })
+            }
+        }
+    };
+    (@general $geequ:path, $l:expr, $a:expr) => {{
+        // C-continuous matrix is regarded as the transposed F-continuous one,
+        // and its row and column factors are swapped.
+        let (m, n) = ($l.lda(), $l.len());
+        let mut r = unsafe { vec_uninit(m as usize) };
+        let mut c = unsafe { vec_uninit(n as usize) };
+        let mut rowcnd = Self::Real::zero();
+        let mut colcnd = Self::Real::zero();
+        let mut amax = Self::Real::zero();
+        let mut info = 0;
+        unsafe {
+            $geequ(
+                m,
+                n,
+                $a,
+                $l.lda(),
+                &mut r,
+                &mut c,
+                &mut rowcnd,
+                &mut colcnd,
+                &mut amax,
+                &mut info,
+            )
+        };
+        info.as_lapack_result()?;
+        Ok(match $l {
+            MatrixLayout::C { .. } => EquilibrateOutput {
+                r: c,
+                c: r,
+                rowcnd: colcnd,
+                colcnd: rowcnd,
+                amax,
+            },
+            MatrixLayout::F { .. } => EquilibrateOutput {
+                r,
+                c,
+                rowcnd,
+                colcnd,
+                amax,
+            },
+        })
+    }};
+}
+
+impl_equilibrate!(f64, lapack::dgeequ, lapack::dgeequb, lapack::dpoequ);
+impl_equilibrate!(f32, lapack::sgeequ, lapack::sgeequb, lapack::spoequ);
+impl_equilibrate!(c64, lapack::zgeequ, lapack::zgeequb, lapack::zpoequ);
+impl_equilibrate!(c32, lapack::cgeequ, lapack::cgeequb, lapack::cpoequ);
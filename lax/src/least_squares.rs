@@ -2,7 +2,7 @@
 
 use crate::{error::*, layout::*, *};
 use cauchy::*;
-use num_traits::{ToPrimitive, Zero};
+use num_traits::{Float, ToPrimitive, Zero};
 
 /// Result of LeastSquares
 pub struct LeastSquaresOutput<A: Scalar> {
@@ -165,3 +165,400 @@ impl_least_squares!(@real, f64, lapack::dgelsd);
 impl_least_squares!(@real, f32, lapack::sgelsd);
 impl_least_squares!(@complex, c64, lapack::zgelsd);
 impl_least_squares!(@complex, c32, lapack::cgelsd);
+
+/// Wraps `*gels` and `*gelsy`
+///
+/// As `least_squares_nrhs` of [LeastSquaresSvdDivideConquer_], `b` is a `max(m, n) x nrhs` matrix
+/// overwritten by the solution in its first `n` rows. `singular_values` of the output are not computed
+/// and left empty.
+pub trait LeastSquaresOrthogonal_: Scalar {
+    /// Solve the least squares problem of a full-rank matrix using QR decomposition,
+    /// or the minimum norm solution of the underdetermined system using LQ decomposition
+    /// if `m < n`.
+    ///
+    /// Error
+    /// ------
+    /// - `LapackComputationalFailure { return_code }` when the matrix does not have full rank
+    fn least_squares_qr(
+        a_layout: MatrixLayout,
+        a: &mut [Self],
+        b_layout: MatrixLayout,
+        b: &mut [Self],
+    ) -> Result<LeastSquaresOutput<Self>>;
+
+    /// Solve the minimum norm least squares problem of a possibly rank-deficient matrix
+    /// using complete orthogonal decomposition with column pivoting.
+    /// The effective rank is determined by the machine epsilon.
+    fn least_squares_complete_orthogonal(
+        a_layout: MatrixLayout,
+        a: &mut [Self],
+        b_layout: MatrixLayout,
+        b: &mut [Self],
+    ) -> Result<LeastSquaresOutput<Self>>;
+}
+
+/// Returns the F-continuous matrix for LAPACK, where C-continuous one is transposed into `buf`
+fn as_fortran<'a, T: Scalar>(
+    l: MatrixLayout,
+    a: &'a mut [T],
+    buf: &'a mut Vec<T>,
+) -> (MatrixLayout, &'a mut [T]) {
+    match l {
+        MatrixLayout::C { .. } => {
+            *buf = unsafe { vec_uninit(a.len()) };
+            let l = transpose(l, a, buf);
+            (l, buf)
+        }
+        MatrixLayout::F { .. } => (l, a),
+    }
+}
+
+macro_rules! impl_least_squares_orthogonal {
+    (@real, $scalar:ty, $gels:path, $gelsy:path) => {
+        impl_least_squares_orthogonal!(@body, $scalar, $gels, $gelsy, );
+    };
+    (@complex, $scalar:ty, $gels:path, $gelsy:path) => {
+        impl_least_squares_orthogonal!(@body, $scalar, $gels, $gelsy, rwork);
+    };
+
+    (@body, $scalar:ty, $gels:path, $gelsy:path, $($rwork:ident),*) => {
+        impl LeastSquaresOrthogonal_ for $scalar {
+            fn least_squares_qr(
+                a_layout: MatrixLayout,
+                a: &mut [Self],
+                b_layout: MatrixLayout,
+                b: &mut [Self],
+            ) -> Result<LeastSquaresOutput<Self>> {
+                let (m, n) = a_layout.size();
+                let (m_, nrhs) = b_layout.size();
+                assert!(m_ >= m);
+
+                let mut a_buf = Vec::new();
+                let mut b_buf = Vec::new();
+                let (a_layout, a_f) = as_fortran(a_layout, a, &mut a_buf);
+                let (b_layout_f, b_f) = as_fortran(b_layout, b, &mut b_buf);
+
+                // eval work size
+                let mut info = 0;
+                let mut work_size = [Self::zero()];
+                unsafe {
+                    $gels(
+                        b'N',
+                        m,
+                        n,
+                        nrhs,
+                        a_f,
+                        a_layout.lda(),
+                        b_f,
+                        b_layout_f.lda(),
+                        &mut work_size,
+                        -1,
+                        &mut info,
+                    )
+                };
+                info.as_lapack_result()?;
+
+                // calc
+                let lwork = work_size[0].to_usize().unwrap();
+                let mut work = unsafe { vec_uninit(lwork) };
+                unsafe {
+                    $gels(
+                        b'N',
+                        m,
+                        n,
+                        nrhs,
+                        a_f,
+                        a_layout.lda(),
+                        b_f,
+                        b_layout_f.lda(),
+                        &mut work,
+                        lwork as i32,
+                        &mut info,
+                    )
+                };
+                info.as_lapack_result()?;
+
+                // Skip a_t -> a transpose because A has been destroyed
+                // Re-transpose b
+                if let MatrixLayout::C { .. } = b_layout {
+                    transpose(b_layout_f, &b_buf, b);
+                }
+
+                Ok(LeastSquaresOutput {
+                    singular_values: Vec::new(),
+                    rank: m.min(n),
+                })
+            }
+
+            fn least_squares_complete_orthogonal(
+                a_layout: MatrixLayout,
+                a: &mut [Self],
+                b_layout: MatrixLayout,
+                b: &mut [Self],
+            ) -> Result<LeastSquaresOutput<Self>> {
+                let (m, n) = a_layout.size();
+                let (m_, nrhs) = b_layout.size();
+                assert!(m_ >= m);
+
+                let mut a_buf = Vec::new();
+                let mut b_buf = Vec::new();
+                let (a_layout, a_f) = as_fortran(a_layout, a, &mut a_buf);
+                let (b_layout_f, b_f) = as_fortran(b_layout, b, &mut b_buf);
+
+                // All columns are free to be pivoted
+                let mut jpvt = vec![0; n as usize];
+                let rcond = <Self::Real as Float>::epsilon();
+                let mut rank: i32 = 0;
+                $(
+                let mut $rwork: Vec<Self::Real> = unsafe { vec_uninit(2 * n as usize) };
+                )*
+
+                // eval work size
+                let mut info = 0;
+                let mut work_size = [Self::zero()];
+                unsafe {
+                    $gelsy(
+                        m,
+                        n,
+                        nrhs,
+                        a_f,
+                        a_layout.lda(),
+                        b_f,
+                        b_layout_f.lda(),
+                        &mut jpvt,
+                        rcond,
+                        &mut rank,
+                        &mut work_size,
+                        -1,
+                        $(&mut $rwork,)*
+                        &mut info,
+                    )
+                };
+                info.as_lapack_result()?;
+
+                // calc
+                let lwork = work_size[0].to_usize().unwrap();
+                let mut work = unsafe { vec_uninit(lwork) };
+                unsafe {
+                    $gelsy(
+                        m,
+                        n,
+                        nrhs,
+                        a_f,
+                        a_layout.lda(),
+                        b_f,
+                        b_layout_f.lda(),
+                        &mut jpvt,
+                        rcond,
+                        &mut rank,
+                        &mut work,
+                        lwork as i32,
+                        $(&mut $rwork,)*
+                        &mut info,
+                    )
+                };
+                info.as_lapack_result()?;
+
+                // Re-transpose b
+                if let MatrixLayout::C { .. } = b_layout {
+                    transpose(b_layout_f, &b_buf, b);
+                }
+
+                Ok(LeastSquaresOutput {
+                    singular_values: Vec::new(),
+                    rank,
+                })
+            }
+        }
+    };
+}
+
+impl_least_squares_orthogonal!(@real, f64, lapack::dgels, lapack::dgelsy);
+impl_least_squares_orthogonal!(@real, f32, lapack::sgels, lapack::sgelsy);
+impl_least_squares_orthogonal!(@complex, c64, lapack::zgels, lapack::zgelsy);
+impl_least_squares_orthogonal!(@complex, c32, lapack::cgels, lapack::cgelsy);
+
+/// Wraps `*gglse` and `*ggglm`
+pub trait LeastSquaresGeneralized_: Scalar {
+    /// Solve the linear equality-constrained least squares problem
+    ///
+    /// $$ \min_x \\| c - Ax \\|_2 \quad \text{subject to} \quad Bx = d $$
+    ///
+    /// where `A` is `m x n` and `B` is `p x n` with `p <= n <= m + p`.
+    /// The solution `x` is returned, and `c` is overwritten so that
+    /// the residual sum of squares is the sum of squares of `c[n - p..m]`.
+    ///
+    /// Error
+    /// ------
+    /// - `LapackComputationalFailure { return_code: 1 }` when `rank(B) < p`
+    /// - `LapackComputationalFailure { return_code: 2 }` when `rank([A; B]) < n`
+    fn least_squares_equality_constrained(
+        a_layout: MatrixLayout,
+        a: &mut [Self],
+        b_layout: MatrixLayout,
+        b: &mut [Self],
+        c: &mut [Self],
+        d: &mut [Self],
+    ) -> Result<Vec<Self>>;
+
+    /// Solve the general Gauss-Markov linear model problem
+    ///
+    /// $$ \min_{x, y} \\| y \\|_2 \quad \text{subject to} \quad d = Ax + By $$
+    ///
+    /// where `A` is `n x m` and `B` is `n x p` with `m <= n <= m + p`.
+    /// The solution `(x, y)` is returned.
+    ///
+    /// Error
+    /// ------
+    /// - `LapackComputationalFailure { return_code: 1 }` when `rank(A) < m`
+    /// - `LapackComputationalFailure { return_code: 2 }` when `rank([A, B]) < n`
+    fn generalized_linear_model(
+        a_layout: MatrixLayout,
+        a: &mut [Self],
+        b_layout: MatrixLayout,
+        b: &mut [Self],
+        d: &mut [Self],
+    ) -> Result<(Vec<Self>, Vec<Self>)>;
+}
+
+macro_rules! impl_least_squares_generalized {
+    ($scalar:ty, $gglse:path, $ggglm:path) => {
+        impl LeastSquaresGeneralized_ for $scalar {
+            fn least_squares_equality_constrained(
+                a_layout: MatrixLayout,
+                a: &mut [Self],
+                b_layout: MatrixLayout,
+                b: &mut [Self],
+                c: &mut [Self],
+                d: &mut [Self],
+            ) -> Result<Vec<Self>> {
+                let (m, n) = a_layout.size();
+                let (p, n_) = b_layout.size();
+                assert_eq!(n, n_);
+                assert_eq!(c.len() as i32, m);
+                assert_eq!(d.len() as i32, p);
+
+                let mut a_buf = Vec::new();
+                let mut b_buf = Vec::new();
+                let (a_layout, a) = as_fortran(a_layout, a, &mut a_buf);
+                let (b_layout, b) = as_fortran(b_layout, b, &mut b_buf);
+                let mut x = unsafe { vec_uninit(n as usize) };
+
+                // eval work size
+                let mut info = 0;
+                let mut work_size = [Self::zero()];
+                unsafe {
+                    $gglse(
+                        m,
+                        n,
+                        p,
+                        a,
+                        a_layout.lda(),
+                        b,
+                        b_layout.lda(),
+                        c,
+                        d,
+                        &mut x,
+                        &mut work_size,
+                        -1,
+                        &mut info,
+                    )
+                };
+                info.as_lapack_result()?;
+
+                // calc
+                let lwork = work_size[0].to_usize().unwrap();
+                let mut work = unsafe { vec_uninit(lwork) };
+                unsafe {
+                    $gglse(
+                        m,
+                        n,
+                        p,
+                        a,
+                        a_layout.lda(),
+                        b,
+                        b_layout.lda(),
+                        c,
+                        d,
+                        &mut x,
+                        &mut work,
+                        lwork as i32,
+                        &mut info,
+                    )
+                };
+                info.as_lapack_result()?;
+                Ok(x)
+            }
+
+            fn generalized_linear_model(
+                a_layout: MatrixLayout,
+                a: &mut [Self],
+                b_layout: MatrixLayout,
+                b: &mut [Self],
+                d: &mut [Self],
+            ) -> Result<(Vec<Self>, Vec<Self>)> {
+                let (n, m) = a_layout.size();
+                let (n_, p) = b_layout.size();
+                assert_eq!(n, n_);
+                assert_eq!(d.len() as i32, n);
+
+                let mut a_buf = Vec::new();
+                let mut b_buf = Vec::new();
+                let (a_layout, a) = as_fortran(a_layout, a, &mut a_buf);
+                let (b_layout, b) = as_fortran(b_layout, b, &mut b_buf);
+                let mut x = unsafe { vec_uninit(m as usize) };
+                let mut y = unsafe { vec_uninit(p as usize) };
+
+                // eval work size
+                let mut info = 0;
+                let mut work_size = [Self::zero()];
+                unsafe {
+                    $ggglm(
+                        n,
+                        m,
+                        p,
+                        a,
+                        a_layout.lda(),
+                        b,
+                        b_layout.lda(),
+                        d,
+                        &mut x,
+                        &mut y,
+                        &mut work_size,
+                        -1,
+                        &mut info,
+                    )
+                };
+                info.as_lapack_result()?;
+
+                // calc
+                let lwork = work_size[0].to_usize().unwrap();
+                let mut work = unsafe { vec_uninit(lwork) };
+                unsafe {
+                    $ggglm(
+                        n,
+                        m,
+                        p,
+                        a,
+                        a_layout.lda(),
+                        b,
+                        b_layout.lda(),
+                        d,
+                        &mut x,
+                        &mut y,
+                        &mut work,
+                        lwork as i32,
+                        &mut info,
+                    )
+                };
+                info.as_lapack_result()?;
+                Ok((x, y))
+            }
+        }
+    };
+}
+
+impl_least_squares_generalized!(f64, lapack::dgglse, lapack::dggglm);
+impl_least_squares_generalized!(f32, lapack::sgglse, lapack::sggglm);
+impl_least_squares_generalized!(c64, lapack::zgglse, lapack::zggglm);
+impl_least_squares_generalized!(c32, lapack::cgglse, lapack::cggglm);
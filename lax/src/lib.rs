@@ -28,6 +28,16 @@
 //! [solveh]: solveh/trait.Solveh_.html#tymethod.solveh
 //! [invh]:   solveh/trait.Solveh_.html#tymethod.invh
 //!
+//! The expert drivers [solve_expert], [solve_cholesky_expert] and [solveh_expert] solve the equation
+//! with iterative refinement and equilibration, and also return the error bounds of the solution.
+//!
+//! [solve_expert]:          solvex/trait.SolveExpert_.html#tymethod.solve_expert
+//! [solve_cholesky_expert]: solvex/trait.SolveExpert_.html#tymethod.solve_cholesky_expert
+//! [solveh_expert]:         solvex/trait.SolveExpert_.html#tymethod.solveh_expert
+//!
+//! For `f64` and `c64`, [MixedPrecision_] factorizes the matrix in single precision
+//! and refines the solution in double precision.
+//!
 //! Eigenvalue Problem
 //! -------------------
 //!
@@ -48,6 +58,12 @@
 //! [eigh]:             eigh/trait.Eigh_.html#tymethod.eigh
 //! [eigh_generalized]: eigh/trait.Eigh_.html#tymethod.eigh_generalized
 //!
+//! Schur decomposition $A = Z T Z^\dagger$ of a general matrix is computed by [schur],
+//! and it can be reordered by [schur_reorder].
+//!
+//! [schur]:         schur/trait.Schur_.html#tymethod.schur
+//! [schur_reorder]: schur/trait.Schur_.html#tymethod.schur_reorder
+//!
 //! Singular Value Decomposition (SVD), Least square problem
 //! ----------------------------------------------------------
 //!
@@ -58,6 +74,19 @@
 //! [svd]:   svd/trait.SVD_.html#tymethod.svd
 //! [svddc]: svddck/trait.SVDDC_.html#tymethod.svddc
 //! [least_squares]: least_squares/trait.LeastSquaresSvdDivideConquer_.html#tymethod.least_squares
+//!
+//! The least square problem is also solved by QR (or LQ) decomposition using [least_squares_qr]
+//! for full-rank matrices, and by complete orthogonal decomposition using
+//! [least_squares_complete_orthogonal] for rank-deficient matrices.
+//!
+//! [least_squares_qr]: least_squares/trait.LeastSquaresOrthogonal_.html#tymethod.least_squares_qr
+//! [least_squares_complete_orthogonal]: least_squares/trait.LeastSquaresOrthogonal_.html#tymethod.least_squares_complete_orthogonal
+//!
+//! The equality-constrained least square problem and the general Gauss-Markov linear model
+//! are solved by [least_squares_equality_constrained] and [generalized_linear_model].
+//!
+//! [least_squares_equality_constrained]: least_squares/trait.LeastSquaresGeneralized_.html#tymethod.least_squares_equality_constrained
+//! [generalized_linear_model]: least_squares/trait.LeastSquaresGeneralized_.html#tymethod.generalized_linear_model
 
 #[cfg(any(feature = "intel-mkl-system", feature = "intel-mkl-static"))]
 extern crate intel_mkl_src as _src;
@@ -74,12 +103,16 @@ pub mod layout;
 mod cholesky;
 mod eig;
 mod eigh;
+mod equilibrate;
 mod least_squares;
+mod mixed_precision;
 mod opnorm;
 mod qr;
 mod rcond;
+mod schur;
 mod solve;
 mod solveh;
+mod solvex;
 mod svd;
 mod svddc;
 mod triangular;
@@ -88,12 +121,16 @@ mod tridiagonal;
 pub use self::cholesky::*;
 pub use self::eig::*;
 pub use self::eigh::*;
+pub use self::equilibrate::*;
 pub use self::least_squares::*;
+pub use self::mixed_precision::*;
 pub use self::opnorm::*;
 pub use self::qr::*;
 pub use self::rcond::*;
+pub use self::schur::*;
 pub use self::solve::*;
 pub use self::solveh::*;
+pub use self::solvex::*;
 pub use self::svd::*;
 pub use self::svddc::*;
 pub use self::triangular::*;
@@ -111,13 +148,18 @@ pub trait Lapack:
     + SVDDC_
     + Solve_
     + Solveh_
+    + SolveExpert_
+    + Equilibrate_
     + Cholesky_
     + Eig_
     + Eigh_
+    + Schur_
     + Triangular_
     + Tridiagonal_
     + Rcond_
     + LeastSquaresSvdDivideConquer_
+    + LeastSquaresOrthogonal_
+    + LeastSquaresGeneralized_
 {
 }
 
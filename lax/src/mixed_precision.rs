@@ -0,0 +1,131 @@
+//! Mixed-precision iterative refinement for linear equations
+
+use crate::{error::*, layout::*, solvex::*, *};
+use cauchy::*;
+
+/// Wraps `dsgesv`/`zcgesv` and `dsposv`/`zcposv`
+///
+/// These drivers factorize the matrix in single precision, and refine the solution
+/// by the iterative refinement in double precision. When the refinement does not converge,
+/// the matrix is factorized again in double precision and the equation is solved directly.
+///
+/// `b` is overwritten by the solution, and the number of iterations is returned as `iter` of LAPACK:
+///
+/// - `iter > 0`: the refinement converged in `iter` iterations
+/// - `iter < 0`: fell back to double precision factorization, where
+///   - `-1`: the single precision is not worth working with
+///   - `-2`: overflow in converting the matrix into single precision
+///   - `-3`: failure of the single precision factorization
+///   - `-31`: the refinement did not converge in 30 iterations
+///
+/// Error
+/// ------
+/// - `LapackComputationalFailure { return_code }` when the matrix is exactly singular
+///   (or not positive definite for `solve_cholesky_mixed_precision`) in double precision
+pub trait MixedPrecision_: Scalar + Sized {
+    /// Solve a general linear equation using LU decomposition
+    fn solve_mixed_precision(
+        a_layout: MatrixLayout,
+        a: &[Self],
+        b_layout: MatrixLayout,
+        b: &mut [Self],
+    ) -> Result<i32>;
+
+    /// Solve a positive-definite Hermitian linear equation using Cholesky decomposition
+    fn solve_cholesky_mixed_precision(
+        a_layout: MatrixLayout,
+        uplo: UPLO,
+        a: &[Self],
+        b_layout: MatrixLayout,
+        b: &mut [Self],
+    ) -> Result<i32>;
+}
+
+macro_rules! impl_mixed_precision {
+    ($scalar:ty, $gesv:path, $posv:path $(, $rwork:ident)?) => {
+        impl MixedPrecision_ for $scalar {
+            fn solve_mixed_precision(
+                a_layout: MatrixLayout,
+                a: &[Self],
+                b_layout: MatrixLayout,
+                b: &mut [Self],
+            ) -> Result<i32> {
+                let (n, _) = a_layout.size();
+                let (_, nrhs) = b_layout.size();
+                let (a_layout, mut a) = to_f(a_layout, a);
+                let (x_layout, b_f) = to_f(b_layout, b);
+                let mut ipiv = unsafe { vec_uninit(n as usize) };
+                let mut x = unsafe { vec_uninit(b_f.len()) };
+                let mut work = unsafe { vec_uninit((n * nrhs) as usize) };
+                let mut swork = unsafe { vec_uninit((n * (n + nrhs)) as usize) };
+                $(let mut $rwork = unsafe { vec_uninit(n as usize) };)*
+                let mut iter = 0;
+                let mut info = 0;
+                unsafe {
+                    $gesv(
+                        n,
+                        nrhs,
+                        &mut a,
+                        a_layout.lda(),
+                        &mut ipiv,
+                        &b_f,
+                        x_layout.lda(),
+                        &mut x,
+                        x_layout.lda(),
+                        &mut work,
+                        &mut swork,
+                        $(&mut $rwork,)*
+                        &mut iter,
+                        &mut info,
+                    )
+                };
+                info.as_lapack_result()?;
+                write_back(b_layout, x_layout, &x, b);
+                Ok(iter)
+            }
+
+            fn solve_cholesky_mixed_precision(
+                a_layout: MatrixLayout,
+                uplo: UPLO,
+                a: &[Self],
+                b_layout: MatrixLayout,
+                b: &mut [Self],
+            ) -> Result<i32> {
+                let (n, _) = a_layout.size();
+                let (_, nrhs) = b_layout.size();
+                let (a_layout, mut a) = to_f(a_layout, a);
+                let (x_layout, b_f) = to_f(b_layout, b);
+                let mut x = unsafe { vec_uninit(b_f.len()) };
+                let mut work = unsafe { vec_uninit((n * nrhs) as usize) };
+                let mut swork = unsafe { vec_uninit((n * (n + nrhs)) as usize) };
+                $(let mut $rwork = unsafe { vec_uninit(n as usize) };)*
+                let mut iter = 0;
+                let mut info = 0;
+                unsafe {
+                    $posv(
+                        uplo as u8,
+                        n,
+                        nrhs,
+                        &mut a,
+                        a_layout.lda(),
+                        &b_f,
+                        x_layout.lda(),
+                        &mut x,
+                        x_layout.lda(),
+                        &mut work,
+                        &mut swork,
+                        $(&mut $rwork,)*
+                        &mut iter,
+                        &mut info,
+                    )
+                };
+                info.as_lapack_result()?;
+                write_back(b_layout, x_layout, &x, b);
+                Ok(iter)
+            }
+        }
+    };
+}
+
+impl_mixed_precision!(f64, lapack::dsgesv, lapack::dsposv);
+impl_mixed_precision!(c64, lapack::zcgesv, lapack::zcposv, rwork);
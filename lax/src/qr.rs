@@ -11,6 +11,16 @@ pub trait QR_: Sized {
     /// this will call LQ-decomposition of the transposed matrix $ A^T = LQ^T $
     fn householder(l: MatrixLayout, a: &mut [Self]) -> Result<Vec<Self>>;
 
+    /// Execute Householder reflection with column pivoting $ AP = QR $
+    ///
+    /// The diagonal elements of $R$ are non-increasing in absolute value.
+    /// Returns the scalar factors of reflectors and the pivot indices,
+    /// where the `i`-th column of $AP$ is the `(pivot[i] - 1)`-th column of $A$.
+    ///
+    /// For C-continuous array,
+    /// this will call the row-pivoted LQ-decomposition $ P^T A = LQ $
+    fn householder_pivoted(l: MatrixLayout, a: &mut [Self]) -> Result<(Vec<Self>, Pivot)>;
+
     /// Reconstruct Q-matrix from Householder-reflectors
     fn q(l: MatrixLayout, a: &mut [Self], tau: &[Self]) -> Result<()>;
 
@@ -19,7 +29,7 @@ pub trait QR_: Sized {
 }
 
 macro_rules! impl_qr {
-    ($scalar:ty, $qrf:path, $lqf:path, $gqr:path, $glq:path) => {
+    ($scalar:ty, $qrf:path, $lqf:path, $gqr:path, $glq:path, $qp3:path $(, $rwork:ident)?) => {
         impl QR_ for $scalar {
             fn householder(l: MatrixLayout, mut a: &mut [Self]) -> Result<Vec<Self>> {
                 let m = l.lda();
@@ -78,6 +88,58 @@ macro_rules! impl_qr {
                 Ok(tau)
             }
 
+            fn householder_pivoted(l: MatrixLayout, a: &mut [Self]) -> Result<(Vec<Self>, Pivot)> {
+                let m = l.lda();
+                let n = l.len();
+                let k = m.min(n);
+                let mut tau = unsafe { vec_uninit(k as usize) };
+                // zero means the column is free to be pivoted
+                let mut jpvt = vec![0; n as usize];
+                $(
+                let mut $rwork = unsafe { vec_uninit(2 * n as usize) };
+                )?
+
+                // eval work size
+                let mut info = 0;
+                let mut work_size = [Self::zero()];
+                unsafe {
+                    $qp3(
+                        m,
+                        n,
+                        a,
+                        m,
+                        &mut jpvt,
+                        &mut tau,
+                        &mut work_size,
+                        -1,
+                        $(&mut $rwork,)?
+                        &mut info,
+                    );
+                }
+                info.as_lapack_result()?;
+
+                // calc
+                let lwork = work_size[0].to_usize().unwrap();
+                let mut work = unsafe { vec_uninit(lwork) };
+                unsafe {
+                    $qp3(
+                        m,
+                        n,
+                        a,
+                        m,
+                        &mut jpvt,
+                        &mut tau,
+                        &mut work,
+                        lwork as i32,
+                        $(&mut $rwork,)?
+                        &mut info,
+                    );
+                }
+                info.as_lapack_result()?;
+
+                Ok((tau, jpvt))
+            }
+
             fn q(l: MatrixLayout, mut a: &mut [Self], tau: &[Self]) -> Result<()> {
                 let m = l.lda();
                 let n = l.len();
@@ -130,26 +192,32 @@ impl_qr!(
     lapack::dgeqrf,
     lapack::dgelqf,
     lapack::dorgqr,
-    lapack::dorglq
+    lapack::dorglq,
+    lapack::dgeqp3
 );
 impl_qr!(
     f32,
     lapack::sgeqrf,
     lapack::sgelqf,
     lapack::sorgqr,
-    lapack::sorglq
+    lapack::sorglq,
+    lapack::sgeqp3
 );
 impl_qr!(
     c64,
     lapack::zgeqrf,
     lapack::zgelqf,
     lapack::zungqr,
-    lapack::zunglq
+    lapack::zunglq,
+    lapack::zgeqp3,
+    rwork
 );
 impl_qr!(
     c32,
     lapack::cgeqrf,
     lapack::cgelqf,
     lapack::cungqr,
-    lapack::cunglq
+    lapack::cunglq,
+    lapack::cgeqp3,
+    rwork
 );
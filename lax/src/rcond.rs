@@ -7,10 +7,37 @@ pub trait Rcond_: Scalar + Sized {
     ///
     /// `anorm` should be the 1-norm of the matrix `a`.
     fn rcond(l: MatrixLayout, a: &[Self], anorm: Self::Real) -> Result<Self::Real>;
+
+    /// Estimates the reciprocal of the condition number of the Hermitian positive-definite matrix
+    /// in 1-norm from its Cholesky factor computed by [Cholesky_::cholesky] (wrapper of `*pocon`).
+    ///
+    /// `anorm` should be the 1-norm of the original matrix.
+    fn rcond_cholesky(
+        l: MatrixLayout,
+        uplo: UPLO,
+        a: &[Self],
+        anorm: Self::Real,
+    ) -> Result<Self::Real>;
+
+    /// Estimates the reciprocal of the condition number of the Hermitian matrix
+    /// in 1-norm from its Bunch-Kaufman factor computed by [Solveh_::bk] (wrapper of `*sycon` and `*hecon`).
+    ///
+    /// `anorm` should be the 1-norm of the original matrix.
+    fn rcond_bk(
+        l: MatrixLayout,
+        uplo: UPLO,
+        a: &[Self],
+        ipiv: &Pivot,
+        anorm: Self::Real,
+    ) -> Result<Self::Real>;
+
+    /// Estimates the reciprocal of the condition number of the triangular matrix in 1-norm
+    /// (wrapper of `*trcon`).
+    fn rcond_triangular(l: MatrixLayout, uplo: UPLO, diag: Diag, a: &[Self]) -> Result<Self::Real>;
 }
 
 macro_rules! impl_rcond_real {
-    ($scalar:ty, $gecon:path) => {
+    ($scalar:ty, $gecon:path, $pocon:path, $sycon:path, $trcon:path) => {
         impl Rcond_ for $scalar {
             fn rcond(l: MatrixLayout, a: &[Self], anorm: Self::Real) -> Result<Self::Real> {
                 let (n, _) = l.size();
@@ -40,15 +67,124 @@ macro_rules! impl_rcond_real {
 
                 Ok(rcond)
             }
+
+            fn rcond_cholesky(
+                l: MatrixLayout,
+                uplo: UPLO,
+                a: &[Self],
+                anorm: Self::Real,
+            ) -> Result<Self::Real> {
+                let (n, _) = l.size();
+                let mut rcond = Self::Real::zero();
+                let mut info = 0;
+                let mut work = unsafe { vec_uninit(3 * n as usize) };
+                let mut iwork = unsafe { vec_uninit(n as usize) };
+                // The factor of C-continuous array is stored as its transpose (see Cholesky_::cholesky)
+                let uplo = match l {
+                    MatrixLayout::C { .. } => uplo.t(),
+                    MatrixLayout::F { .. } => uplo,
+                };
+                unsafe {
+                    $pocon(
+                        uplo as u8,
+                        n,
+                        a,
+                        l.lda(),
+                        anorm,
+                        &mut rcond,
+                        &mut work,
+                        &mut iwork,
+                        &mut info,
+                    )
+                };
+                info.as_lapack_result()?;
+                Ok(rcond)
+            }
+
+            fn rcond_bk(
+                l: MatrixLayout,
+                uplo: UPLO,
+                a: &[Self],
+                ipiv: &Pivot,
+                anorm: Self::Real,
+            ) -> Result<Self::Real> {
+                let (n, _) = l.size();
+                let mut rcond = Self::Real::zero();
+                let mut info = 0;
+                let mut work = unsafe { vec_uninit(2 * n as usize) };
+                let mut iwork = unsafe { vec_uninit(n as usize) };
+                unsafe {
+                    $sycon(
+                        uplo as u8,
+                        n,
+                        a,
+                        l.lda(),
+                        ipiv,
+                        anorm,
+                        &mut rcond,
+                        &mut work,
+                        &mut iwork,
+                        &mut info,
+                    )
+                };
+                info.as_lapack_result()?;
+                Ok(rcond)
+            }
+
+            fn rcond_triangular(
+                l: MatrixLayout,
+                uplo: UPLO,
+                diag: Diag,
+                a: &[Self],
+            ) -> Result<Self::Real> {
+                let (n, _) = l.size();
+                let mut rcond = Self::Real::zero();
+                let mut info = 0;
+                let mut work = unsafe { vec_uninit(3 * n as usize) };
+                let mut iwork = unsafe { vec_uninit(n as usize) };
+                // C-continuous array is regarded as the transposed F-continuous array
+                let (norm_type, uplo) = match l {
+                    MatrixLayout::C { .. } => (NormType::Infinity, uplo.t()),
+                    MatrixLayout::F { .. } => (NormType::One, uplo),
+                };
+                unsafe {
+                    $trcon(
+                        norm_type as u8,
+                        uplo as u8,
+                        diag as u8,
+                        n,
+                        a,
+                        l.lda(),
+                        &mut rcond,
+                        &mut work,
+                        &mut iwork,
+                        &mut info,
+                    )
+                };
+                info.as_lapack_result()?;
+                Ok(rcond)
+            }
         }
     };
 }
 
-impl_rcond_real!(f32, lapack::sgecon);
-impl_rcond_real!(f64, lapack::dgecon);
+impl_rcond_real!(
+    f32,
+    lapack::sgecon,
+    lapack::spocon,
+    lapack::ssycon,
+    lapack::strcon
+);
+impl_rcond_real!(
+    f64,
+    lapack::dgecon,
+    lapack::dpocon,
+    lapack::dsycon,
+    lapack::dtrcon
+);
 
 macro_rules! impl_rcond_complex {
-    ($scalar:ty, $gecon:path) => {
+    ($scalar:ty, $gecon:path, $pocon:path, $hecon:path, $trcon:path) => {
         impl Rcond_ for $scalar {
             fn rcond(l: MatrixLayout, a: &[Self], anorm: Self::Real) -> Result<Self::Real> {
                 let (n, _) = l.size();
@@ -77,9 +213,116 @@ macro_rules! impl_rcond_complex {
 
                 Ok(rcond)
             }
+
+            fn rcond_cholesky(
+                l: MatrixLayout,
+                uplo: UPLO,
+                a: &[Self],
+                anorm: Self::Real,
+            ) -> Result<Self::Real> {
+                let (n, _) = l.size();
+                let mut rcond = Self::Real::zero();
+                let mut info = 0;
+                let mut work = unsafe { vec_uninit(2 * n as usize) };
+                let mut rwork = unsafe { vec_uninit(n as usize) };
+                // The factor of C-continuous array is stored as its transpose (see Cholesky_::cholesky)
+                let uplo = match l {
+                    MatrixLayout::C { .. } => uplo.t(),
+                    MatrixLayout::F { .. } => uplo,
+                };
+                unsafe {
+                    $pocon(
+                        uplo as u8,
+                        n,
+                        a,
+                        l.lda(),
+                        anorm,
+                        &mut rcond,
+                        &mut work,
+                        &mut rwork,
+                        &mut info,
+                    )
+                };
+                info.as_lapack_result()?;
+                Ok(rcond)
+            }
+
+            fn rcond_bk(
+                l: MatrixLayout,
+                uplo: UPLO,
+                a: &[Self],
+                ipiv: &Pivot,
+                anorm: Self::Real,
+            ) -> Result<Self::Real> {
+                let (n, _) = l.size();
+                let mut rcond = Self::Real::zero();
+                let mut info = 0;
+                let mut work = unsafe { vec_uninit(2 * n as usize) };
+                unsafe {
+                    $hecon(
+                        uplo as u8,
+                        n,
+                        a,
+                        l.lda(),
+                        ipiv,
+                        anorm,
+                        &mut rcond,
+                        &mut work,
+                        &mut info,
+                    )
+                };
+                info.as_lapack_result()?;
+                Ok(rcond)
+            }
+
+            fn rcond_triangular(
+                l: MatrixLayout,
+                uplo: UPLO,
+                diag: Diag,
+                a: &[Self],
+            ) -> Result<Self::Real> {
+                let (n, _) = l.size();
+                let mut rcond = Self::Real::zero();
+                let mut info = 0;
+                let mut work = unsafe { vec_uninit(2 * n as usize) };
+                let mut rwork = unsafe { vec_uninit(n as usize) };
+                // C-continuous array is regarded as the transposed F-continuous array
+                let (norm_type, uplo) = match l {
+                    MatrixLayout::C { .. } => (NormType::Infinity, uplo.t()),
+                    MatrixLayout::F { .. } => (NormType::One, uplo),
+                };
+                unsafe {
+                    $trcon(
+                        norm_type as u8,
+                        uplo as u8,
+                        diag as u8,
+                        n,
+                        a,
+                        l.lda(),
+                        &mut rcond,
+                        &mut work,
+                        &mut rwork,
+                        &mut info,
+                    )
+                };
+                info.as_lapack_result()?;
+                Ok(rcond)
+            }
         }
     };
 }
 
-impl_rcond_complex!(c32, lapack::cgecon);
-impl_rcond_complex!(c64, lapack::zgecon);
+impl_rcond_complex!(
+    c32,
+    lapack::cgecon,
+    lapack::cpocon,
+    lapack::checon,
+    lapack::ctrcon
+);
+impl_rcond_complex!(
+    c64,
+    lapack::zgecon,
+    lapack::zpocon,
+    lapack::zhecon,
+    lapack::ztrcon
+);
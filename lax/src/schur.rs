@@ -0,0 +1,361 @@
+//! Schur decomposition for general matrices
+
+use crate::{error::*, layout::*, *};
+use cauchy::*;
+use num_traits::{ToPrimitive, Zero};
+
+/// Result of Schur decomposition
+pub struct SchurOutput<A: Scalar> {
+    /// Eigenvalues of the matrix
+    pub eigs: Vec<A::Complex>,
+    /// Unitary matrix `Z` of the Schur vectors, in the same layout as the input
+    pub z: Vec<A>,
+}
+
+/// Wraps `*gees`, `*trexc` and `*trsyl`
+pub trait Schur_: Scalar {
+    /// Computes the Schur decomposition $ A = Z T Z^\dagger $
+    ///
+    /// `a` is overwritten by `T`, which is upper triangular for complex matrices.
+    /// For real matrices, `T` is quasi-upper triangular, i.e. the complex
+    /// conjugate pairs of eigenvalues appear as 2x2 blocks on its diagonal.
+    fn schur(l: MatrixLayout, a: &mut [Self]) -> Result<SchurOutput<Self>>;
+
+    /// Reorders the Schur decomposition so that the diagonal element (or block)
+    /// of `T` at the row `ifst` is moved to the row `ilst`.
+    ///
+    /// `t` and `z` are updated so that $ Z T Z^\dagger $ is kept unchanged.
+    /// The indices are zero-based.
+    fn schur_reorder(
+        l: MatrixLayout,
+        t: &mut [Self],
+        z: &mut [Self],
+        ifst: usize,
+        ilst: usize,
+    ) -> Result<()>;
+
+    /// Solves the Sylvester equation $ AX - XB = \alpha C $ for the
+    /// (quasi-)upper triangular matrices `a` and `b`
+    ///
+    /// `c` is overwritten by `X`, and the scale factor $ \alpha \le 1 $
+    /// chosen to avoid overflow is returned.
+    ///
+    /// Error
+    /// ------
+    /// - `LapackComputationalFailure { return_code: 1 }` when `a` and `b` have
+    ///   common or very close eigenvalues. `X` is still computed using
+    ///   slightly perturbed values in this case.
+    fn sylvester_triangular(
+        a_layout: MatrixLayout,
+        a: &[Self],
+        b_layout: MatrixLayout,
+        b: &[Self],
+        c_layout: MatrixLayout,
+        c: &mut [Self],
+    ) -> Result<Self::Real>;
+}
+
+/// Copy a C-continuous matrix into a F-continuous buffer
+fn into_f<T: Scalar>(l: MatrixLayout, a: &[T]) -> Option<(MatrixLayout, Vec<T>)> {
+    match l {
+        MatrixLayout::C { .. } => {
+            let mut a_t = unsafe { vec_uninit(a.len()) };
+            let l = transpose(l, a, &mut a_t);
+            Some((l, a_t))
+        }
+        MatrixLayout::F { .. } => None,
+    }
+}
+
+macro_rules! impl_schur_common {
+    ($trexc:path, $trsyl:path) => {
+        fn sylvester_triangular(
+            a_layout: MatrixLayout,
+            a: &[Self],
+            b_layout: MatrixLayout,
+            b: &[Self],
+            c_layout: MatrixLayout,
+            c: &mut [Self],
+        ) -> Result<Self::Real> {
+            let a_t = into_f(a_layout, a);
+            let b_t = into_f(b_layout, b);
+            let mut c_t = into_f(c_layout, c);
+            let a_layout = a_t.as_ref().map(|(l, _)| *l).unwrap_or(a_layout);
+            let b_layout = b_t.as_ref().map(|(l, _)| *l).unwrap_or(b_layout);
+            let c_f_layout = c_t.as_ref().map(|(l, _)| *l).unwrap_or(c_layout);
+
+            let (m, _) = a_layout.size();
+            let (n, _) = b_layout.size();
+            assert_eq!(c_f_layout.size(), (m, n));
+
+            let mut scale = [Self::Real::zero()];
+            let mut info = 0;
+            unsafe {
+                $trsyl(
+                    Transpose::No as u8,
+                    Transpose::No as u8,
+                    &[-1],
+                    m,
+                    n,
+                    a_t.as_ref().map(|(_, v)| v.as_slice()).unwrap_or(a),
+                    a_layout.lda(),
+                    b_t.as_ref().map(|(_, v)| v.as_slice()).unwrap_or(b),
+                    b_layout.lda(),
+                    c_t.as_mut().map(|(_, v)| v.as_mut_slice()).unwrap_or(c),
+                    c_f_layout.lda(),
+                    &mut scale,
+                    &mut info,
+                )
+            };
+
+            // Re-transpose c, even if `info == 1` since `X` is computed
+            if let Some((l, c_t)) = c_t {
+                transpose(l, &c_t, c);
+            }
+            info.as_lapack_result()?;
+            Ok(scale[0])
+        }
+    };
+}
+
+macro_rules! impl_schur_real {
+    ($scalar:ty, $gees:path, $trexc:path, $trsyl:path) => {
+        impl Schur_ for $scalar {
+            fn schur(l: MatrixLayout, a: &mut [Self]) -> Result<SchurOutput<Self>> {
+                let (n, _) = l.size();
+                let mut a_t = into_f(l, a);
+                let f_layout = a_t.as_ref().map(|(l, _)| *l).unwrap_or(l);
+
+                let mut sdim = 0;
+                let mut wr = unsafe { vec_uninit(n as usize) };
+                let mut wi = unsafe { vec_uninit(n as usize) };
+                let mut z = unsafe { vec_uninit((n * n) as usize) };
+
+                // calc work size
+                let mut info = 0;
+                let mut work_size = [Self::zero()];
+                unsafe {
+                    $gees(
+                        b'V',
+                        b'N',
+                        None,
+                        n,
+                        a_t.as_mut().map(|(_, v)| v.as_mut_slice()).unwrap_or(a),
+                        f_layout.lda(),
+                        &mut sdim,
+                        &mut wr,
+                        &mut wi,
+                        &mut z,
+                        n,
+                        &mut work_size,
+                        -1,
+                        &mut [],
+                        &mut info,
+                    )
+                };
+                info.as_lapack_result()?;
+
+                // actual
+                let lwork = work_size[0].to_usize().unwrap();
+                let mut work = unsafe { vec_uninit(lwork) };
+                unsafe {
+                    $gees(
+                        b'V',
+                        b'N',
+                        None,
+                        n,
+                        a_t.as_mut().map(|(_, v)| v.as_mut_slice()).unwrap_or(a),
+                        f_layout.lda(),
+                        &mut sdim,
+                        &mut wr,
+                        &mut wi,
+                        &mut z,
+                        n,
+                        &mut work,
+                        lwork as i32,
+                        &mut [],
+                        &mut info,
+                    )
+                };
+                info.as_lapack_result()?;
+
+                // Re-transpose T and Z into the original layout
+                if let Some((f_layout, a_t)) = a_t {
+                    transpose(f_layout, &a_t, a);
+                    let z_f = z.clone();
+                    transpose(f_layout, &z_f, &mut z);
+                }
+
+                let eigs = wr
+                    .iter()
+                    .zip(wi.iter())
+                    .map(|(&re, &im)| Self::complex(re, im))
+                    .collect();
+                Ok(SchurOutput { eigs, z })
+            }
+
+            fn schur_reorder(
+                l: MatrixLayout,
+                t: &mut [Self],
+                z: &mut [Self],
+                ifst: usize,
+                ilst: usize,
+            ) -> Result<()> {
+                let (n, _) = l.size();
+                let mut t_t = into_f(l, t);
+                let mut z_t = into_f(l, z);
+                let f_layout = t_t.as_ref().map(|(l, _)| *l).unwrap_or(l);
+
+                // `*trexc` may move the indices to the first row of 2x2 blocks
+                let ifst = [ifst as i32 + 1];
+                let mut ilst = [ilst as i32 + 1];
+                let mut work = unsafe { vec_uninit(n as usize) };
+                let mut info = 0;
+                unsafe {
+                    $trexc(
+                        b'V',
+                        n,
+                        t_t.as_mut().map(|(_, v)| v.as_mut_slice()).unwrap_or(t),
+                        f_layout.lda(),
+                        z_t.as_mut().map(|(_, v)| v.as_mut_slice()).unwrap_or(z),
+                        f_layout.lda(),
+                        &ifst,
+                        &mut ilst,
+                        &mut work,
+                        &mut info,
+                    )
+                };
+                info.as_lapack_result()?;
+
+                if let Some((f_layout, t_t)) = t_t {
+                    transpose(f_layout, &t_t, t);
+                }
+                if let Some((f_layout, z_t)) = z_t {
+                    transpose(f_layout, &z_t, z);
+                }
+                Ok(())
+            }
+
+            impl_schur_common!($trexc, $trsyl);
+        }
+    };
+}
+
+impl_schur_real!(f64, lapack::dgees, lapack::dtrexc, lapack::dtrsyl);
+impl_schur_real!(f32, lapack::sgees, lapack::strexc, lapack::strsyl);
+
+macro_rules! impl_schur_complex {
+    ($scalar:ty, $gees:path, $trexc:path, $trsyl:path) => {
+        impl Schur_ for $scalar {
+            fn schur(l: MatrixLayout, a: &mut [Self]) -> Result<SchurOutput<Self>> {
+                let (n, _) = l.size();
+                let mut a_t = into_f(l, a);
+                let f_layout = a_t.as_ref().map(|(l, _)| *l).unwrap_or(l);
+
+                let mut sdim = 0;
+                let mut eigs = unsafe { vec_uninit(n as usize) };
+                let mut z = unsafe { vec_uninit((n * n) as usize) };
+                let mut rwork = unsafe { vec_uninit(n as usize) };
+
+                // calc work size
+                let mut info = 0;
+                let mut work_size = [Self::zero()];
+                unsafe {
+                    $gees(
+                        b'V',
+                        b'N',
+                        None,
+                        n,
+                        a_t.as_mut().map(|(_, v)| v.as_mut_slice()).unwrap_or(a),
+                        f_layout.lda(),
+                        &mut sdim,
+                        &mut eigs,
+                        &mut z,
+                        n,
+                        &mut work_size,
+                        -1,
+                        &mut rwork,
+                        &mut [],
+                        &mut info,
+                    )
+                };
+                info.as_lapack_result()?;
+
+                // actual
+                let lwork = work_size[0].to_usize().unwrap();
+                let mut work = unsafe { vec_uninit(lwork) };
+                unsafe {
+                    $gees(
+                        b'V',
+                        b'N',
+                        None,
+                        n,
+                        a_t.as_mut().map(|(_, v)| v.as_mut_slice()).unwrap_or(a),
+                        f_layout.lda(),
+                        &mut sdim,
+                        &mut eigs,
+                        &mut z,
+                        n,
+                        &mut work,
+                        lwork as i32,
+                        &mut rwork,
+                        &mut [],
+                        &mut info,
+                    )
+                };
+                info.as_lapack_result()?;
+
+                // Re-transpose T and Z into the original layout
+                if let Some((f_layout, a_t)) = a_t {
+                    transpose(f_layout, &a_t, a);
+                    let z_f = z.clone();
+                    transpose(f_layout, &z_f, &mut z);
+                }
+                Ok(SchurOutput { eigs, z })
+            }
+
+            fn schur_reorder(
+                l: MatrixLayout,
+                t: &mut [Self],
+                z: &mut [Self],
+                ifst: usize,
+                ilst: usize,
+            ) -> Result<()> {
+                let (n, _) = l.size();
+                let mut t_t = into_f(l, t);
+                let mut z_t = into_f(l, z);
+                let f_layout = t_t.as_ref().map(|(l, _)| *l).unwrap_or(l);
+
+                // `info` of `*trexc` cannot be read through the `lapack` crate,
+                // but it only reports invalid arguments, which are checked here.
+                assert!(ifst < n as usize && ilst < n as usize);
+                unsafe {
+                    $trexc(
+                        b'V',
+                        n,
+                        t_t.as_mut().map(|(_, v)| v.as_mut_slice()).unwrap_or(t),
+                        f_layout.lda(),
+                        z_t.as_mut().map(|(_, v)| v.as_mut_slice()).unwrap_or(z),
+                        f_layout.lda(),
+                        &[ifst as i32 + 1],
+                        &[ilst as i32 + 1],
+                        0,
+                    )
+                };
+
+                if let Some((f_layout, t_t)) = t_t {
+                    transpose(f_layout, &t_t, t);
+                }
+                if let Some((f_layout, z_t)) = z_t {
+                    transpose(f_layout, &z_t, z);
+                }
+                Ok(())
+            }
+
+            impl_schur_common!($trexc, $trsyl);
+        }
+    };
+}
+
+impl_schur_complex!(c64, lapack::zgees, lapack::ztrexc, lapack::ztrsyl);
+impl_schur_complex!(c32, lapack::cgees, lapack::ctrexc, lapack::ctrsyl);
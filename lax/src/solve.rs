@@ -19,6 +19,9 @@ pub trait Solve_: Scalar + Sized {
 
     fn inv(l: MatrixLayout, a: &mut [Self], p: &Pivot) -> Result<()>;
 
+    /// Wrapper of `*getrs`
+    ///
+    /// `b` is a column-major `n x nrhs` matrix overwritten by the solution.
     fn solve(l: MatrixLayout, t: Transpose, a: &[Self], p: &Pivot, b: &mut [Self]) -> Result<()>;
 }
 
@@ -108,7 +111,11 @@ macro_rules! impl_solve {
                     MatrixLayout::F { .. } => (t, false),
                 };
                 let (n, _) = l.size();
-                let nrhs = 1;
+                if n == 0 {
+                    // Do nothing for empty matrices.
+                    return Ok(());
+                }
+                let nrhs = b.len() as i32 / n;
                 let ldb = l.lda();
                 let mut info = 0;
                 if conj {
@@ -12,6 +12,8 @@ pub trait Solveh_: Sized {
     /// Wrapper of `*sytri` and `*hetri`
     fn invh(l: MatrixLayout, uplo: UPLO, a: &mut [Self], ipiv: &Pivot) -> Result<()>;
     /// Wrapper of `*sytrs` and `*hetrs`
+    ///
+    /// `b` is a column-major `n x nrhs` matrix overwritten by the solution.
     fn solveh(l: MatrixLayout, uplo: UPLO, a: &[Self], ipiv: &Pivot, b: &mut [Self]) -> Result<()>;
 }
 
@@ -78,8 +80,13 @@ macro_rules! impl_solveh {
                 b: &mut [Self],
             ) -> Result<()> {
                 let (n, _) = l.size();
+                if n == 0 {
+                    // Do nothing for empty matrices.
+                    return Ok(());
+                }
+                let nrhs = b.len() as i32 / n;
                 let mut info = 0;
-                unsafe { $trs(uplo as u8, n, 1, a, l.lda(), ipiv, b, n, &mut info) };
+                unsafe { $trs(uplo as u8, n, nrhs, a, l.lda(), ipiv, b, n, &mut info) };
                 info.as_lapack_result()?;
                 Ok(())
             }
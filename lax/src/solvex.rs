@@ -0,0 +1,555 @@
+//! Expert drivers for linear equations with error bounds
+
+use crate::{error::*, layout::*, *};
+use cauchy::*;
+use num_traits::{ToPrimitive, Zero};
+
+/// Diagnostics of the solution computed by the expert drivers
+pub struct SolveExpertOutput<A: Scalar> {
+    /// Estimate of the reciprocal condition number of the (equilibrated) matrix in 1-norm
+    pub rcond: A::Real,
+    /// Estimated forward error bound $\\|x - x_\text{true}\\|_\infty / \\|x\\|_\infty$ for each right-hand side
+    pub ferr: Vec<A::Real>,
+    /// Componentwise relative backward error for each right-hand side
+    pub berr: Vec<A::Real>,
+    /// Row scale factors $R$ if the matrix is equilibrated as $RAC$
+    pub row_scale: Option<Vec<A::Real>>,
+    /// Column scale factors $C$ if the matrix is equilibrated as $RAC$
+    pub col_scale: Option<Vec<A::Real>>,
+}
+
+/// Wraps `*gesvx`, `*posvx` and `*sysvx` (`*hesvx` for complex)
+///
+/// These drivers solve $AX = B$ for multiple right-hand sides `b`,
+/// which is overwritten by the solution $X$, with iterative refinement.
+/// The matrix `a` is not modified.
+///
+/// Error
+/// ------
+/// - `LapackComputationalFailure { return_code }` when the matrix is exactly singular
+///   (or not positive definite for `solve_cholesky_expert`) and the solution is not computed.
+///   The matrix singular to working precision, i.e. `rcond` is smaller than the machine epsilon,
+///   is **not** regarded as an error since the solution and its error bounds are still computed.
+pub trait SolveExpert_: Scalar + Sized {
+    /// Solve a general linear equation using LU decomposition,
+    /// with optional row/column equilibration when the matrix is poorly scaled.
+    fn solve_expert(
+        a_layout: MatrixLayout,
+        a: &[Self],
+        b_layout: MatrixLayout,
+        b: &mut [Self],
+        equilibrate: bool,
+    ) -> Result<SolveExpertOutput<Self>>;
+
+    /// Solve a positive-definite Hermitian linear equation using Cholesky decomposition,
+    /// with optional symmetric equilibration when the matrix is poorly scaled.
+    fn solve_cholesky_expert(
+        a_layout: MatrixLayout,
+        uplo: UPLO,
+        a: &[Self],
+        b_layout: MatrixLayout,
+        b: &mut [Self],
+        equilibrate: bool,
+    ) -> Result<SolveExpertOutput<Self>>;
+
+    /// Solve a Hermitian linear equation using Bunch-Kaufman diagonal pivoting.
+    /// The matrix is never equilibrated.
+    fn solveh_expert(
+        a_layout: MatrixLayout,
+        uplo: UPLO,
+        a: &[Self],
+        b_layout: MatrixLayout,
+        b: &mut [Self],
+    ) -> Result<SolveExpertOutput<Self>>;
+}
+
+/// Copy a matrix into a new F-continuous buffer
+pub(crate) fn to_f<T: Scalar>(l: MatrixLayout, a: &[T]) -> (MatrixLayout, Vec<T>) {
+    match l {
+        MatrixLayout::C { .. } => {
+            let mut a_t = unsafe { vec_uninit(a.len()) };
+            let l = transpose(l, a, &mut a_t);
+            (l, a_t)
+        }
+        MatrixLayout::F { .. } => (l, a.to_vec()),
+    }
+}
+
+/// Write back F-continuous solution `x` into `b` with its original layout
+pub(crate) fn write_back<T: Scalar>(
+    b_layout: MatrixLayout,
+    x_layout: MatrixLayout,
+    x: &[T],
+    b: &mut [T],
+) {
+    match b_layout {
+        MatrixLayout::C { .. } => {
+            transpose(x_layout, x, b);
+        }
+        MatrixLayout::F { .. } => b.copy_from_slice(x),
+    }
+}
+
+/// `info == n + 1` means that `rcond` is less than the machine epsilon,
+/// but the solution is computed.
+fn check_info(info: i32, n: i32) -> Result<()> {
+    if info == n + 1 {
+        Ok(())
+    } else {
+        info.as_lapack_result()
+    }
+}
+
+/// Convert `equed` of `*gesvx` into row and column scale factors
+fn general_scale<R>(equed: u8, r: Vec<R>, c: Vec<R>) -> (Option<Vec<R>>, Option<Vec<R>>) {
+    match equed {
+        b'R' => (Some(r), None),
+        b'C' => (None, Some(c)),
+        b'B' => (Some(r), Some(c)),
+        _ => (None, None),
+    }
+}
+
+/// Convert `equed` of `*posvx` into row and column scale factors
+fn symmetric_scale<R: Clone>(equed: u8, s: Vec<R>) -> (Option<Vec<R>>, Option<Vec<R>>) {
+    match equed {
+        b'Y' => (Some(s.clone()), Some(s)),
+        _ => (None, None),
+    }
+}
+
+fn fact(equilibrate: bool) -> u8 {
+    if equilibrate {
+        b'E'
+    } else {
+        b'N'
+    }
+}
+
+macro_rules! impl_solvex_real {
+    ($scalar:ty, $gesvx:path, $posvx:path, $sysvx:path) => {
+        impl SolveExpert_ for $scalar {
+            fn solve_expert(
+                a_layout: MatrixLayout,
+                a: &[Self],
+                b_layout: MatrixLayout,
+                b: &mut [Self],
+                equilibrate: bool,
+            ) -> Result<SolveExpertOutput<Self>> {
+                let (n, _) = a_layout.size();
+                let (_, nrhs) = b_layout.size();
+                let (a_layout, mut a) = to_f(a_layout, a);
+                let (x_layout, mut b_f) = to_f(b_layout, b);
+                let mut af = unsafe { vec_uninit((n * n) as usize) };
+                let mut ipiv = unsafe { vec_uninit(n as usize) };
+                let mut equed = b'N';
+                let mut r = unsafe { vec_uninit(n as usize) };
+                let mut c = unsafe { vec_uninit(n as usize) };
+                let mut x = unsafe { vec_uninit(b_f.len()) };
+                let mut rcond = Self::Real::zero();
+                let mut ferr = unsafe { vec_uninit(nrhs as usize) };
+                let mut berr = unsafe { vec_uninit(nrhs as usize) };
+                let mut work = unsafe { vec_uninit(4 * n as usize) };
+                let mut iwork = unsafe { vec_uninit(n as usize) };
+                let mut info = 0;
+                unsafe {
+                    $gesvx(
+                        fact(equilibrate),
+                        Transpose::No as u8,
+                        n,
+                        nrhs,
+                        &mut a,
+                        a_layout.lda(),
+                        &mut af,
+                        n,
+                        &mut ipiv,
+                        &mut equed,
+                        &mut r,
+                        &mut c,
+                        &mut b_f,
+                        x_layout.lda(),
+                        &mut x,
+                        x_layout.lda(),
+                        &mut rcond,
+                        &mut ferr,
+                        &mut berr,
+                        &mut work,
+                        &mut iwork,
+                        &mut info,
+                    )
+                };
+                check_info(info, n)?;
+                write_back(b_layout, x_layout, &x, b);
+                let (row_scale, col_scale) = general_scale(equed, r, c);
+                Ok(SolveExpertOutput {
+                    rcond,
+                    ferr,
+                    berr,
+                    row_scale,
+                    col_scale,
+                })
+            }
+
+            fn solve_cholesky_expert(
+                a_layout: MatrixLayout,
+                uplo: UPLO,
+                a: &[Self],
+                b_layout: MatrixLayout,
+                b: &mut [Self],
+                equilibrate: bool,
+            ) -> Result<SolveExpertOutput<Self>> {
+                let (n, _) = a_layout.size();
+                let (_, nrhs) = b_layout.size();
+                let (a_layout, mut a) = to_f(a_layout, a);
+                let (x_layout, mut b_f) = to_f(b_layout, b);
+                let mut af = unsafe { vec_uninit((n * n) as usize) };
+                let mut equed = b'N';
+                let mut s = unsafe { vec_uninit(n as usize) };
+                let mut x = unsafe { vec_uninit(b_f.len()) };
+                let mut rcond = Self::Real::zero();
+                let mut ferr = unsafe { vec_uninit(nrhs as usize) };
+                let mut berr = unsafe { vec_uninit(nrhs as usize) };
+                let mut work = unsafe { vec_uninit(3 * n as usize) };
+                let mut iwork = unsafe { vec_uninit(n as usize) };
+                let mut info = 0;
+                unsafe {
+                    $posvx(
+                        fact(equilibrate),
+                        uplo as u8,
+                        n,
+                        nrhs,
+                        &mut a,
+                        a_layout.lda(),
+                        &mut af,
+                        n,
+                        &mut equed,
+                        &mut s,
+                        &mut b_f,
+                        x_layout.lda(),
+                        &mut x,
+                        x_layout.lda(),
+                        &mut rcond,
+                        &mut ferr,
+                        &mut berr,
+                        &mut work,
+                        &mut iwork,
+                        &mut info,
+                    )
+                };
+                check_info(info, n)?;
+                write_back(b_layout, x_layout, &x, b);
+                let (row_scale, col_scale) = symmetric_scale(equed, s);
+                Ok(SolveExpertOutput {
+                    rcond,
+                    ferr,
+                    berr,
+                    row_scale,
+                    col_scale,
+                })
+            }
+
+            fn solveh_expert(
+                a_layout: MatrixLayout,
+                uplo: UPLO,
+                a: &[Self],
+                b_layout: MatrixLayout,
+                b: &mut [Self],
+            ) -> Result<SolveExpertOutput<Self>> {
+                let (n, _) = a_layout.size();
+                let (_, nrhs) = b_layout.size();
+                let (a_layout, a) = to_f(a_layout, a);
+                let (x_layout, b_f) = to_f(b_layout, b);
+                let mut af = unsafe { vec_uninit((n * n) as usize) };
+                let mut ipiv = unsafe { vec_uninit(n as usize) };
+                let mut x = unsafe { vec_uninit(b_f.len()) };
+                let mut rcond = Self::Real::zero();
+                let mut ferr = unsafe { vec_uninit(nrhs as usize) };
+                let mut berr = unsafe { vec_uninit(nrhs as usize) };
+                let mut iwork = unsafe { vec_uninit(n as usize) };
+
+                // calc work size
+                let mut info = 0;
+                let mut work_size = [Self::zero()];
+                unsafe {
+                    $sysvx(
+                        b'N',
+                        uplo as u8,
+                        n,
+                        nrhs,
+                        &a,
+                        a_layout.lda(),
+                        &mut af,
+                        n,
+                        &mut ipiv,
+                        &b_f,
+                        x_layout.lda(),
+                        &mut x,
+                        x_layout.lda(),
+                        &mut rcond,
+                        &mut ferr,
+                        &mut berr,
+                        &mut work_size,
+                        -1,
+                        &mut iwork,
+                        &mut info,
+                    )
+                };
+                info.as_lapack_result()?;
+
+                // actual
+                let lwork = work_size[0].to_usize().unwrap();
+                let mut work = unsafe { vec_uninit(lwork) };
+                unsafe {
+                    $sysvx(
+                        b'N',
+                        uplo as u8,
+                        n,
+                        nrhs,
+                        &a,
+                        a_layout.lda(),
+                        &mut af,
+                        n,
+                        &mut ipiv,
+                        &b_f,
+                        x_layout.lda(),
+                        &mut x,
+                        x_layout.lda(),
+                        &mut rcond,
+                        &mut ferr,
+                        &mut berr,
+                        &mut work,
+                        lwork as i32,
+                        &mut iwork,
+                        &mut info,
+                    )
+                };
+                check_info(info, n)?;
+                write_back(b_layout, x_layout, &x, b);
+                Ok(SolveExpertOutput {
+                    rcond,
+                    ferr,
+                    berr,
+                    row_scale: None,
+                    col_scale: None,
+                })
+            }
+        }
+    };
+}
+
+impl_solvex_real!(f64, lapack::dgesvx, lapack::dposvx, lapack::dsysvx);
+impl_solvex_real!(f32, lapack::sgesvx, lapack::sposvx, lapack::ssysvx);
+
+macro_rules! impl_solvex_complex {
+    ($scalar:ty, $gesvx:path, $posvx:path, $hesvx:path) => {
+        impl SolveExpert_ for $scalar {
+            fn solve_expert(
+                a_layout: MatrixLayout,
+                a: &[Self],
+                b_layout: MatrixLayout,
+                b: &mut [Self],
+                equilibrate: bool,
+            ) -> Result<SolveExpertOutput<Self>> {
+                let (n, _) = a_layout.size();
+                let (_, nrhs) = b_layout.size();
+                let (a_layout, mut a) = to_f(a_layout, a);
+                let (x_layout, mut b_f) = to_f(b_layout, b);
+                let mut af = unsafe { vec_uninit((n * n) as usize) };
+                let mut ipiv = unsafe { vec_uninit(n as usize) };
+                let mut equed = b'N';
+                let mut r = unsafe { vec_uninit(n as usize) };
+                let mut c = unsafe { vec_uninit(n as usize) };
+                let mut x = unsafe { vec_uninit(b_f.len()) };
+                let mut rcond = Self::Real::zero();
+                let mut ferr = unsafe { vec_uninit(nrhs as usize) };
+                let mut berr = unsafe { vec_uninit(nrhs as usize) };
+                let mut work = unsafe { vec_uninit(2 * n as usize) };
+                let mut rwork = unsafe { vec_uninit(2 * n as usize) };
+                let mut info = 0;
+                unsafe {
+                    $gesvx(
+                        fact(equilibrate),
+                        Transpose::No as u8,
+                        n,
+                        nrhs,
+                        &mut a,
+                        a_layout.lda(),
+                        &mut af,
+                        n,
+                        &mut ipiv,
+                        &mut equed,
+                        &mut r,
+                        &mut c,
+                        &mut b_f,
+                        x_layout.lda(),
+                        &mut x,
+                        x_layout.lda(),
+                        &mut rcond,
+                        &mut ferr,
+                        &mut berr,
+                        &mut work,
+                        &mut rwork,
+                        &mut info,
+                    )
+                };
+                check_info(info, n)?;
+                write_back(b_layout, x_layout, &x, b);
+                let (row_scale, col_scale) = general_scale(equed, r, c);
+                Ok(SolveExpertOutput {
+                    rcond,
+                    ferr,
+                    berr,
+                    row_scale,
+                    col_scale,
+                })
+            }
+
+            fn solve_cholesky_expert(
+                a_layout: MatrixLayout,
+                uplo: UPLO,
+                a: &[Self],
+                b_layout: MatrixLayout,
+                b: &mut [Self],
+                equilibrate: bool,
+            ) -> Result<SolveExpertOutput<Self>> {
+                let (n, _) = a_layout.size();
+                let (_, nrhs) = b_layout.size();
+                let (a_layout, mut a) = to_f(a_layout, a);
+                let (x_layout, mut b_f) = to_f(b_layout, b);
+                let mut af = unsafe { vec_uninit((n * n) as usize) };
+                let mut equed = b'N';
+                let mut s = unsafe { vec_uninit(n as usize) };
+                let mut x = unsafe { vec_uninit(b_f.len()) };
+                let mut rcond = Self::Real::zero();
+                let mut ferr = unsafe { vec_uninit(nrhs as usize) };
+                let mut berr = unsafe { vec_uninit(nrhs as usize) };
+                let mut work = unsafe { vec_uninit(2 * n as usize) };
+                let mut rwork = unsafe { vec_uninit(n as usize) };
+                let mut info = 0;
+                unsafe {
+                    $posvx(
+                        fact(equilibrate),
+                        uplo as u8,
+                        n,
+                        nrhs,
+                        &mut a,
+                        a_layout.lda(),
+                        &mut af,
+                        n,
+                        &mut equed,
+                        &mut s,
+                        &mut b_f,
+                        x_layout.lda(),
+                        &mut x,
+                        x_layout.lda(),
+                        &mut rcond,
+                        &mut ferr,
+                        &mut berr,
+                        &mut work,
+                        &mut rwork,
+                        &mut info,
+                    )
+                };
+                check_info(info, n)?;
+                write_back(b_layout, x_layout, &x, b);
+                let (row_scale, col_scale) = symmetric_scale(equed, s);
+                Ok(SolveExpertOutput {
+                    rcond,
+                    ferr,
+                    berr,
+                    row_scale,
+                    col_scale,
+                })
+            }
+
+            fn solveh_expert(
+                a_layout: MatrixLayout,
+                uplo: UPLO,
+                a: &[Self],
+                b_layout: MatrixLayout,
+                b: &mut [Self],
+            ) -> Result<SolveExpertOutput<Self>> {
+                let (n, _) = a_layout.size();
+                let (_, nrhs) = b_layout.size();
+                let (a_layout, a) = to_f(a_layout, a);
+                let (x_layout, b_f) = to_f(b_layout, b);
+                let mut af = unsafe { vec_uninit((n * n) as usize) };
+                let mut ipiv = unsafe { vec_uninit(n as usize) };
+                let mut x = unsafe { vec_uninit(b_f.len()) };
+                let mut rcond = Self::Real::zero();
+                let mut ferr = unsafe { vec_uninit(nrhs as usize) };
+                let mut berr = unsafe { vec_uninit(nrhs as usize) };
+                let mut rwork = unsafe { vec_uninit(n as usize) };
+
+                // calc work size
+                let mut info = 0;
+                let mut work_size = [Self::zero()];
+                unsafe {
+                    $hesvx(
+                        b'N',
+                        uplo as u8,
+                        n,
+                        nrhs,
+                        &a,
+                        a_layout.lda(),
+                        &mut af,
+                        n,
+                        &mut ipiv,
+                        &b_f,
+                        x_layout.lda(),
+                        &mut x,
+                        x_layout.lda(),
+                        &mut rcond,
+                        &mut ferr,
+                        &mut berr,
+                        &mut work_size,
+                        -1,
+                        &mut rwork,
+                        &mut info,
+                    )
+                };
+                info.as_lapack_result()?;
+
+                // actual
+                let lwork = work_size[0].to_usize().unwrap();
+                let mut work = unsafe { vec_uninit(lwork) };
+                unsafe {
+                    $hesvx(
+                        b'N',
+                        uplo as u8,
+                        n,
+                        nrhs,
+                        &a,
+                        a_layout.lda(),
+                        &mut af,
+                        n,
+                        &mut ipiv,
+                        &b_f,
+                        x_layout.lda(),
+                        &mut x,
+                        x_layout.lda(),
+                        &mut rcond,
+                        &mut ferr,
+                        &mut berr,
+                        &mut work,
+                        lwork as i32,
+                        &mut rwork,
+                        &mut info,
+                    )
+                };
+                check_info(info, n)?;
+                write_back(b_layout, x_layout, &x, b);
+                Ok(SolveExpertOutput {
+                    rcond,
+                    ferr,
+                    berr,
+                    row_scale: None,
+                    col_scale: None,
+                })
+            }
+        }
+    };
+}
+
+impl_solvex_complex!(c64, lapack::zgesvx, lapack::zposvx, lapack::zhesvx);
+impl_solvex_complex!(c32, lapack::cgesvx, lapack::cposvx, lapack::chesvx);
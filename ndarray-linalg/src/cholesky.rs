@@ -44,11 +44,14 @@
 //! ```
 
 use ndarray::*;
-use num_traits::Float;
+use num_traits::{Float, Zero};
 
 use crate::convert::*;
+use crate::equilibrate::*;
 use crate::error::*;
+use crate::generate::*;
 use crate::layout::*;
+use crate::opnorm::*;
 use crate::triangular::IntoTriangular;
 use crate::types::*;
 
@@ -92,6 +95,72 @@ where
             UPLO::Upper => self.factor,
         }
     }
+
+    /// Updates the decomposition to that of `A + x * x^H` in O(n^2) operations.
+    pub fn update<Sx>(&mut self, x: &ArrayBase<Sx, Ix1>) -> Result<()>
+    where
+        Sx: Data<Elem = A>,
+    {
+        self.rank_one(x, false)
+    }
+
+    /// Downdates the decomposition to that of `A - x * x^H` in O(n^2) operations.
+    ///
+    /// Returns [`LinalgError::NotPositiveDefinite`] and leaves the decomposition
+    /// unchanged if `A - x * x^H` is not positive definite.
+    pub fn downdate<Sx>(&mut self, x: &ArrayBase<Sx, Ix1>) -> Result<()>
+    where
+        Sx: Data<Elem = A>,
+    {
+        self.rank_one(x, true)
+    }
+
+    /// Rotates `x` into the columns of `L` by Givens rotations for the update,
+    /// or by hyperbolic rotations for the downdate
+    fn rank_one<Sx>(&mut self, x: &ArrayBase<Sx, Ix1>, downdate: bool) -> Result<()>
+    where
+        Sx: Data<Elem = A>,
+    {
+        let n = self.factor.nrows();
+        if x.len() != n {
+            return Err(ShapeError::from_kind(ErrorKind::IncompatibleShape).into());
+        }
+        let mut x = x.to_owned();
+        let mut l: Array2<A> = match self.uplo {
+            UPLO::Lower => replicate(&self.factor),
+            UPLO::Upper => conjugate(&self.factor),
+        };
+        for k in 0..n {
+            let lkk = l[(k, k)].re();
+            let r2 = if downdate {
+                lkk * lkk - x[k].square()
+            } else {
+                lkk * lkk + x[k].square()
+            };
+            if r2 <= A::Real::zero() {
+                return Err(LinalgError::NotPositiveDefinite);
+            }
+            let r = Float::sqrt(r2);
+            let c = r / lkk;
+            let s = x[k].div_real(lkk);
+            l[(k, k)] = A::from_real(r);
+            for i in k + 1..n {
+                let lik = if downdate {
+                    l[(i, k)] - s.conj() * x[i]
+                } else {
+                    l[(i, k)] + s.conj() * x[i]
+                }
+                .div_real(c);
+                l[(i, k)] = lik;
+                x[i] = x[i].mul_real(c) - s * lik;
+            }
+        }
+        match self.uplo {
+            UPLO::Lower => self.factor.assign(&l),
+            UPLO::Upper => self.factor.assign(&l.t().mapv(|x| x.conj())),
+        }
+        Ok(())
+    }
 }
 
 impl<A, S> DeterminantC for CholeskyFactorized<S>
@@ -161,7 +230,26 @@ where
     }
 }
 
-impl<A, S> SolveC<A> for CholeskyFactorized<S>
+impl<A, S> SolveC<A, Ix2> for CholeskyFactorized<S>
+where
+    A: Scalar + Lapack,
+    S: Data<Elem = A>,
+{
+    fn solvec_inplace<'a, Sb>(
+        &self,
+        b: &'a mut ArrayBase<Sb, Ix2>,
+    ) -> Result<&'a mut ArrayBase<Sb, Ix2>>
+    where
+        Sb: DataMut<Elem = A>,
+    {
+        let l = self.factor.square_layout()?;
+        let factor = self.factor.as_allocated()?;
+        with_fortran_slice(b, |b| Ok(A::solve_cholesky(l, self.uplo, factor, b)?))?;
+        Ok(b)
+    }
+}
+
+impl<A, S> SolveC<A, Ix1> for CholeskyFactorized<S>
 where
     A: Scalar + Lapack,
     S: Data<Elem = A>,
@@ -173,12 +261,192 @@ where
     where
         Sb: DataMut<Elem = A>,
     {
-        A::solve_cholesky(
+        self.solvec_inplace(&mut b.view_mut().insert_axis(Axis(1)))?;
+        Ok(b)
+    }
+}
+
+impl<A, S> CholeskyFactorized<S>
+where
+    A: Scalar + Lapack,
+    S: Data<Elem = A>,
+{
+    /// *Estimates* the reciprocal of the condition number in 1-norm,
+    /// where `anorm` is the 1-norm of the original matrix.
+    ///
+    /// The factorization does not keep the original matrix,
+    /// and `anorm` should be computed before factorizing it.
+    pub fn rcondc_with_norm(&self, anorm: A::Real) -> Result<A::Real> {
+        Ok(A::rcond_cholesky(
             self.factor.square_layout()?,
             self.uplo,
             self.factor.as_allocated()?,
-            b.as_slice_mut().unwrap(),
-        )?;
+            anorm,
+        )?)
+    }
+}
+
+/// Cholesky decomposition of the equilibrated matrix `S * A * S`
+/// with the diagonal scale factors `S`
+///
+/// The equations are solved, and the inverse and the determinant are computed,
+/// for the original matrix `A`.
+pub struct EquilibratedCholeskyFactorized<S: Data> {
+    /// The Cholesky decomposition of `S * A * S`
+    cholesky: CholeskyFactorized<S>,
+    /// The scale factors `S`, which are ones if the matrix is not scaled
+    scale: Array1<S::Elem>,
+}
+
+impl<A, S> EquilibratedCholeskyFactorized<S>
+where
+    A: Scalar,
+    S: Data<Elem = A>,
+{
+    /// The Cholesky decomposition of the equilibrated matrix `S * A * S`,
+    /// e.g. for the reciprocal condition number of the equilibrated matrix
+    pub fn cholesky(&self) -> &CholeskyFactorized<S> {
+        &self.cholesky
+    }
+
+    /// The scale factors `S`
+    pub fn scale(&self) -> &Array1<A> {
+        &self.scale
+    }
+}
+
+impl<A, S> EquilibratedCholeskyFactorized<S>
+where
+    A: Scalar + Lapack,
+    S: DataMut<Elem = A>,
+{
+    /// Updates the decomposition to that of `A + x * x^H` in O(n^2) operations.
+    ///
+    /// The scale factors are kept, i.e. the updated matrix is not equilibrated again.
+    pub fn update<Sx>(&mut self, x: &ArrayBase<Sx, Ix1>) -> Result<()>
+    where
+        Sx: Data<Elem = A>,
+    {
+        // S (A + x x^H) S = SAS + (Sx) (Sx)^H
+        self.cholesky.update(&self.scale_vector(x)?)
+    }
+
+    /// Downdates the decomposition to that of `A - x * x^H` in O(n^2) operations.
+    ///
+    /// Returns [`LinalgError::NotPositiveDefinite`] and leaves the decomposition
+    /// unchanged if `A - x * x^H` is not positive definite.
+    pub fn downdate<Sx>(&mut self, x: &ArrayBase<Sx, Ix1>) -> Result<()>
+    where
+        Sx: Data<Elem = A>,
+    {
+        self.cholesky.downdate(&self.scale_vector(x)?)
+    }
+
+    fn scale_vector<Sx>(&self, x: &ArrayBase<Sx, Ix1>) -> Result<Array1<A>>
+    where
+        Sx: Data<Elem = A>,
+    {
+        if x.len() != self.scale.len() {
+            return Err(ShapeError::from_kind(ErrorKind::IncompatibleShape).into());
+        }
+        Ok(&self.scale * x)
+    }
+}
+
+impl<A, S> DeterminantC for EquilibratedCholeskyFactorized<S>
+where
+    A: Scalar + Lapack,
+    S: Data<Elem = A>,
+{
+    type Output = <A as Scalar>::Real;
+
+    fn detc(&self) -> Self::Output {
+        Float::exp(self.ln_detc())
+    }
+
+    fn ln_detc(&self) -> Self::Output {
+        // det(A) = det(SAS) / det(S)^2
+        self.cholesky.ln_detc()
+            - self
+                .scale
+                .iter()
+                .map(|s| Float::ln(s.square()))
+                .sum::<Self::Output>()
+    }
+}
+
+impl<A, S> DeterminantCInto for EquilibratedCholeskyFactorized<S>
+where
+    A: Scalar + Lapack,
+    S: Data<Elem = A>,
+{
+    type Output = <A as Scalar>::Real;
+
+    fn detc_into(self) -> Self::Output {
+        self.detc()
+    }
+
+    fn ln_detc_into(self) -> Self::Output {
+        self.ln_detc()
+    }
+}
+
+/// `A^{-1} = S (SAS)^{-1} S`
+fn unscale_inverse<A, S>(inv: &mut ArrayBase<S, Ix2>, scale: &Array1<A>)
+where
+    A: Scalar,
+    S: DataMut<Elem = A>,
+{
+    scale_rows(inv, scale, |x, s| x * s);
+    scale_rows(&mut inv.view_mut().reversed_axes(), scale, |x, s| x * s);
+}
+
+impl<A, S> InverseC for EquilibratedCholeskyFactorized<S>
+where
+    A: Scalar + Lapack,
+    S: Data<Elem = A>,
+{
+    type Output = Array2<A>;
+
+    fn invc(&self) -> Result<Self::Output> {
+        let mut inv = self.cholesky.invc()?;
+        unscale_inverse(&mut inv, &self.scale);
+        Ok(inv)
+    }
+}
+
+impl<A, S> InverseCInto for EquilibratedCholeskyFactorized<S>
+where
+    A: Scalar + Lapack,
+    S: DataMut<Elem = A>,
+{
+    type Output = ArrayBase<S, Ix2>;
+
+    fn invc_into(self) -> Result<Self::Output> {
+        let mut inv = self.cholesky.invc_into()?;
+        unscale_inverse(&mut inv, &self.scale);
+        Ok(inv)
+    }
+}
+
+impl<A, S, D> SolveC<A, D> for EquilibratedCholeskyFactorized<S>
+where
+    A: Scalar + Lapack,
+    S: Data<Elem = A>,
+    D: RemoveAxis,
+    CholeskyFactorized<S>: SolveC<A, D>,
+{
+    fn solvec_inplace<'a, Sb>(
+        &self,
+        b: &'a mut ArrayBase<Sb, D>,
+    ) -> Result<&'a mut ArrayBase<Sb, D>>
+    where
+        Sb: DataMut<Elem = A>,
+    {
+        // A x = b is (SAS) (S^{-1} x) = S b
+        scale_rows(b, &self.scale, |x, s| x * s);
+        self.cholesky.solvec_inplace(b)?;
+        scale_rows(b, &self.scale, |x, s| x * s);
         Ok(b)
     }
 }
@@ -273,6 +541,12 @@ pub trait FactorizeC<S: Data> {
     /// `UPLO::Lower`, computes the decomposition `A = L * L^H` using the lower
     /// triangular portion of `A` and returns the factorization containing `L`.
     fn factorizec(&self, uplo: UPLO) -> Result<CholeskyFactorized<S>>;
+
+    /// Computes the Cholesky decomposition of the equilibrated matrix `S * A * S`,
+    /// where `S` is the diagonal scale factors computed by
+    /// [`Equilibrate::equilibrate_hermitian`]. The matrix is scaled only if it is
+    /// worthwhile. See [`EquilibratedCholeskyFactorized`] for the operations on `A`.
+    fn factorizec_equilibrated(&self, uplo: UPLO) -> Result<EquilibratedCholeskyFactorized<S>>;
 }
 
 /// Cholesky decomposition of Hermitian (or real symmetric) positive definite matrix
@@ -286,6 +560,10 @@ pub trait FactorizeCInto<S: Data> {
     /// `UPLO::Lower`, computes the decomposition `A = L * L^H` using the lower
     /// triangular portion of `A` and returns the factorization containing `L`.
     fn factorizec_into(self, uplo: UPLO) -> Result<CholeskyFactorized<S>>;
+
+    /// Computes the Cholesky decomposition of the equilibrated matrix `S * A * S`.
+    /// See [`FactorizeC::factorizec_equilibrated`] for detail.
+    fn factorizec_equilibrated_into(self, uplo: UPLO) -> Result<EquilibratedCholeskyFactorized<S>>;
 }
 
 impl<A, S> FactorizeCInto<S> for ArrayBase<S, Ix2>
@@ -299,6 +577,19 @@ where
             uplo,
         })
     }
+
+    fn factorizec_equilibrated_into(
+        mut self,
+        uplo: UPLO,
+    ) -> Result<EquilibratedCholeskyFactorized<S>> {
+        let (scale, _) = self.equilibrate_hermitian()?.into_applied();
+        scale_rows(&mut self, &scale, |x, s| x * s);
+        scale_rows(&mut self.view_mut().reversed_axes(), &scale, |x, s| x * s);
+        Ok(EquilibratedCholeskyFactorized {
+            cholesky: self.factorizec_into(uplo)?,
+            scale,
+        })
+    }
 }
 
 impl<A, Si> FactorizeC<OwnedRepr<A>> for ArrayBase<Si, Ix2>
@@ -312,15 +603,26 @@ where
             uplo,
         })
     }
+
+    fn factorizec_equilibrated(
+        &self,
+        uplo: UPLO,
+    ) -> Result<EquilibratedCholeskyFactorized<OwnedRepr<A>>> {
+        let a: Array2<A> = replicate(self);
+        a.factorizec_equilibrated_into(uplo)
+    }
 }
 
 /// Solve systems of linear equations with Hermitian (or real symmetric)
 /// positive definite coefficient matrices
-pub trait SolveC<A: Scalar> {
+///
+/// The right-hand side `b` is either a vector (`D = Ix1`) or a matrix
+/// (`D = Ix2`) whose columns are solved at once.
+pub trait SolveC<A: Scalar, D: Dimension = Ix1> {
     /// Solves a system of linear equations `A * x = b` with Hermitian (or real
     /// symmetric) positive definite matrix `A`, where `A` is `self`, `b` is
     /// the argument, and `x` is the successful result.
-    fn solvec<S: Data<Elem = A>>(&self, b: &ArrayBase<S, Ix1>) -> Result<Array1<A>> {
+    fn solvec<S: Data<Elem = A>>(&self, b: &ArrayBase<S, D>) -> Result<Array<A, D>> {
         let mut b = replicate(b);
         self.solvec_inplace(&mut b)?;
         Ok(b)
@@ -328,10 +630,7 @@ pub trait SolveC<A: Scalar> {
     /// Solves a system of linear equations `A * x = b` with Hermitian (or real
     /// symmetric) positive definite matrix `A`, where `A` is `self`, `b` is
     /// the argument, and `x` is the successful result.
-    fn solvec_into<S: DataMut<Elem = A>>(
-        &self,
-        mut b: ArrayBase<S, Ix1>,
-    ) -> Result<ArrayBase<S, Ix1>> {
+    fn solvec_into<S: DataMut<Elem = A>>(&self, mut b: ArrayBase<S, D>) -> Result<ArrayBase<S, D>> {
         self.solvec_inplace(&mut b)?;
         Ok(b)
     }
@@ -341,19 +640,21 @@ pub trait SolveC<A: Scalar> {
     /// also assigned to the argument.
     fn solvec_inplace<'a, S: DataMut<Elem = A>>(
         &self,
-        b: &'a mut ArrayBase<S, Ix1>,
-    ) -> Result<&'a mut ArrayBase<S, Ix1>>;
+        b: &'a mut ArrayBase<S, D>,
+    ) -> Result<&'a mut ArrayBase<S, D>>;
 }
 
-impl<A, S> SolveC<A> for ArrayBase<S, Ix2>
+impl<A, S, D> SolveC<A, D> for ArrayBase<S, Ix2>
 where
     A: Scalar + Lapack,
     S: Data<Elem = A>,
+    D: Dimension,
+    CholeskyFactorized<OwnedRepr<A>>: SolveC<A, D>,
 {
     fn solvec_inplace<'a, Sb>(
         &self,
-        b: &'a mut ArrayBase<Sb, Ix1>,
-    ) -> Result<&'a mut ArrayBase<Sb, Ix1>>
+        b: &'a mut ArrayBase<Sb, D>,
+    ) -> Result<&'a mut ArrayBase<Sb, D>>
     where
         Sb: DataMut<Elem = A>,
     {
@@ -466,3 +767,51 @@ where
         Ok(self.factorizec_into(UPLO::Upper)?.ln_detc_into())
     }
 }
+
+/// *Estimates* the reciprocal condition number of Hermitian (or real
+/// symmetric) positive definite matrix ref
+pub trait ReciprocalConditionNumC<A: Scalar> {
+    /// *Estimates* the reciprocal of the condition number of the Hermitian (or
+    /// real symmetric) positive definite matrix in 1-norm.
+    ///
+    /// This method uses the LAPACK `*pocon` routines on the Cholesky factor.
+    ///
+    /// * If `rcond` is near `0.`, the matrix is badly conditioned.
+    /// * If `rcond` is near `1.`, the matrix is well conditioned.
+    fn rcondc(&self) -> Result<A::Real>;
+}
+
+/// *Estimates* the reciprocal condition number of Hermitian (or real
+/// symmetric) positive definite matrix
+pub trait ReciprocalConditionNumCInto<A: Scalar> {
+    /// *Estimates* the reciprocal of the condition number of the Hermitian (or
+    /// real symmetric) positive definite matrix in 1-norm.
+    ///
+    /// This method uses the LAPACK `*pocon` routines on the Cholesky factor.
+    ///
+    /// * If `rcond` is near `0.`, the matrix is badly conditioned.
+    /// * If `rcond` is near `1.`, the matrix is well conditioned.
+    fn rcondc_into(self) -> Result<A::Real>;
+}
+
+impl<A, S> ReciprocalConditionNumC<A> for ArrayBase<S, Ix2>
+where
+    A: Scalar + Lapack,
+    S: Data<Elem = A>,
+{
+    fn rcondc(&self) -> Result<A::Real> {
+        let anorm = opnorm_one_hermitian(self, UPLO::Upper);
+        self.factorizec(UPLO::Upper)?.rcondc_with_norm(anorm)
+    }
+}
+
+impl<A, S> ReciprocalConditionNumCInto<A> for ArrayBase<S, Ix2>
+where
+    A: Scalar + Lapack,
+    S: DataMut<Elem = A>,
+{
+    fn rcondc_into(self) -> Result<A::Real> {
+        let anorm = opnorm_one_hermitian(&self, UPLO::Upper);
+        self.factorizec_into(UPLO::Upper)?.rcondc_with_norm(anorm)
+    }
+}
@@ -0,0 +1,210 @@
+//! Condition number of matrices
+//!
+//! $$ \kappa_p(A) = \\|A\\|_p \\|A^{-1}\\|_p $$
+//!
+//! The condition number is computed exactly for dense matrices:
+//! the 2-norm and Frobenius-norm ones from the singular values,
+//! and the 1-norm and ∞-norm ones from the explicit inverse.
+//! See [`ReciprocalConditionNum`](crate::solve::ReciprocalConditionNum)
+//! for the cheap estimation using the LU decomposition.
+//!
+//! For a [`LinearOperator`] which can apply its adjoint, the spectral norm and
+//! the 2-norm condition number are estimated by the Lanczos iteration on $A^\dagger A$.
+//!
+//! ```
+//! use ndarray::*;
+//! use ndarray_linalg::*;
+//!
+//! let a: Array2<f64> = array![[1.0, 0.0], [0.0, 1e-3]];
+//! assert_rclose!(a.cond(CondType::Two).unwrap(), 1e3, 1e-9);
+//! assert_rclose!(estimate_cond_2(&a, 2, 10, 1e-9).unwrap(), 1e3, 1e-6);
+//! ```
+
+use ndarray::*;
+use num_traits::{Float, Zero};
+
+use crate::error::*;
+use crate::generate::*;
+use crate::krylov::*;
+use crate::layout::*;
+use crate::operator::*;
+use crate::opnorm::*;
+use crate::pinv::Cutoff;
+use crate::solve::*;
+use crate::svddc::*;
+use crate::types::*;
+
+/// Matrix norm used to define the condition number
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CondType {
+    /// Maximum column sum
+    One,
+    /// Largest singular value
+    Two,
+    /// Maximum row sum
+    Infinity,
+    /// Square root of sum of squares
+    Frobenius,
+}
+
+/// Condition number of matrix
+pub trait ConditionNumber {
+    type Output;
+    /// Compute the condition number $\\|A\\|_p \\|A^{-1}\\|_p$
+    ///
+    /// Rectangular matrices are accepted for `Two` and `Frobenius`, where $A^{-1}$ is
+    /// replaced by the pseudo-inverse of the full-rank matrix.
+    /// Infinity is returned for singular (or rank-deficient) matrices, where the singular values
+    /// below [`Cutoff::Auto`] are regarded as zero for `Two` and `Frobenius`.
+    ///
+    /// Error
+    /// ------
+    /// - `NotSquare` for non-square matrix with `One` or `Infinity`
+    fn cond(&self, p: CondType) -> Result<Self::Output>;
+}
+
+impl<A, S> ConditionNumber for ArrayBase<S, Ix2>
+where
+    A: Scalar + Lapack,
+    S: Data<Elem = A>,
+{
+    type Output = A::Real;
+
+    fn cond(&self, p: CondType) -> Result<Self::Output> {
+        let norm_type = match p {
+            CondType::One => NormType::One,
+            CondType::Infinity => NormType::Infinity,
+            CondType::Two | CondType::Frobenius => {
+                if self.is_empty() {
+                    return Ok(A::Real::zero());
+                }
+                let (_, s, _) = self.to_owned().svddc_into(UVTFlag::None)?;
+                // singular values are sorted in descending order
+                let (m, n) = self.dim();
+                if s[s.len() - 1] <= Cutoff::Auto.threshold(m, n, s[0]) {
+                    return Ok(A::Real::infinity());
+                }
+                return Ok(match p {
+                    CondType::Two => s[0] / s[s.len() - 1],
+                    _ => {
+                        let norm: A::Real = s.iter().map(|&s| s * s).sum();
+                        let inv_norm: A::Real = s.iter().map(|&s| (s * s).recip()).sum();
+                        Float::sqrt(norm * inv_norm)
+                    }
+                });
+            }
+        };
+        self.ensure_square()?;
+        if self.is_empty() {
+            return Ok(A::Real::zero());
+        }
+        let inv = match self.factorize().and_then(|f| f.inv()) {
+            Ok(inv) => inv,
+            Err(LinalgError::Lapack(lax::error::Error::LapackComputationalFailure { .. })) => {
+                return Ok(A::Real::infinity())
+            }
+            Err(e) => return Err(e),
+        };
+        Ok(self.opnorm(norm_type)? * inv.opnorm(norm_type)?)
+    }
+}
+
+/// Estimate the spectral norm $\\|A\\|_2$ of an operator acting on `n`-dimensional vectors
+///
+/// The largest eigenvalue of $A^\dagger A$ is computed by the Lanczos iteration
+/// from a random starting vector.
+///
+/// Error
+/// ------
+/// - `NotConverged` when the relative residual of the Ritz value does not reach `tol` in `max_iter` iterations
+pub fn estimate_opnorm_2<A, Op>(a: &Op, n: usize, max_iter: usize, tol: A::Real) -> Result<A::Real>
+where
+    A: Scalar + Lapack,
+    Op: AdjointOperator<Elem = A>,
+{
+    let (max, _) = extreme_eigenvalues_normal(a, n, max_iter, tol, false)?;
+    Ok(Float::sqrt(max))
+}
+
+/// Estimate the 2-norm condition number of an operator acting on `n`-dimensional vectors
+///
+/// Both the largest and smallest eigenvalues of $A^\dagger A$ are computed by the Lanczos iteration
+/// from a random starting vector. Since the smallest one converges slowly in general,
+/// this is only practical for the operators whose condition number is moderate.
+///
+/// Error
+/// ------
+/// - `NotConverged` when the relative residuals of the Ritz values do not reach `tol` in `max_iter` iterations
+pub fn estimate_cond_2<A, Op>(a: &Op, n: usize, max_iter: usize, tol: A::Real) -> Result<A::Real>
+where
+    A: Scalar + Lapack,
+    Op: AdjointOperator<Elem = A>,
+{
+    let (max, min) = extreme_eigenvalues_normal(a, n, max_iter, tol, true)?;
+    if min <= A::Real::zero() {
+        return Ok(A::Real::infinity());
+    }
+    Ok(Float::sqrt(max / min))
+}
+
+/// The operator $A^\dagger A$
+struct Normal<'a, Op>(&'a Op);
+
+impl<'a, A, Op> LinearOperator for Normal<'a, Op>
+where
+    A: Scalar,
+    Op: AdjointOperator<Elem = A>,
+{
+    type Elem = A;
+
+    fn apply<S>(&self, a: &ArrayBase<S, Ix1>) -> Array1<A>
+    where
+        S: Data<Elem = A>,
+    {
+        self.0.apply_adjoint(&self.0.apply(a))
+    }
+}
+
+/// Largest and smallest eigenvalues of $A^\dagger A$ by the Lanczos iteration with full re-orthogonalization
+///
+/// Convergence is checked by the residual bounds of the Ritz pairs relative to the largest Ritz value,
+/// and only for the largest one if `check_min` is false.
+fn extreme_eigenvalues_normal<A, Op>(
+    a: &Op,
+    n: usize,
+    max_iter: usize,
+    tol: A::Real,
+    check_min: bool,
+) -> Result<(A::Real, A::Real)>
+where
+    A: Scalar + Lapack,
+    Op: AdjointOperator<Elem = A>,
+{
+    if n == 0 {
+        return Ok((A::Real::zero(), A::Real::zero()));
+    }
+    let eps = A::Real::epsilon();
+    let v: Array1<A> = random(n);
+    let mut lanczos = Lanczos::new(
+        Normal(a),
+        v,
+        eps,
+        Reorthogonalization::Full(MGS::new(n, eps)),
+    );
+    for _ in 0..max_iter {
+        // The Ritz values are exact when the Krylov subspace becomes invariant
+        let exhausted = lanczos.next().is_none();
+        let e = lanczos.ritz_values()?;
+        let residuals = lanczos.residual_bounds()?;
+        // Ritz values are sorted in ascending order
+        let k = e.len();
+        let (min, max) = (e[0], e[k - 1]);
+        let converged = residuals[k - 1] <= tol * max && (!check_min || residuals[0] <= tol * max);
+        if exhausted || converged {
+            return Ok((max, min));
+        }
+    }
+    Err(LinalgError::NotConverged {
+        iterations: max_iter,
+    })
+}
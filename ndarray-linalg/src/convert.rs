@@ -142,3 +142,23 @@ where
         }
     }
 }
+
+/// Calls `f` with the column-major (Fortran) memory of the matrix `b`, e.g. the right-hand sides
+/// of linear equations passed to LAPACK.
+///
+/// If `b` is not F-contiguous, `f` works on a temporary copy which is written back to `b`.
+pub(crate) fn with_fortran_slice<A, S, F>(b: &mut ArrayBase<S, Ix2>, f: F) -> Result<()>
+where
+    A: Copy,
+    S: DataMut<Elem = A>,
+    F: FnOnce(&mut [A]) -> Result<()>,
+{
+    let mut bt = b.view_mut().reversed_axes();
+    if let Some(slice) = bt.as_slice_mut() {
+        return f(slice);
+    }
+    let mut tmp = bt.as_standard_layout().into_owned();
+    f(tmp.as_slice_mut().unwrap())?;
+    bt.assign(&tmp);
+    Ok(())
+}
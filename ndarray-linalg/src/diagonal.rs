@@ -46,3 +46,20 @@ where
         }
     }
 }
+
+impl<A, Sa> AdjointOperator for Diagonal<Sa>
+where
+    A: Scalar,
+    Sa: Data<Elem = A>,
+{
+    fn apply_adjoint<S>(&self, a: &ArrayBase<S, Ix1>) -> Array1<A>
+    where
+        S: Data<Elem = A>,
+    {
+        let mut a = a.to_owned();
+        for (val, d) in a.iter_mut().zip(self.diag.iter()) {
+            *val *= d.conj();
+        }
+        a
+    }
+}
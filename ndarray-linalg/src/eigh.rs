@@ -11,6 +11,9 @@
 //! order and `V` is the matrix of corresponding generalized eigenvectors. The
 //! matrix `V` is normalized such that `V^H B V = I`.
 //!
+//! Functions of a Hermitian matrix, e.g. `exp(A)` or `A^{-1/2}`, are computed
+//! through the eigendecomposition by [`HermitianFunction`].
+//!
 //! # Example
 //!
 //! Find the eigendecomposition of a Hermitian (or real symmetric) matrix.
@@ -37,6 +40,7 @@ use ndarray::*;
 
 use crate::diagonal::*;
 use crate::error::*;
+use crate::generate::conjugate;
 use crate::layout::*;
 use crate::operator::LinearOperator;
 use crate::types::*;
@@ -269,9 +273,132 @@ where
     type Output = Array2<A>;
 
     fn ssqrt_into(self, uplo: UPLO) -> Result<Self::Output> {
+        self.funh_into(uplo, |e| e.sqrt())
+    }
+}
+
+/// Calculate a function of a Hermitian (or real symmetric) matrix using `eigh`
+///
+/// For a Hermitian matrix `A = V Λ V^H`, this computes `f(A) = V f(Λ) V^H`
+/// where `f` is applied to each eigenvalue.
+pub trait HermitianFunction<A: Scalar> {
+    type Output;
+
+    /// Computes `f(A)` for a real-valued function `f`. The result is also
+    /// Hermitian (or real symmetric).
+    fn funh<F>(&self, uplo: UPLO, f: F) -> Result<Self::Output>
+    where
+        F: Fn(A::Real) -> A::Real;
+
+    /// Computes `f(A)` for a complex-valued function `f`. The result is a
+    /// normal matrix, but not Hermitian in general.
+    fn funh_complex<F>(&self, uplo: UPLO, f: F) -> Result<Array2<A::Complex>>
+    where
+        F: Fn(A::Real) -> A::Complex;
+
+    /// Matrix exponential `exp(A)`
+    fn expmh(&self, uplo: UPLO) -> Result<Self::Output> {
+        self.funh(uplo, |e| e.exp())
+    }
+
+    /// Principal matrix logarithm `log(A)`
+    ///
+    /// `A` must be positive definite, otherwise the result contains NaN.
+    fn logmh(&self, uplo: UPLO) -> Result<Self::Output> {
+        self.funh(uplo, |e| e.ln())
+    }
+
+    /// Matrix power `A^p` for real `p`
+    ///
+    /// If `p` is not an integer, `A` must be positive semi-definite,
+    /// otherwise the result contains NaN.
+    fn powh(&self, uplo: UPLO, p: A::Real) -> Result<Self::Output> {
+        self.funh(uplo, |e| e.powf(p))
+    }
+
+    /// Inverse of the square-root matrix `A^{-1/2}`
+    ///
+    /// `A` must be positive definite, otherwise the result contains NaN or
+    /// infinity.
+    fn inv_sqrth(&self, uplo: UPLO) -> Result<Self::Output> {
+        self.funh(uplo, |e| e.sqrt().powi(-1))
+    }
+}
+
+/// Calculate a function of a Hermitian (or real symmetric) matrix using `eigh`
+pub trait HermitianFunctionInto<A: Scalar> {
+    type Output;
+
+    /// Computes `f(A)` for a real-valued function `f`. The result is also
+    /// Hermitian (or real symmetric).
+    fn funh_into<F>(self, uplo: UPLO, f: F) -> Result<Self::Output>
+    where
+        F: Fn(A::Real) -> A::Real;
+
+    /// Computes `f(A)` for a complex-valued function `f`. The result is a
+    /// normal matrix, but not Hermitian in general.
+    fn funh_complex_into<F>(self, uplo: UPLO, f: F) -> Result<Array2<A::Complex>>
+    where
+        F: Fn(A::Real) -> A::Complex;
+}
+
+impl<A, S> HermitianFunction<A> for ArrayBase<S, Ix2>
+where
+    A: Scalar + Lapack,
+    S: Data<Elem = A>,
+{
+    type Output = Array2<A>;
+
+    fn funh<F>(&self, uplo: UPLO, f: F) -> Result<Self::Output>
+    where
+        F: Fn(A::Real) -> A::Real,
+    {
+        let a = self.to_owned();
+        a.funh_into(uplo, f)
+    }
+
+    fn funh_complex<F>(&self, uplo: UPLO, f: F) -> Result<Array2<A::Complex>>
+    where
+        F: Fn(A::Real) -> A::Complex,
+    {
+        let a = self.to_owned();
+        a.funh_complex_into(uplo, f)
+    }
+}
+
+impl<A, S> HermitianFunctionInto<A> for ArrayBase<S, Ix2>
+where
+    A: Scalar + Lapack,
+    S: DataMut<Elem = A>,
+{
+    type Output = Array2<A>;
+
+    fn funh_into<F>(self, uplo: UPLO, f: F) -> Result<Self::Output>
+    where
+        F: Fn(A::Real) -> A::Real,
+    {
+        let (e, v) = self.eigh_into(uplo)?;
+        let fe = e.mapv(|e| A::from_real(f(e)));
+        Ok(compose_eigh(fe, &v))
+    }
+
+    fn funh_complex_into<F>(self, uplo: UPLO, f: F) -> Result<Array2<A::Complex>>
+    where
+        F: Fn(A::Real) -> A::Complex,
+    {
         let (e, v) = self.eigh_into(uplo)?;
-        let e_sqrt = Array::from_iter(e.iter().map(|r| Scalar::from_real(r.sqrt())));
-        let ev = e_sqrt.into_diagonal().apply2(&v.t());
-        Ok(v.apply2(&ev))
+        let fe = e.mapv(f);
+        Ok(compose_eigh(fe, &v.mapv(|v| v.as_c())))
     }
 }
+
+/// Compose `V diag(d) V^H` from the eigenvectors `V`
+fn compose_eigh<A, S>(d: Array1<A>, v: &ArrayBase<S, Ix2>) -> Array2<A>
+where
+    A: Scalar,
+    S: Data<Elem = A>,
+{
+    let vh: Array2<A> = conjugate(v);
+    let dvh = d.into_diagonal().apply2(&vh);
+    v.apply2(&dvh)
+}
@@ -0,0 +1,217 @@
+//! Equilibration (row and column scaling) of matrices
+//!
+//! A badly scaled matrix $A$ is replaced by $RAC$ using the diagonal matrices $R$ and $C$
+//! so that the largest element in each row and column has absolute value close to 1.
+//! The linear equation $Ax = b$ is then solved as
+//!
+//! $$ (RAC) (C^{-1} x) = R b $$
+//!
+//! For Hermitian positive definite matrices, the symmetric scaling $R = C = S$ is used
+//! to keep the matrix Hermitian.
+//!
+//! The factorizations with equilibration are available as
+//! [`Factorize::factorize_equilibrated`](crate::solve::Factorize::factorize_equilibrated) and
+//! [`FactorizeC::factorizec_equilibrated`](crate::cholesky::FactorizeC::factorizec_equilibrated).
+//!
+//! ```
+//! use ndarray::*;
+//! use ndarray_linalg::*;
+//!
+//! let a: Array2<f64> = array![[1e10, 2e10], [3.0, 4.0]];
+//! let e = a.equilibrate().unwrap();
+//! assert!(e.needs_row_scaling());
+//!
+//! let mut a_eq = a.clone();
+//! e.apply(&mut a_eq);
+//! assert!(a_eq.norm_max() <= 1.0 + 1e-9);
+//! e.unapply(&mut a_eq);
+//! assert_close_l2!(&a_eq, &a, 1e-9);
+//! ```
+
+use ndarray::*;
+use num_traits::{Float, One, Zero};
+
+use crate::error::*;
+use crate::layout::*;
+use crate::types::*;
+
+/// Threshold of the ratio of the smallest and largest scale factors used in LAPACK
+const THRESHOLD: f64 = 0.1;
+
+/// Row and column scale factors of a matrix
+#[derive(Debug, Clone)]
+pub struct Equilibration<A: Scalar> {
+    /// Row scale factors $R$
+    pub row: Array1<A::Real>,
+    /// Column scale factors $C$
+    pub col: Array1<A::Real>,
+    /// Ratio of the smallest to the largest row scale factor
+    pub rowcnd: A::Real,
+    /// Ratio of the smallest to the largest column scale factor
+    pub colcnd: A::Real,
+    /// Absolute value of the largest matrix element
+    pub amax: A::Real,
+}
+
+impl<A: Scalar> Equilibration<A> {
+    /// Whether the rows should be scaled, using the same criterion as LAPACK:
+    /// the row scale factors vary widely, or the matrix elements are close to underflow or overflow.
+    pub fn needs_row_scaling(&self) -> bool {
+        let small = A::Real::min_positive_value() / A::Real::epsilon();
+        let large = small.recip();
+        self.rowcnd < A::real(THRESHOLD) || self.amax < small || self.amax > large
+    }
+
+    /// Whether the columns should be scaled, i.e. the column scale factors vary widely
+    pub fn needs_col_scaling(&self) -> bool {
+        self.colcnd < A::real(THRESHOLD)
+    }
+
+    /// Overwrite $A$ by $RAC$
+    pub fn apply<S: DataMut<Elem = A>>(&self, a: &mut ArrayBase<S, Ix2>) {
+        scale(a, &self.row, &self.col, |x, s| x.mul_real(s));
+    }
+
+    /// Overwrite $RAC$ by $A$, i.e. the inverse of [`apply`](Self::apply)
+    pub fn unapply<S: DataMut<Elem = A>>(&self, a: &mut ArrayBase<S, Ix2>) {
+        scale(a, &self.row, &self.col, |x, s| x.div_real(s));
+    }
+
+    /// Overwrite the right-hand side $b$ (a vector or a matrix) of $Ax = b$ by $Rb$
+    pub fn scale_rhs<S, D>(&self, b: &mut ArrayBase<S, D>)
+    where
+        S: DataMut<Elem = A>,
+        D: RemoveAxis,
+    {
+        scale_rows(b, &self.row, |x, s| x.mul_real(s));
+    }
+
+    /// Recover the solution $x$ of $Ax = b$ from the solution $C^{-1} x$ of the equilibrated equation
+    pub fn unscale_solution<S, D>(&self, x: &mut ArrayBase<S, D>)
+    where
+        S: DataMut<Elem = A>,
+        D: RemoveAxis,
+    {
+        scale_rows(x, &self.col, |x, s| x.mul_real(s));
+    }
+
+    /// Row and column scale factors actually applied in the factorization,
+    /// where the unnecessary ones are replaced by ones.
+    pub(crate) fn into_applied(self) -> (Array1<A>, Array1<A>) {
+        let row = if self.needs_row_scaling() {
+            self.row.mapv(A::from_real)
+        } else {
+            Array1::ones(self.row.len())
+        };
+        let col = if self.needs_col_scaling() {
+            self.col.mapv(A::from_real)
+        } else {
+            Array1::ones(self.col.len())
+        };
+        (row, col)
+    }
+}
+
+fn scale<A, S, F>(a: &mut ArrayBase<S, Ix2>, r: &Array1<A::Real>, c: &Array1<A::Real>, f: F)
+where
+    A: Scalar,
+    S: DataMut<Elem = A>,
+    F: Fn(A, A::Real) -> A,
+{
+    assert_eq!(a.dim(), (r.len(), c.len()));
+    for ((i, j), x) in a.indexed_iter_mut() {
+        *x = f(f(*x, r[i]), c[j]);
+    }
+}
+
+/// Scale the `i`-th row (the `i`-th element for a vector) of `a` by `s[i]` using `f`
+pub(crate) fn scale_rows<A, T, S, D, F>(a: &mut ArrayBase<S, D>, s: &Array1<T>, f: F)
+where
+    A: Scalar,
+    T: Copy,
+    S: DataMut<Elem = A>,
+    D: RemoveAxis,
+    F: Fn(A, T) -> A,
+{
+    assert_eq!(a.len_of(Axis(0)), s.len());
+    for (mut row, &s) in a.axis_iter_mut(Axis(0)).zip(s.iter()) {
+        row.mapv_inplace(|x| f(x, s));
+    }
+}
+
+/// Compute the scale factors to equilibrate a matrix
+pub trait Equilibrate<A: Scalar> {
+    /// Scale factors of a general matrix (`*geequ`)
+    ///
+    /// Error
+    /// ------
+    /// - `LapackComputationalFailure` when a row or a column of the matrix is exactly zero
+    fn equilibrate(&self) -> Result<Equilibration<A>>;
+
+    /// Scale factors of a general matrix restricted to powers of the radix (`*geequb`),
+    /// so that the scaling introduces no rounding error
+    ///
+    /// Error
+    /// ------
+    /// - `LapackComputationalFailure` when a row or a column of the matrix is exactly zero
+    fn equilibrate_power_of_two(&self) -> Result<Equilibration<A>>;
+
+    /// Symmetric scale factors of a Hermitian positive definite matrix (`*poequ`),
+    /// where only the diagonal elements are used. `row` and `col` of the result are the same.
+    ///
+    /// Error
+    /// ------
+    /// - `LapackComputationalFailure` when a diagonal element is not positive
+    fn equilibrate_hermitian(&self) -> Result<Equilibration<A>>;
+}
+
+impl<A, S> Equilibrate<A> for ArrayBase<S, Ix2>
+where
+    A: Scalar + Lapack,
+    S: Data<Elem = A>,
+{
+    fn equilibrate(&self) -> Result<Equilibration<A>> {
+        if self.is_empty() {
+            return Ok(empty(self.dim()));
+        }
+        let out = A::equilibrate(self.layout()?, self.as_allocated()?)?;
+        Ok(into_equilibration(out))
+    }
+
+    fn equilibrate_power_of_two(&self) -> Result<Equilibration<A>> {
+        if self.is_empty() {
+            return Ok(empty(self.dim()));
+        }
+        let out = A::equilibrate_power_of_two(self.layout()?, self.as_allocated()?)?;
+        Ok(into_equilibration(out))
+    }
+
+    fn equilibrate_hermitian(&self) -> Result<Equilibration<A>> {
+        let l = self.square_layout()?;
+        if self.is_empty() {
+            return Ok(empty(self.dim()));
+        }
+        let out = A::equilibrate_hermitian(l, self.as_allocated()?)?;
+        Ok(into_equilibration(out))
+    }
+}
+
+fn into_equilibration<A: Scalar>(out: lax::EquilibrateOutput<A>) -> Equilibration<A> {
+    Equilibration {
+        row: Array1::from(out.r),
+        col: Array1::from(out.c),
+        rowcnd: out.rowcnd,
+        colcnd: out.colcnd,
+        amax: out.amax,
+    }
+}
+
+fn empty<A: Scalar>((m, n): (usize, usize)) -> Equilibration<A> {
+    Equilibration {
+        row: Array1::ones(m),
+        col: Array1::ones(n),
+        rowcnd: A::Real::one(),
+        colcnd: A::Real::one(),
+        amax: A::Real::zero(),
+    }
+}
@@ -35,4 +35,20 @@ pub enum LinalgError {
     /// Strides of the array is not supported
     #[error(transparent)]
     Shape(#[from] ShapeError),
+
+    /// Iterative algorithm does not converge
+    #[error("Not converged in {} iterations", iterations)]
+    NotConverged { iterations: usize },
+
+    /// Scalar parameter is out of its domain
+    #[error("Invalid parameter: {}", name)]
+    InvalidParameter { name: &'static str },
+
+    /// Matrix is not positive definite
+    #[error("Not positive definite")]
+    NotPositiveDefinite,
+
+    /// Total least squares solution does not exist since the problem is nongeneric
+    #[error("Total least squares solution does not exist")]
+    TotalLeastSquaresNotExist,
 }
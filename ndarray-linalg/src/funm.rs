@@ -0,0 +1,490 @@
+//! Functions of general square matrices
+//!
+//! $f(A)$ for an analytic function $f$ is computed by the Schur-Parlett algorithm
+//! (P. I. Davies and N. J. Higham, "A Schur-Parlett algorithm for computing matrix functions",
+//! SIAM J. Matrix Anal. Appl. 25 (2003) 464-485):
+//!
+//! 1. Compute the complex Schur decomposition $A = Z T Z^\dagger$
+//! 2. Reorder $T$ so that close eigenvalues are gathered into the same diagonal block
+//! 3. Evaluate $f$ on each diagonal block by the Taylor series around the mean of its eigenvalues
+//! 4. Compute the off-diagonal blocks by the block Parlett recurrence,
+//!    i.e. solving Sylvester equations
+//!
+//! The matrix sign function [`MatrixSign`] and real powers [`MatrixPower`] are computed by the specialized algorithms.
+//!
+//! For Hermitian matrices, the eigendecomposition-based [`HermitianFunction`](crate::eigh::HermitianFunction)
+//! is faster and more accurate.
+
+use lax::{Schur_, UPLO};
+use ndarray::*;
+use num_traits::{Float, One, ToPrimitive, Zero};
+
+use crate::error::*;
+use crate::layout::*;
+use crate::norm::*;
+use crate::opnorm::*;
+use crate::solve::*;
+use crate::triangular::*;
+use crate::types::*;
+
+/// Eigenvalues whose distance is smaller than this are put into the same block
+const BLOCKING_DELTA: f64 = 0.1;
+
+/// Maximum number of Taylor series terms for each diagonal block
+const MAX_TAYLOR_TERMS: usize = 250;
+
+/// Maximum number of Newton iterations for the matrix sign function
+const MAX_SIGN_ITERATIONS: usize = 100;
+
+/// Degree of the Padé approximant used in the matrix power
+const PADE_DEGREE: usize = 7;
+
+/// Bound of $\|I - T\|_1$ for which the Padé approximant of degree 7 is accurate in double precision
+const PADE_THETA: f64 = 2.79e-1;
+
+/// Maximum number of square roots taken in the matrix power
+const MAX_SQUARE_ROOTS: usize = 64;
+
+/// Function of a general square matrix
+pub trait MatrixFunction<A: Scalar> {
+    /// Compute $f(A)$ by the Schur-Parlett algorithm
+    ///
+    /// `f(z, k)` must return the `k`-th derivative $f^{(k)}(z)$.
+    /// The derivatives are used only for the eigenvalues close to each other,
+    /// and `f(z, 0)` is sufficient if the eigenvalues of $A$ are well separated.
+    /// The result is always complex even if $A$ and $f$ are real.
+    ///
+    /// Error
+    /// ------
+    /// - `NotConverged` when the Taylor series of `f` around a cluster of close eigenvalues does not converge
+    ///
+    /// ```
+    /// use ndarray::*;
+    /// use ndarray_linalg::*;
+    ///
+    /// // Jordan block, whose exponential is exp(2) [[1, 1], [0, 1]]
+    /// let a: Array2<f64> = array![[2.0, 1.0], [0.0, 2.0]];
+    /// let e = a.funm(|z, _| z.exp()).unwrap();
+    /// let expected = array![[1.0, 1.0], [0.0, 1.0]].mapv(|x: f64| c64::new(x * 2.0_f64.exp(), 0.0));
+    /// assert_close_l2!(&e, &expected, 1e-9);
+    /// ```
+    fn funm<F>(&self, f: F) -> Result<Array2<A::Complex>>
+    where
+        F: Fn(A::Complex, usize) -> A::Complex;
+}
+
+impl<A, S> MatrixFunction<A> for ArrayBase<S, Ix2>
+where
+    A: Scalar + Lapack,
+    A::Complex: Lapack,
+    S: Data<Elem = A>,
+{
+    fn funm<F>(&self, f: F) -> Result<Array2<A::Complex>>
+    where
+        F: Fn(A::Complex, usize) -> A::Complex,
+    {
+        self.ensure_square()?;
+        let (mut t, mut z) = complex_schur(self)?;
+        let blocks = reorder_schur(&mut t, &mut z)?;
+        let fz = parlett(&t, &blocks, &f)?;
+
+        // f(A) = Z f(T) Z^H
+        let zh = z.t().mapv(|x| x.conj());
+        Ok(z.dot(&fz).dot(&zh))
+    }
+}
+
+/// Matrix sign function
+pub trait MatrixSign {
+    type Output;
+    /// Compute the matrix sign function by the scaled Newton iteration
+    ///
+    /// $$ X_{k+1} = \frac{1}{2} \left(\mu_k X_k + \mu_k^{-1} X_k^{-1}\right), \quad X_0 = A $$
+    ///
+    /// with the determinantal scaling $\mu_k = |\det X_k|^{-1/n}$.
+    /// The result has the eigenvalues $\pm 1$, and $(I + \mathrm{sign}(A))/2$ is the spectral projector
+    /// onto the invariant subspace of $A$ corresponding to the right half-plane.
+    ///
+    /// ```
+    /// use ndarray::*;
+    /// use ndarray_linalg::*;
+    ///
+    /// let a: Array2<f64> = array![[2.0, 1.0], [0.0, -3.0]];
+    /// let s = a.signm().unwrap();
+    /// assert_close_l2!(&s, &array![[1.0, 0.4], [0.0, -1.0]], 1e-9);
+    /// ```
+    ///
+    /// Error
+    /// ------
+    /// - `NotConverged` when the iteration does not converge, e.g. when $A$ has purely imaginary eigenvalues
+    /// - `Lapack` error when some $X_k$ is singular
+    fn signm(&self) -> Result<Self::Output>;
+}
+
+impl<A, S> MatrixSign for ArrayBase<S, Ix2>
+where
+    A: Scalar + Lapack,
+    S: Data<Elem = A>,
+{
+    type Output = Array2<A>;
+
+    fn signm(&self) -> Result<Self::Output> {
+        self.ensure_square()?;
+        let n = self.nrows();
+        let mut x = self.to_owned();
+        if n == 0 {
+            return Ok(x);
+        }
+        // The iteration converges quadratically,
+        // i.e. the error of X_{k+1} is about the square of ||X_{k+1} - X_k||
+        let tol = Float::sqrt(A::real(n) * A::Real::epsilon());
+        let mut scaling = true;
+        for _ in 0..MAX_SIGN_ITERATIONS {
+            let lu = x.factorize()?;
+            let x_inv = lu.inv()?;
+            let mu = if scaling {
+                let (_, ln_det) = lu.sln_det()?;
+                Float::exp(-ln_det / A::real(n))
+            } else {
+                A::Real::one()
+            };
+            let x_new = (x.mapv(|v| v.mul_real(mu)) + x_inv.mapv(|v| v.div_real(mu)))
+                .mapv(|v| v.div_real(A::real(2)));
+            let diff = (&x_new - &x).norm_l2() / x_new.norm_l2();
+            x = x_new;
+            if diff <= tol {
+                return Ok(x);
+            }
+            // Scaling slows down the convergence in the final stage
+            if diff < A::real(1e-2) {
+                scaling = false;
+            }
+        }
+        Err(LinalgError::NotConverged {
+            iterations: MAX_SIGN_ITERATIONS,
+        })
+    }
+}
+
+/// Real power of a general square matrix
+pub trait MatrixPower<A: Scalar> {
+    /// Compute the principal power $A^p$ for real $p$ by the Schur-Padé algorithm
+    /// (N. J. Higham and L. Lin, "A Schur-Padé algorithm for fractional powers of a matrix",
+    /// SIAM J. Matrix Anal. Appl. 32 (2011) 1056-1078)
+    ///
+    /// The integer part of $p$ is computed by multiplications (and an inversion for negative $p$),
+    /// and the fractional part by repeated square roots of the triangular Schur factor
+    /// followed by a Padé approximant of $(1-x)^p$.
+    /// $A$ must not have eigenvalues on the closed negative real axis if $p$ is not an integer.
+    /// [LinalgError::InvalidParameter] is returned if $p$ is not finite
+    /// or its integer part does not fit in `i32`.
+    ///
+    /// ```
+    /// use ndarray::*;
+    /// use ndarray_linalg::*;
+    ///
+    /// let a: Array2<f64> = array![[4.0, 1.0], [0.0, 9.0]];
+    /// let s = a.powm(0.5).unwrap();
+    /// let expected = array![[2.0, 0.2], [0.0, 3.0]].mapv(|x| c64::new(x, 0.0));
+    /// assert_close_l2!(&s, &expected, 1e-9);
+    /// ```
+    fn powm(&self, p: A::Real) -> Result<Array2<A::Complex>>;
+}
+
+impl<A, S> MatrixPower<A> for ArrayBase<S, Ix2>
+where
+    A: Scalar + Lapack,
+    A::Complex: Lapack,
+    S: Data<Elem = A>,
+{
+    fn powm(&self, p: A::Real) -> Result<Array2<A::Complex>> {
+        self.ensure_square()?;
+        let k = p.trunc();
+        let k_int = k
+            .to_i32()
+            .ok_or(LinalgError::InvalidParameter { name: "p" })?;
+        let (t, z) = complex_schur(self)?;
+        let tk = pow_triangular_int(&t, k_int)?;
+        let tp = if p == k {
+            tk
+        } else {
+            tk.dot(&pow_triangular_frac(&t, p - k)?)
+        };
+        let zh = z.t().mapv(|x| x.conj());
+        Ok(z.dot(&tp).dot(&zh))
+    }
+}
+
+/// $T^k$ of an upper triangular $T$ by the binary powering
+fn pow_triangular_int<A: Scalar + Lapack>(t: &Array2<A>, k: i32) -> Result<Array2<A>> {
+    let n = t.nrows();
+    let mut base = if k < 0 {
+        t.solve_triangular(UPLO::Upper, Diag::NonUnit, &Array2::eye(n))?
+    } else {
+        t.clone()
+    };
+    let mut k = k.unsigned_abs();
+    let mut result = Array2::eye(n);
+    while k > 0 {
+        if k & 1 == 1 {
+            result = result.dot(&base);
+        }
+        k >>= 1;
+        if k > 0 {
+            base = base.dot(&base);
+        }
+    }
+    Ok(result)
+}
+
+/// $T^p$ of an upper triangular $T$ for $-1 < p < 1$ by the Schur-Padé algorithm
+fn pow_triangular_frac<A: Scalar + Lapack>(t: &Array2<A>, p: A::Real) -> Result<Array2<A>> {
+    let n = t.nrows();
+    let eye = Array2::<A>::eye(n);
+    let theta = A::real(PADE_THETA);
+
+    // Take square roots until T^{1/2^s} is close to the identity
+    let mut r = t.clone();
+    let mut s = 0;
+    while (&r - &eye).opnorm_one()? > theta {
+        if s == MAX_SQUARE_ROOTS {
+            return Err(LinalgError::NotConverged {
+                iterations: MAX_SQUARE_ROOTS,
+            });
+        }
+        r = sqrt_triangular(&r);
+        s += 1;
+    }
+
+    // Padé approximant of (1 - x)^p in the continued fraction form
+    //   1 + c_1 x / (1 + c_2 x / (1 + ... c_{2m} x))
+    // with c_1 = -p, c_{2j} = (-j + p) / 2(2j - 1), c_{2j+1} = (-j - p) / 2(2j + 1)
+    let coef = |i: usize| -> A::Real {
+        let j = A::real(i / 2);
+        match (i, i % 2) {
+            (1, _) => -p,
+            (_, 0) => (-j + p) / (A::real(2) * (A::real(2) * j - A::Real::one())),
+            _ => (-j - p) / (A::real(2) * (A::real(2) * j + A::Real::one())),
+        }
+    };
+    let x = &eye - &r;
+    let mut y = x.mapv(|v| v.mul_real(coef(2 * PADE_DEGREE)));
+    for i in (1..(2 * PADE_DEGREE)).rev() {
+        let denom = &eye + &y;
+        y = denom
+            .solve_triangular(UPLO::Upper, Diag::NonUnit, &x)?
+            .mapv(|v| v.mul_real(coef(i)));
+    }
+    let mut r = &eye + &y;
+
+    // Square back, recomputing the diagonal from the eigenvalues for accuracy
+    let ln_eigs: Vec<A> = t.diag().iter().map(|e| e.ln()).collect();
+    let set_diag = |r: &mut Array2<A>, q: A::Real| {
+        for (i, ln_e) in ln_eigs.iter().enumerate() {
+            r[(i, i)] = ln_e.mul_real(q).exp();
+        }
+    };
+    set_diag(&mut r, p / Float::powi(A::real(2), s as i32));
+    for j in (0..s).rev() {
+        r = r.dot(&r);
+        set_diag(&mut r, p / Float::powi(A::real(2), j as i32));
+    }
+    Ok(r)
+}
+
+/// Principal square root of an upper triangular matrix (Björck-Hammarling)
+fn sqrt_triangular<A: Scalar>(t: &Array2<A>) -> Array2<A> {
+    let n = t.nrows();
+    let mut u = Array2::zeros((n, n).f());
+    for j in 0..n {
+        u[(j, j)] = t[(j, j)].sqrt();
+        for i in (0..j).rev() {
+            let sum = ((i + 1)..j).fold(A::zero(), |acc, k| acc + u[(i, k)] * u[(k, j)]);
+            u[(i, j)] = (t[(i, j)] - sum) / (u[(i, i)] + u[(j, j)]);
+        }
+    }
+    u
+}
+
+/// Complex Schur decomposition $A = Z T Z^\dagger$ in F-layout
+fn complex_schur<A, S>(a: &ArrayBase<S, Ix2>) -> Result<(Array2<A::Complex>, Array2<A::Complex>)>
+where
+    A: Scalar + Lapack,
+    A::Complex: Lapack,
+    S: Data<Elem = A>,
+{
+    let n = a.nrows();
+    let mut t = Array2::zeros((n, n).f());
+    t.zip_mut_with(a, |t, a| *t = a.as_c());
+    if n == 0 {
+        return Ok((t, Array2::zeros((0, 0).f())));
+    }
+    let layout = t.square_layout()?;
+    let schur = A::Complex::schur(layout, t.as_allocated_mut()?)?;
+    let z = Array2::from_shape_vec((n, n).f(), schur.z).unwrap();
+    Ok((t, z))
+}
+
+/// Reorder the Schur form so that the clusters of close eigenvalues become contiguous,
+/// and returns the ranges of the diagonal blocks
+fn reorder_schur<A: Scalar + Lapack>(
+    t: &mut Array2<A>,
+    z: &mut Array2<A>,
+) -> Result<Vec<std::ops::Range<usize>>> {
+    let n = t.nrows();
+    let delta = A::real(BLOCKING_DELTA);
+
+    // Cluster eigenvalues by the transitive closure of |λ_i - λ_j| <= δ
+    let mut root: Vec<usize> = (0..n).collect();
+    fn find(root: &mut Vec<usize>, mut i: usize) -> usize {
+        while root[i] != i {
+            root[i] = root[root[i]];
+            i = root[i];
+        }
+        i
+    }
+    for i in 0..n {
+        for j in (i + 1)..n {
+            if (t[(i, i)] - t[(j, j)]).abs() <= delta {
+                let (ri, rj) = (find(&mut root, i), find(&mut root, j));
+                root[ri.max(rj)] = ri.min(rj);
+            }
+        }
+    }
+    let mut labels: Vec<usize> = (0..n).map(|i| find(&mut root, i)).collect();
+
+    // Order the clusters by the mean position of their eigenvalues to reduce the swaps
+    let mut clusters: Vec<usize> = labels.clone();
+    clusters.sort_unstable();
+    clusters.dedup();
+    let mean_position = |c: usize| {
+        let (sum, count) = labels
+            .iter()
+            .enumerate()
+            .filter(|(_, &l)| l == c)
+            .fold((0, 0), |(s, k), (i, _)| (s + i, k + 1));
+        sum as f64 / count as f64
+    };
+    clusters.sort_by(|&a, &b| mean_position(a).partial_cmp(&mean_position(b)).unwrap());
+
+    let layout = t.square_layout()?;
+    let mut blocks = Vec::with_capacity(clusters.len());
+    let mut pos = 0;
+    for c in clusters {
+        let start = pos;
+        for j in start..n {
+            if labels[j] != c {
+                continue;
+            }
+            if j != pos {
+                A::schur_reorder(layout, t.as_allocated_mut()?, z.as_allocated_mut()?, j, pos)?;
+                labels[pos..=j].rotate_right(1);
+            }
+            pos += 1;
+        }
+        blocks.push(start..pos);
+    }
+    Ok(blocks)
+}
+
+/// Evaluate `f` on the reordered upper triangular `t` by the block Parlett recurrence
+fn parlett<A, F>(t: &Array2<A>, blocks: &[std::ops::Range<usize>], f: &F) -> Result<Array2<A>>
+where
+    A: Scalar + Lapack,
+    F: Fn(A, usize) -> A,
+{
+    let n = t.nrows();
+    let mut fz = Array2::zeros((n, n).f());
+    for b in blocks {
+        let tb = t.slice(s![b.clone(), b.clone()]);
+        let fb = atomic_block(&tb, f)?;
+        fz.slice_mut(s![b.clone(), b.clone()]).assign(&fb);
+    }
+
+    // T_ii F_ij - F_ij T_jj = F_ii T_ij - T_ij F_jj + sum_k (F_ik T_kj - T_ik F_kj)
+    for j in 0..blocks.len() {
+        for i in (0..j).rev() {
+            let (bi, bj) = (blocks[i].clone(), blocks[j].clone());
+            let mut c = fz
+                .slice(s![bi.clone(), bi.clone()])
+                .dot(&t.slice(s![bi.clone(), bj.clone()]))
+                - t.slice(s![bi.clone(), bj.clone()])
+                    .dot(&fz.slice(s![bj.clone(), bj.clone()]));
+            for bk in &blocks[(i + 1)..j] {
+                c = c + fz
+                    .slice(s![bi.clone(), bk.clone()])
+                    .dot(&t.slice(s![bk.clone(), bj.clone()]))
+                    - t.slice(s![bi.clone(), bk.clone()])
+                        .dot(&fz.slice(s![bk.clone(), bj.clone()]));
+            }
+            let tii = t.slice(s![bi.clone(), bi.clone()]).to_owned();
+            let tjj = t.slice(s![bj.clone(), bj.clone()]).to_owned();
+            let scale = A::sylvester_triangular(
+                tii.layout()?,
+                tii.as_allocated()?,
+                tjj.layout()?,
+                tjj.as_allocated()?,
+                c.layout()?,
+                c.as_allocated_mut()?,
+            )?;
+            fz.slice_mut(s![bi, bj])
+                .assign(&c.mapv(|x| x.div_real(scale)));
+        }
+    }
+    Ok(fz)
+}
+
+/// Evaluate `f` on an upper triangular block with close eigenvalues
+/// by the Taylor series around the mean of the eigenvalues (Algorithm 2.6 of Davies-Higham)
+fn atomic_block<A, S, F>(t: &ArrayBase<S, Ix2>, f: &F) -> Result<Array2<A>>
+where
+    A: Scalar + Lapack,
+    S: Data<Elem = A>,
+    F: Fn(A, usize) -> A,
+{
+    let m = t.nrows();
+    if m == 1 {
+        return Ok(Array2::from_elem((1, 1), f(t[(0, 0)], 0)));
+    }
+    let tol = A::Real::epsilon();
+    let sigma = t.diag().sum().div_real(A::real(m));
+    let mt = t.to_owned() - &Array2::from_diag(&Array1::from_elem(m, sigma));
+
+    // μ = ||y||_∞ where (I - |N|) y = e, N is the strictly upper triangular part of T
+    let mut y = Array1::<A::Real>::zeros(m);
+    for i in (0..m).rev() {
+        y[i] = A::Real::one()
+            + ((i + 1)..m)
+                .map(|j| t[(i, j)].abs() * y[j])
+                .sum::<A::Real>();
+    }
+    let mu = y.iter().cloned().fold(A::Real::zero(), A::Real::max);
+
+    let mut fm = Array2::from_diag(&Array1::from_elem(m, f(sigma, 0)));
+    let mut p = mt.clone();
+    for s in 1..MAX_TAYLOR_TERMS {
+        let term = p.mapv(|x| x * f(sigma, s));
+        fm += &term;
+        p = p.dot(&mt).mapv(|x| x.div_real(A::real(s + 1)));
+        let norm_f = fm.norm_l2();
+        if term.norm_l2() <= tol * norm_f {
+            // Successive terms are close, so check the truncation error bound
+            let mut omega = A::Real::zero();
+            let mut factorial = A::Real::one();
+            for r in 0..m {
+                if r > 0 {
+                    factorial *= A::real(r);
+                }
+                for k in 0..m {
+                    omega = omega.max(f(t[(k, k)], s + r + 1).abs() / factorial);
+                }
+            }
+            if mu * omega * p.norm_l2() <= tol * norm_f {
+                return Ok(fm);
+            }
+        }
+    }
+    Err(LinalgError::NotConverged {
+        iterations: MAX_TAYLOR_TERMS,
+    })
+}
@@ -31,7 +31,21 @@ where
     Sh: ShapeBuilder<Dim = D>,
 {
     let mut rng = thread_rng();
-    ArrayBase::from_shape_fn(sh, |_| A::rand(&mut rng))
+    random_using(sh, &mut rng)
+}
+
+/// Generate random array with the given random number generator
+///
+/// This is reproducible with a seeded generator, e.g. `StdRng::seed_from_u64`.
+pub fn random_using<A, S, Sh, D, R>(sh: Sh, rng: &mut R) -> ArrayBase<S, D>
+where
+    A: Scalar,
+    S: DataOwned<Elem = A>,
+    D: Dimension,
+    Sh: ShapeBuilder<Dim = D>,
+    R: Rng,
+{
+    ArrayBase::from_shape_fn(sh, |_| A::rand(rng))
 }
 
 /// Generate random unitary matrix using QR decomposition
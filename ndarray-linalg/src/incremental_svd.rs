@@ -0,0 +1,205 @@
+//! Incremental singular value decomposition
+//!
+//! [IncrementalSvd] maintains a truncated SVD $A \approx U \Sigma V^\dagger$ of a matrix
+//! whose columns arrive as a stream, by the algorithm of Brand (2002).
+//! When new columns $C$ are appended, they are decomposed by an [Orthogonalizer]
+//! into the span of $U$ and the orthonormal residual directions $J$, i.e. $[U \\; J] = Q$ and
+//!
+//! $$ [U \Sigma V^\dagger \\; C] = Q \begin{pmatrix} R_U \Sigma & P \end{pmatrix}
+//!    \begin{pmatrix} V^\dagger & 0 \\\\ 0 & I \end{pmatrix}, $$
+//!
+//! where $R_U$ and $P$ are the coefficients of $U$ and $C$ in the basis $Q$.
+//! Only the SVD of the small middle matrix is computed, and the result is truncated to the rank.
+//! Since the columns of $U$ are passed through the orthogonalizer again,
+//! $U$ is reorthogonalized at every update, while the drift of $V$ can be corrected by
+//! [IncrementalSvd::reorthogonalize].
+//!
+//! ```
+//! use ndarray::*;
+//! use ndarray_linalg::*;
+//!
+//! let a: Array2<f64> = random((10, 8));
+//! let (u, s, vt) = a.slice(s![.., ..4]).svd(true, true).unwrap();
+//! let (u, vt) = (u.unwrap(), vt.unwrap());
+//! let mut svd = IncrementalSvd::new(u.slice(s![.., ..4]).to_owned(), s, vt);
+//! svd.append_columns(&a.slice(s![.., 4..])).unwrap();
+//!
+//! let s_ref = a.svd(false, false).unwrap().1;
+//! assert_close_l2!(svd.singular_values(), &s_ref, 1e-9);
+//! ```
+
+use ndarray::*;
+use num_traits::{Float, Zero};
+
+use crate::error::*;
+use crate::krylov::*;
+use crate::qr::*;
+use crate::svddc::*;
+use crate::types::*;
+
+/// Truncated SVD $A \approx U \Sigma V^\dagger$ updated by appending columns
+#[derive(Debug, Clone)]
+pub struct IncrementalSvd<A: Scalar> {
+    u: Array2<A>,
+    s: Array1<A::Real>,
+    vt: Array2<A>,
+    rank: usize,
+    tolerance: A::Real,
+}
+
+impl<A: Scalar + Lapack> IncrementalSvd<A> {
+    /// Start from the `m x k` left singular vectors `u`, the `k` singular values `s`
+    /// and the `k x n` right singular vectors `vt`,
+    /// e.g. the result of [TruncatedSvd](crate::TruncatedSvd)
+    ///
+    /// Panics
+    /// -------
+    /// - if the shapes of `u`, `s` and `vt` are inconsistent
+    pub fn new(u: Array2<A>, s: Array1<A::Real>, vt: Array2<A>) -> Self {
+        assert_eq!(u.ncols(), s.len());
+        assert_eq!(vt.nrows(), s.len());
+        IncrementalSvd {
+            u,
+            s,
+            vt,
+            rank: usize::MAX,
+            tolerance: A::real(100.0) * <A::Real as Float>::epsilon(),
+        }
+    }
+
+    /// Start from the empty matrix with `rows` rows
+    pub fn empty(rows: usize) -> Self {
+        Self::new(
+            Array2::zeros((rows, 0)),
+            Array1::zeros(0),
+            Array2::zeros((0, 0)),
+        )
+    }
+
+    /// Maximum number of singular values kept after each update, which is unlimited by default
+    pub fn rank(mut self, rank: usize) -> Self {
+        self.rank = rank;
+        self
+    }
+
+    /// Relative tolerance to the largest singular value, below which
+    /// the residual directions and the singular values are discarded
+    pub fn tolerance(mut self, tolerance: A::Real) -> Self {
+        self.tolerance = tolerance;
+        self
+    }
+
+    /// Left singular vectors $U$
+    pub fn u(&self) -> &Array2<A> {
+        &self.u
+    }
+
+    /// Singular values in descending order
+    pub fn singular_values(&self) -> &Array1<A::Real> {
+        &self.s
+    }
+
+    /// Right singular vectors $V^\dagger$
+    pub fn vt(&self) -> &Array2<A> {
+        &self.vt
+    }
+
+    /// Returns the left singular vectors, singular values and right singular vectors
+    pub fn values_vectors(&self) -> (Array2<A>, Array1<A::Real>, Array2<A>) {
+        (self.u.clone(), self.s.clone(), self.vt.clone())
+    }
+
+    /// Append the columns `c` using the modified Gram-Schmidt orthogonalizer
+    pub fn append_columns<S>(&mut self, c: &ArrayBase<S, Ix2>) -> Result<()>
+    where
+        S: Data<Elem = A>,
+    {
+        let scale = c
+            .axis_iter(Axis(1))
+            .map(|col| Float::sqrt(col.iter().map(|x| x.square()).sum::<A::Real>()))
+            .chain(self.s.iter().cloned())
+            .fold(A::Real::zero(), Float::max);
+        let ortho = MGS::new(self.u.nrows(), self.tolerance * scale);
+        self.append_columns_with(c, ortho)
+    }
+
+    /// Append the columns `c` using the given empty orthogonalizer,
+    /// whose tolerance determines the residual directions to be added
+    pub fn append_columns_with<S, O>(&mut self, c: &ArrayBase<S, Ix2>, mut ortho: O) -> Result<()>
+    where
+        S: Data<Elem = A>,
+        O: Orthogonalizer<Elem = A>,
+    {
+        let (m, k) = self.u.dim();
+        let p = c.ncols();
+        if c.nrows() != m || ortho.dim() != m || !ortho.is_empty() {
+            return Err(ShapeError::from_kind(ErrorKind::IncompatibleShape).into());
+        }
+
+        // Coefficients of [U C] in the basis Q spanned by the orthogonalizer
+        let coefs: Vec<_> = self
+            .u
+            .axis_iter(Axis(1))
+            .chain(c.axis_iter(Axis(1)))
+            .map(|col| match ortho.append(col) {
+                AppendResult::Added(coef) => coef,
+                AppendResult::Dependent(coef) => coef.slice(s![..-1]).to_owned(),
+            })
+            .collect();
+        let r = ortho.len();
+        let mut middle = Array2::zeros((r, k + p));
+        for (j, coef) in coefs.iter().enumerate() {
+            middle.slice_mut(s![..coef.len(), j]).assign(coef);
+        }
+        for (mut col, &s) in middle.axis_iter_mut(Axis(1)).zip(&self.s) {
+            col.mapv_inplace(|x| x.mul_real(s));
+        }
+
+        let n = self.vt.ncols();
+        if r == 0 {
+            self.u = Array2::zeros((m, 0));
+            self.s = Array1::zeros(0);
+            self.vt = Array2::zeros((0, n + p));
+            return Ok(());
+        }
+        let (um, sm, vmt) = middle.svddc_into(UVTFlag::Some)?;
+        let (um, vmt) = (um.unwrap(), vmt.unwrap());
+        let threshold = self.tolerance * sm[0];
+        let keep = sm
+            .iter()
+            .take(self.rank)
+            .filter(|&&s| s > threshold)
+            .count();
+
+        self.u = ortho.get_q().dot(&um.slice(s![.., ..keep]));
+        let mut vt = Array2::zeros((keep, n + p));
+        vt.slice_mut(s![.., ..n])
+            .assign(&vmt.slice(s![..keep, ..k]).dot(&self.vt));
+        vt.slice_mut(s![.., n..])
+            .assign(&vmt.slice(s![..keep, k..]));
+        self.vt = vt;
+        self.s = sm.slice(s![..keep]).to_owned();
+        Ok(())
+    }
+
+    /// Reorthogonalize the right singular vectors which lose the orthogonality by rounding errors
+    ///
+    /// This takes the QR decomposition $V = Q_V R_V$ and recomputes the SVD of $\Sigma R_V^\dagger$.
+    pub fn reorthogonalize(&mut self) -> Result<()> {
+        let k = self.s.len();
+        if k == 0 {
+            return Ok(());
+        }
+        let v = self.vt.t().mapv(|x| x.conj());
+        let (qv, rv) = v.qr_into()?;
+        let mut middle = rv.t().mapv(|x| x.conj());
+        for (mut row, &s) in middle.axis_iter_mut(Axis(0)).zip(&self.s) {
+            row.mapv_inplace(|x| x.mul_real(s));
+        }
+        let (um, sm, vmt) = middle.svddc_into(UVTFlag::Some)?;
+        self.u = self.u.dot(&um.unwrap());
+        self.s = sm;
+        self.vt = vmt.unwrap().dot(&qv.t().mapv(|x| x.conj()));
+        Ok(())
+    }
+}
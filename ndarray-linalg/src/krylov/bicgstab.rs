@@ -0,0 +1,144 @@
+//! Stabilized biconjugate gradient method
+//!
+//! - [Biconjugate gradient stabilized method - Wikipedia](https://en.wikipedia.org/wiki/Biconjugate_gradient_stabilized_method)
+//!
+//! BiCGSTAB solves $Ax = b$ for a general operator $A$ by short recurrences,
+//! i.e. the memory and the cost of each iteration do not grow unlike [GMRES](super::gmres).
+//! Each iteration applies $A$ twice, and smooths the irregular convergence of BiCG
+//! by a local residual minimization.
+//! With a preconditioner $M \approx A^{-1}$, the iteration runs on $AMy = b$ and updates $x = My$ directly,
+//! so that the stopping criterion is checked on the residual of the original system.
+//!
+//! ```
+//! use ndarray::*;
+//! use ndarray_linalg::{krylov::*, *};
+//!
+//! let a: Array2<f64> = random((20, 20));
+//! let a = a + Array2::<f64>::eye(20) * 20.0;
+//! let b: Array1<f64> = random(20);
+//! let result = Bicgstab::new().tolerance(1e-10).solve(&a, &b).unwrap();
+//! assert!(result.is_converged());
+//! assert_close_l2!(&a.dot(&result.solution), &b, 1e-9);
+//! ```
+
+use super::*;
+use crate::{error::*, inner::*, norm::Norm};
+use num_traits::Zero;
+
+/// BiCGSTAB solver for a general operator
+///
+/// Each iteration records the norm of the residual after the stabilizing step,
+/// or after the BiCG half step if it already reaches the tolerance.
+#[derive(Debug, Clone)]
+pub struct Bicgstab<A: Scalar> {
+    options: IterativeSolverOptions<A>,
+}
+
+impl<A: Scalar + Lapack> Default for Bicgstab<A> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<A: Scalar + Lapack> Bicgstab<A> {
+    /// Create a solver with the default [IterativeSolverOptions]
+    pub fn new() -> Self {
+        Bicgstab {
+            options: IterativeSolverOptions::default(),
+        }
+    }
+}
+
+impl<A: Scalar> IterativeSolver<A> for Bicgstab<A> {
+    fn options_mut(&mut self) -> &mut IterativeSolverOptions<A> {
+        &mut self.options
+    }
+}
+
+impl<A: Scalar + Lapack> PreconditionedSolver<A> for Bicgstab<A> {
+    fn solve_with<F, P, S>(
+        &self,
+        a: &F,
+        m: Option<&P>,
+        b: &ArrayBase<S, Ix1>,
+    ) -> Result<IterativeSolverResult<A>>
+    where
+        F: LinearOperator<Elem = A>,
+        P: LinearOperator<Elem = A>,
+        S: Data<Elem = A>,
+    {
+        let n = b.len();
+        let mut x = self.options.initial_solution(n)?;
+        let threshold = self.options.threshold(b.norm_l2());
+        let max_iterations = self.options.iteration_limit(n);
+
+        let mut r = residual(a, &x, b)?;
+        let mut r_norm = r.norm_l2();
+        let mut residual_history = vec![r_norm];
+        // The shadow residual
+        let r0 = r.clone();
+        let mut p: Array1<A> = Array1::zeros(n);
+        let mut v: Array1<A> = Array1::zeros(n);
+        let (mut rho, mut alpha, mut omega) = (A::one(), A::one(), A::one());
+        let mut iterations = 0;
+        let mut breakdown = false;
+
+        while r_norm > threshold && iterations < max_iterations {
+            let rho_next = r0.inner(&r);
+            if rho_next.is_zero() || omega.is_zero() {
+                breakdown = true;
+                break;
+            }
+            let beta = (rho_next / rho) * (alpha / omega);
+            rho = rho_next;
+            azip!((p in &mut p, &r in &r, &v in &v) *p = r + beta * (*p - omega * v));
+
+            let p_hat = precondition(m, &p);
+            v = a.apply(&p_hat);
+            let r0v = r0.inner(&v);
+            if r0v.is_zero() {
+                breakdown = true;
+                break;
+            }
+            alpha = rho / r0v;
+            x.scaled_add(alpha, &p_hat);
+            // r becomes the intermediate residual s
+            r.scaled_add(-alpha, &v);
+            iterations += 1;
+            r_norm = r.norm_l2();
+            if r_norm <= threshold {
+                residual_history.push(r_norm);
+                break;
+            }
+
+            let s_hat = precondition(m, &r);
+            let t = a.apply(&s_hat);
+            let tt = t.inner(&t).re();
+            if tt.is_zero() {
+                residual_history.push(r_norm);
+                breakdown = true;
+                break;
+            }
+            omega = t.inner(&r).div_real(tt);
+            x.scaled_add(omega, &s_hat);
+            r.scaled_add(-omega, &t);
+            r_norm = r.norm_l2();
+            residual_history.push(r_norm);
+        }
+
+        let status = if r_norm <= threshold {
+            SolverStatus::Converged
+        } else if breakdown {
+            SolverStatus::Breakdown
+        } else {
+            SolverStatus::MaxIterationsReached
+        };
+        Ok(IterativeSolverResult {
+            residual_norm: residual(a, &x, b)?.norm_l2(),
+            solution: x,
+            iterations,
+            residual_history,
+            status,
+        })
+    }
+}
@@ -0,0 +1,123 @@
+//! Conjugate gradient method
+//!
+//! - [Conjugate gradient method - Wikipedia](https://en.wikipedia.org/wiki/Conjugate_gradient_method)
+//!
+//! CG solves $Ax = b$ for a Hermitian positive definite operator $A$ using only the products with $A$
+//! and a few vectors, i.e. without factorizing $A$ unlike [Cholesky](crate::cholesky).
+//! The error is minimized in the $A$-norm over the Krylov subspace $x_0 + \mathcal{K}_k(A, r_0)$,
+//! and the number of iterations grows with $\sqrt{\kappa(A)}$.
+//! The preconditioned CG (PCG) with a Hermitian positive definite preconditioner $M \approx A^{-1}$
+//! iterates in the same way on the system whose condition number is $\kappa(MA)$.
+//!
+//! ```
+//! use ndarray::*;
+//! use ndarray_linalg::{krylov::*, *};
+//!
+//! let a: Array2<f64> = random_hpd(30);
+//! let b: Array1<f64> = random(30);
+//! let result = ConjugateGradient::new().tolerance(1e-10).solve(&a, &b).unwrap();
+//! assert!(result.is_converged());
+//! assert_close_l2!(&a.dot(&result.solution), &b, 1e-9);
+//! ```
+
+use super::*;
+use crate::{error::*, inner::*, norm::Norm};
+use num_traits::Zero;
+
+/// Conjugate gradient solver for a Hermitian positive definite operator
+///
+/// The history records the norms of the residuals updated by the recurrence,
+/// which can drift from the true residual $b - Ax$ in floating point arithmetic.
+#[derive(Debug, Clone)]
+pub struct ConjugateGradient<A: Scalar> {
+    options: IterativeSolverOptions<A>,
+}
+
+impl<A: Scalar + Lapack> Default for ConjugateGradient<A> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<A: Scalar + Lapack> ConjugateGradient<A> {
+    /// Create a solver with the default [IterativeSolverOptions]
+    pub fn new() -> Self {
+        ConjugateGradient {
+            options: IterativeSolverOptions::default(),
+        }
+    }
+}
+
+impl<A: Scalar> IterativeSolver<A> for ConjugateGradient<A> {
+    fn options_mut(&mut self) -> &mut IterativeSolverOptions<A> {
+        &mut self.options
+    }
+}
+
+impl<A: Scalar + Lapack> PreconditionedSolver<A> for ConjugateGradient<A> {
+    fn solve_with<F, P, S>(
+        &self,
+        a: &F,
+        m: Option<&P>,
+        b: &ArrayBase<S, Ix1>,
+    ) -> Result<IterativeSolverResult<A>>
+    where
+        F: LinearOperator<Elem = A>,
+        P: LinearOperator<Elem = A>,
+        S: Data<Elem = A>,
+    {
+        let n = b.len();
+        let mut x = self.options.initial_solution(n)?;
+        let threshold = self.options.threshold(b.norm_l2());
+        let max_iterations = self.options.iteration_limit(n);
+
+        let mut r = residual(a, &x, b)?;
+        let mut r_norm = r.norm_l2();
+        let mut residual_history = vec![r_norm];
+        let mut z = precondition(m, &r);
+        let mut p = z.clone();
+        let mut rho = r.inner(&z).re();
+        let mut iterations = 0;
+        let mut breakdown = false;
+
+        while r_norm > threshold && iterations < max_iterations {
+            let q = a.apply(&p);
+            let pq = p.inner(&q).re();
+            if pq <= A::Real::zero() || rho <= A::Real::zero() {
+                // A or M is not positive definite
+                breakdown = true;
+                break;
+            }
+            let alpha = A::from_real(rho / pq);
+            x.scaled_add(alpha, &p);
+            r.scaled_add(-alpha, &q);
+            r_norm = r.norm_l2();
+            residual_history.push(r_norm);
+            iterations += 1;
+            if r_norm <= threshold {
+                break;
+            }
+
+            z = precondition(m, &r);
+            let rho_next = r.inner(&z).re();
+            let beta = rho_next / rho;
+            rho = rho_next;
+            azip!((p in &mut p, &z in &z) *p = z + p.mul_real(beta));
+        }
+
+        let status = if r_norm <= threshold {
+            SolverStatus::Converged
+        } else if breakdown {
+            SolverStatus::Breakdown
+        } else {
+            SolverStatus::MaxIterationsReached
+        };
+        Ok(IterativeSolverResult {
+            residual_norm: residual(a, &x, b)?.norm_l2(),
+            solution: x,
+            iterations,
+            residual_history,
+            status,
+        })
+    }
+}
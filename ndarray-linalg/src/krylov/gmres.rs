@@ -0,0 +1,194 @@
+//! Restarted GMRES
+//!
+//! - [Generalized minimal residual method - Wikipedia](https://en.wikipedia.org/wiki/Generalized_minimal_residual_method)
+//!
+//! GMRES minimizes the residual norm $\\|b - Ax\\|$ over the Krylov subspace
+//! $x_0 + \mathcal{K}_k(A, r_0)$ built by the Arnoldi iteration.
+//! The upper Hessenberg matrix $\bar{H}_k$ is reduced to the triangular form by Givens rotations
+//! as it grows, which gives the residual norm of each step without forming the solution.
+//! GMRES(m) restarts from the current solution after `m` steps to bound the memory and the cost of the orthogonalization.
+//!
+//! ```
+//! use ndarray::*;
+//! use ndarray_linalg::{krylov::*, *};
+//!
+//! let a: Array2<f64> = random((20, 20));
+//! let a = a + Array2::<f64>::eye(20) * 20.0;
+//! let b: Array1<f64> = random(20);
+//! let result = Gmres::new(10).tolerance(1e-10).solve(&a, &b).unwrap();
+//! assert!(result.is_converged());
+//! assert_close_l2!(&a.dot(&result.solution), &b, 1e-9);
+//! ```
+
+use super::*;
+use crate::{error::*, norm::Norm, operator::LinearOperator, qr_update::Givens};
+use num_traits::{Float, Zero};
+
+/// Restarted GMRES(m) solver for a linear operator
+///
+/// With the left preconditioning, both the residual and $b$ in the stopping criterion
+/// of [IterativeSolverOptions] are preconditioned by $M$.
+#[derive(Debug, Clone)]
+pub struct Gmres<A: Scalar> {
+    restart: usize,
+    options: IterativeSolverOptions<A>,
+}
+
+impl<A: Scalar> IterativeSolver<A> for Gmres<A> {
+    fn options_mut(&mut self) -> &mut IterativeSolverOptions<A> {
+        &mut self.options
+    }
+}
+
+impl<A: Scalar + Lapack> Gmres<A> {
+    /// Create a solver restarting every `restart` iterations with the default [IterativeSolverOptions]
+    ///
+    /// Panics
+    /// -------
+    /// - if `restart` is zero
+    pub fn new(restart: usize) -> Self {
+        assert!(restart > 0, "Restart length must be positive");
+        Gmres {
+            restart,
+            options: IterativeSolverOptions::default(),
+        }
+    }
+
+    /// Solve $Ax = b$
+    pub fn solve<F, S>(&self, a: &F, b: &ArrayBase<S, Ix1>) -> Result<IterativeSolverResult<A>>
+    where
+        F: LinearOperator<Elem = A>,
+        S: Data<Elem = A>,
+    {
+        self.solve_impl(a, None::<(&F, PreconditionerSide)>, b)
+    }
+
+    /// Solve $Ax = b$ with the preconditioner `m` approximating $A^{-1}$
+    pub fn solve_preconditioned<F, P, S>(
+        &self,
+        a: &F,
+        m: &P,
+        side: PreconditionerSide,
+        b: &ArrayBase<S, Ix1>,
+    ) -> Result<IterativeSolverResult<A>>
+    where
+        F: LinearOperator<Elem = A>,
+        P: LinearOperator<Elem = A>,
+        S: Data<Elem = A>,
+    {
+        self.solve_impl(a, Some((m, side)), b)
+    }
+
+    fn solve_impl<F, P, S>(
+        &self,
+        a: &F,
+        m: Option<(&P, PreconditionerSide)>,
+        b: &ArrayBase<S, Ix1>,
+    ) -> Result<IterativeSolverResult<A>>
+    where
+        F: LinearOperator<Elem = A>,
+        P: LinearOperator<Elem = A>,
+        S: Data<Elem = A>,
+    {
+        let n = b.len();
+        let mut x = self.options.initial_solution(n)?;
+        let left = |v: Array1<A>| match m {
+            Some((m, PreconditionerSide::Left)) => m.apply(&v),
+            _ => v,
+        };
+        let right = |v: Array1<A>| match m {
+            Some((m, PreconditionerSide::Right)) => m.apply(&v),
+            _ => v,
+        };
+
+        let threshold = self.options.threshold(left(b.to_owned()).norm_l2());
+        let max_iterations = self.options.iteration_limit(n);
+        let eps = <A::Real as Float>::epsilon();
+        let mut r = left(residual(a, &x, b)?);
+        let mut beta = r.norm_l2();
+        let mut residual_history = vec![beta];
+        let mut iterations = 0;
+        let mut breakdown = false;
+
+        while beta > threshold && iterations < max_iterations && !breakdown {
+            // Arnoldi iteration, where the columns of H are rotated into R as they are computed
+            let mut mgs = MGS::new(n, A::Real::zero());
+            r.mapv_inplace(|x| x.div_real(beta));
+            mgs.append(r.view());
+            let mut basis = vec![r.clone()];
+            let mut rotations: Vec<Givens<A>> = Vec::new();
+            let mut h: Vec<Array1<A>> = Vec::new();
+            let mut g = vec![A::from_real(beta)];
+            for _ in 0..self.restart.min(max_iterations - iterations) {
+                let j = h.len();
+                let mut w = left(a.apply(&right(basis[j].clone())));
+                let w_norm = w.norm_l2();
+                let mut coef = mgs.div_append(&mut w).into_coeff();
+                let h_next = coef[j + 1].abs();
+                for (i, rotation) in rotations.iter().enumerate() {
+                    let (x, y) = rotation.rotate(coef[i], coef[i + 1]);
+                    coef[i] = x;
+                    coef[i + 1] = y;
+                }
+                let rotation = Givens::new(coef[j], coef[j + 1]);
+                coef[j] = rotation.rotate(coef[j], coef[j + 1]).0;
+                coef[j + 1] = A::zero();
+                let (gj, gj1) = rotation.rotate(g[j], A::zero());
+                g[j] = gj;
+                g.push(gj1);
+                rotations.push(rotation);
+                h.push(coef);
+                basis.push(w);
+                iterations += 1;
+
+                let estimate = g[j + 1].abs();
+                residual_history.push(estimate);
+                if h_next <= eps * w_norm {
+                    // The Krylov subspace is invariant, and the solution is exact in it
+                    breakdown = true;
+                    break;
+                }
+                if estimate <= threshold {
+                    break;
+                }
+            }
+
+            // Solve R y = g by the back substitution
+            let k = h.len();
+            if h.iter().enumerate().any(|(i, col)| col[i].is_zero()) {
+                break;
+            }
+            let mut y = vec![A::zero(); k];
+            for i in (0..k).rev() {
+                let s = (i + 1..k).fold(g[i], |acc, l| acc - h[l][i] * y[l]);
+                y[i] = s / h[i][i];
+            }
+            let mut z = Array1::zeros(n);
+            for (yi, v) in y.iter().zip(&basis) {
+                z.scaled_add(*yi, v);
+            }
+            x += &right(z);
+            r = left(residual(a, &x, b)?);
+            beta = r.norm_l2();
+        }
+
+        let status = if beta <= threshold {
+            SolverStatus::Converged
+        } else if breakdown || iterations < max_iterations {
+            SolverStatus::Breakdown
+        } else {
+            SolverStatus::MaxIterationsReached
+        };
+        let residual_norm = match m {
+            Some((_, PreconditionerSide::Left)) => residual(a, &x, b)?.norm_l2(),
+            _ => beta,
+        };
+        Ok(IterativeSolverResult {
+            solution: x,
+            residual_norm,
+            iterations,
+            residual_history,
+            status,
+        })
+    }
+}
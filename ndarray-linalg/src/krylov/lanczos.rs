@@ -0,0 +1,302 @@
+//! Lanczos iteration
+
+use super::*;
+use crate::{
+    eigh::*, error::*, generate::hstack, inner::*, layout::MatrixLayout, norm::Norm,
+    operator::LinearOperator, tridiagonal::Tridiagonal, UPLO,
+};
+use num_traits::{Float, Zero};
+
+/// Reorthogonalization of the Lanczos vectors
+///
+/// In floating point arithmetic, the Lanczos vectors lose their orthogonality
+/// as soon as a Ritz value converges, and the converged Ritz values appear repeatedly as "ghosts".
+#[derive(Debug, Clone)]
+pub enum Reorthogonalization<Ortho> {
+    /// Only the three-term recurrence
+    None,
+    /// Orthogonalize against all the previous Lanczos vectors stored in the orthogonalizer
+    Full(Ortho),
+    /// Orthogonalize against the converged Ritz vectors stored in the orthogonalizer (Parlett and Scott),
+    /// where a Ritz pair is converged if its residual is smaller than $\sqrt{\epsilon} \\|T\\|$.
+    /// The convergence is checked only every few steps.
+    Selective(Ortho),
+}
+
+/// Number of steps between the convergence checks of the selective reorthogonalization,
+/// each of which takes $O(k^3)$ operations for the eigenvalue problem of $T$
+const LOCK_INTERVAL: usize = 4;
+
+/// Execute Lanczos iteration for a Hermitian operator as Rust iterator
+///
+/// - [Lanczos algorithm - Wikipedia](https://en.wikipedia.org/wiki/Lanczos_algorithm)
+///
+/// Each step yields the diagonal $\alpha_k$ and the sub-diagonal $\beta_k$
+/// of the real symmetric tridiagonal matrix $T = Q^\dagger A Q$.
+pub struct Lanczos<A, S, F, Ortho>
+where
+    A: Scalar,
+    S: DataMut<Elem = A>,
+    F: LinearOperator<Elem = A>,
+    Ortho: Orthogonalizer<Elem = A>,
+{
+    a: F,
+    /// Current vector (normalized `|v|=1`)
+    v: ArrayBase<S, Ix1>,
+    /// Lanczos vectors
+    basis: Vec<Array1<A>>,
+    /// Breakdown tolerance of the sub-diagonal elements
+    tol: A::Real,
+    reorthogonalization: Reorthogonalization<Ortho>,
+    /// Ritz values whose Ritz vectors are stored in the orthogonalizer for the selective reorthogonalization
+    locked: Vec<A::Real>,
+    /// Diagonal elements of T
+    alpha: Vec<A::Real>,
+    /// Sub-diagonal elements of T, where the last one is the norm of the residual vector
+    beta: Vec<A::Real>,
+}
+
+impl<A, S, F, Ortho> Lanczos<A, S, F, Ortho>
+where
+    A: Scalar + Lapack,
+    S: DataMut<Elem = A>,
+    F: LinearOperator<Elem = A>,
+    Ortho: Orthogonalizer<Elem = A>,
+{
+    /// Create a Lanczos iterator from a Hermitian linear operator `a`,
+    /// which stops when the sub-diagonal element is smaller than `tol`
+    pub fn new(
+        a: F,
+        mut v: ArrayBase<S, Ix1>,
+        tol: A::Real,
+        mut reorthogonalization: Reorthogonalization<Ortho>,
+    ) -> Self {
+        let norm = v.norm_l2();
+        azip!((v in &mut v) *v = v.div_real(norm));
+        match &mut reorthogonalization {
+            Reorthogonalization::Full(ortho) => {
+                assert_eq!(ortho.len(), 0);
+                ortho.append(v.view());
+            }
+            Reorthogonalization::Selective(ortho) => assert_eq!(ortho.len(), 0),
+            Reorthogonalization::None => {}
+        }
+        Lanczos {
+            a,
+            basis: vec![v.to_owned()],
+            v,
+            tol,
+            reorthogonalization,
+            locked: Vec::new(),
+            alpha: Vec::new(),
+            beta: Vec::new(),
+        }
+    }
+
+    /// Dimension of Krylov subspace
+    pub fn dim(&self) -> usize {
+        self.alpha.len()
+    }
+
+    /// The tridiagonal matrix $T$ of the current Krylov subspace
+    pub fn tridiagonal(&self) -> Tridiagonal<A> {
+        let k = self.dim();
+        let off: Vec<A> = self.beta[..k.saturating_sub(1)]
+            .iter()
+            .map(|&b| A::from_real(b))
+            .collect();
+        Tridiagonal {
+            l: MatrixLayout::F {
+                col: k as i32,
+                lda: k as i32,
+            },
+            dl: off.clone(),
+            d: self.alpha.iter().map(|&a| A::from_real(a)).collect(),
+            du: off,
+        }
+    }
+
+    /// Ritz values, i.e. the eigenvalues of $T$, in ascending order
+    pub fn ritz_values(&self) -> Result<Array1<A::Real>> {
+        Ok(self.eigh_tridiagonal()?.0)
+    }
+
+    /// Ritz values in ascending order and the corresponding Ritz vectors $x_i = Q y_i$ as columns
+    pub fn ritz_pairs(&self) -> Result<(Array1<A::Real>, Array2<A>)> {
+        let (e, y) = self.eigh_tridiagonal()?;
+        Ok((e, self.q().dot(&y)))
+    }
+
+    /// Residual norms $\\|A x_i - \theta_i x_i\\| = \beta_k |y_{k,i}|$ of the Ritz pairs
+    /// in the same order as [Lanczos::ritz_values]
+    pub fn residual_bounds(&self) -> Result<Array1<A::Real>> {
+        let k = self.dim();
+        if k == 0 {
+            return Ok(Array1::zeros(0));
+        }
+        let (_, y) = self.eigh_tridiagonal()?;
+        Ok(residuals(&y, self.beta[k - 1]))
+    }
+
+    /// Iterate until convergent
+    pub fn complete(mut self) -> (Q<A>, Tridiagonal<A>) {
+        for _ in &mut self {} // execute iteration until convergent
+        (self.q(), self.tridiagonal())
+    }
+
+    fn q(&self) -> Q<A> {
+        let k = self.dim();
+        if k == 0 {
+            return Array2::zeros((self.v.len(), 0));
+        }
+        hstack(&self.basis[..k]).unwrap()
+    }
+
+    fn eigh_tridiagonal(&self) -> Result<(Array1<A::Real>, Array2<A>)> {
+        let k = self.dim();
+        if k == 0 {
+            return Ok((Array1::zeros(0), Array2::zeros((0, 0))));
+        }
+        let mut t = Array2::zeros((k, k));
+        for i in 0..k {
+            t[(i, i)] = A::from_real(self.alpha[i]);
+        }
+        for i in 0..k - 1 {
+            t[(i, i + 1)] = A::from_real(self.beta[i]);
+            t[(i + 1, i)] = A::from_real(self.beta[i]);
+        }
+        t.eigh_into(UPLO::Upper)
+    }
+
+    /// Store the newly converged Ritz vectors into the orthogonalizer,
+    /// where `beta` is the norm of the current residual vector
+    fn lock_converged(&mut self, beta: A::Real) {
+        let (e, y) = match self.eigh_tridiagonal() {
+            Ok(ey) => ey,
+            Err(_) => return,
+        };
+        let residuals = residuals(&y, beta);
+        let threshold = Float::sqrt(<A::Real as Float>::epsilon())
+            * e.iter()
+                .fold(A::Real::zero(), |acc, &x| Float::max(acc, Float::abs(x)));
+        if let Reorthogonalization::Selective(ortho) = &mut self.reorthogonalization {
+            for (i, &theta) in e.iter().enumerate() {
+                let locked = self
+                    .locked
+                    .iter()
+                    .any(|&l| Float::abs(l - theta) <= threshold);
+                if residuals[i] > threshold || locked {
+                    continue;
+                }
+                // Ritz vector x = Q y without stacking the Lanczos vectors
+                let mut x = Array1::zeros(self.v.len());
+                for (q, &c) in self.basis.iter().zip(y.column(i)) {
+                    x.scaled_add(c, q);
+                }
+                if !ortho.append(x).is_dependent() {
+                    self.locked.push(theta);
+                }
+            }
+        }
+    }
+}
+
+/// Residual norms $\beta |y_{k,i}|$ from the last row of the eigenvectors of $T$
+fn residuals<A: Scalar>(y: &Array2<A>, beta: A::Real) -> Array1<A::Real> {
+    y.row(y.nrows() - 1).mapv(|x| beta * x.abs())
+}
+
+impl<A, S, F> Lanczos<A, S, F, MGS<A>>
+where
+    A: Scalar + Lapack,
+    S: DataMut<Elem = A>,
+    F: LinearOperator<Elem = A>,
+{
+    /// Create a Lanczos iterator without reorthogonalization
+    pub fn without_reorthogonalization(a: F, v: ArrayBase<S, Ix1>, tol: A::Real) -> Self {
+        Self::new(a, v, tol, Reorthogonalization::None)
+    }
+}
+
+impl<A, S, F, Ortho> Iterator for Lanczos<A, S, F, Ortho>
+where
+    A: Scalar + Lapack,
+    S: DataMut<Elem = A>,
+    F: LinearOperator<Elem = A>,
+    Ortho: Orthogonalizer<Elem = A>,
+{
+    type Item = (A::Real, A::Real);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let k = self.dim();
+        if k == self.basis.len() {
+            // terminated
+            return None;
+        }
+        let mut w = self.a.apply(&self.v);
+        let alpha = self.v.inner(&w).re();
+        azip!((w in &mut w, &v in &self.v) *w -= v.mul_real(alpha));
+        if k > 0 {
+            let beta = self.beta[k - 1];
+            azip!((w in &mut w, &u in &self.basis[k - 1]) *w -= u.mul_real(beta));
+        }
+        self.alpha.push(alpha);
+
+        match &self.reorthogonalization {
+            Reorthogonalization::None => {}
+            Reorthogonalization::Full(ortho) => {
+                ortho.decompose(&mut w);
+            }
+            Reorthogonalization::Selective(_) => {
+                if self.dim() % LOCK_INTERVAL == 0 {
+                    self.lock_converged(w.norm_l2());
+                }
+                if let Reorthogonalization::Selective(ortho) = &self.reorthogonalization {
+                    if !ortho.is_empty() {
+                        ortho.decompose(&mut w);
+                    }
+                }
+            }
+        }
+        let beta = w.norm_l2();
+        self.beta.push(beta);
+        if beta <= self.tol || self.basis.len() == self.v.len() {
+            return None;
+        }
+
+        azip!((v in &mut self.v, &w in &w) *v = w.div_real(beta));
+        if let Reorthogonalization::Full(ortho) = &mut self.reorthogonalization {
+            ortho.append(self.v.view());
+        }
+        self.basis.push(self.v.to_owned());
+        Some((alpha, beta))
+    }
+}
+
+/// Utility to execute Lanczos iteration with full reorthogonalization by Householder reflection
+pub fn lanczos_householder<A, S>(
+    a: impl LinearOperator<Elem = A>,
+    v: ArrayBase<S, Ix1>,
+    tol: A::Real,
+) -> (Q<A>, Tridiagonal<A>)
+where
+    A: Scalar + Lapack,
+    S: DataMut<Elem = A>,
+{
+    let householder = Householder::new(v.len(), tol);
+    Lanczos::new(a, v, tol, Reorthogonalization::Full(householder)).complete()
+}
+
+/// Utility to execute Lanczos iteration with full reorthogonalization by modified Gram-Schmit orthogonalizer
+pub fn lanczos_mgs<A, S>(
+    a: impl LinearOperator<Elem = A>,
+    v: ArrayBase<S, Ix1>,
+    tol: A::Real,
+) -> (Q<A>, Tridiagonal<A>)
+where
+    A: Scalar + Lapack,
+    S: DataMut<Elem = A>,
+{
+    let mgs = MGS::new(v.len(), tol);
+    Lanczos::new(a, v, tol, Reorthogonalization::Full(mgs)).complete()
+}
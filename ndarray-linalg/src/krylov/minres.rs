@@ -0,0 +1,159 @@
+//! Minimal residual method for Hermitian operators
+//!
+//! - [Minimal residual method - Wikipedia](https://en.wikipedia.org/wiki/Minimal_residual_method)
+//!
+//! MINRES of Paige and Saunders (1975) solves $Ax = b$ for a Hermitian, possibly indefinite operator $A$.
+//! The residual norm is minimized over the Krylov subspace built by the Lanczos iteration,
+//! and the tridiagonal least squares problem is solved by Givens rotations with short recurrences.
+//! The preconditioner $M \approx A^{-1}$ must be Hermitian positive definite,
+//! and then the residual is minimized in the norm $\\|r\\|_M$.
+//! This works for indefinite systems like saddle point problems,
+//! where [CG](super::cg) breaks down.
+//!
+//! ```
+//! use ndarray::*;
+//! use ndarray_linalg::{krylov::*, *};
+//!
+//! // Symmetric indefinite matrix
+//! let a: Array2<f64> = random_hermite(20);
+//! let a = a - Array2::<f64>::eye(20) * 5.0;
+//! let b: Array1<f64> = random(20);
+//! let result = Minres::new().tolerance(1e-10).solve(&a, &b).unwrap();
+//! assert!(result.is_converged());
+//! assert_close_l2!(&a.dot(&result.solution), &b, 1e-8);
+//! ```
+
+use super::*;
+use crate::{error::*, inner::*, norm::Norm};
+use num_traits::{Float, One, Zero};
+
+/// MINRES solver for a Hermitian operator
+///
+/// Both the residual norm and the norm of $b$ in the stopping criterion are measured by
+/// $\\|r\\|_M = \sqrt{r^\dagger M r}$ with the preconditioner.
+/// The residual norms are estimated by the recurrence, and recorded in the history.
+#[derive(Debug, Clone)]
+pub struct Minres<A: Scalar> {
+    options: IterativeSolverOptions<A>,
+}
+
+impl<A: Scalar + Lapack> Default for Minres<A> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<A: Scalar + Lapack> Minres<A> {
+    /// Create a solver with the default [IterativeSolverOptions]
+    pub fn new() -> Self {
+        Minres {
+            options: IterativeSolverOptions::default(),
+        }
+    }
+}
+
+impl<A: Scalar> IterativeSolver<A> for Minres<A> {
+    fn options_mut(&mut self) -> &mut IterativeSolverOptions<A> {
+        &mut self.options
+    }
+}
+
+impl<A: Scalar + Lapack> PreconditionedSolver<A> for Minres<A> {
+    fn solve_with<F, P, S>(
+        &self,
+        a: &F,
+        m: Option<&P>,
+        b: &ArrayBase<S, Ix1>,
+    ) -> Result<IterativeSolverResult<A>>
+    where
+        F: LinearOperator<Elem = A>,
+        P: LinearOperator<Elem = A>,
+        S: Data<Elem = A>,
+    {
+        let n = b.len();
+        let mut x = self.options.initial_solution(n)?;
+        let b = b.to_owned();
+        let b_norm = Float::sqrt(Float::abs(b.inner(&precondition(m, &b)).re()));
+        let threshold = self.options.threshold(b_norm);
+        let max_iterations = self.options.iteration_limit(n);
+        let zero = A::Real::zero();
+
+        let mut r1 = residual(a, &x, &b)?;
+        let mut y = precondition(m, &r1);
+        let mut r2 = r1.clone();
+        let beta1 = r1.inner(&y).re();
+        // M is not positive definite if beta1 < 0, and then |beta1| keeps the initial residual from converging
+        let mut breakdown = beta1 < zero;
+        let mut beta = Float::sqrt(Float::abs(beta1));
+        let mut phibar = beta;
+        let mut residual_history = vec![phibar];
+        let mut oldb = zero;
+        let (mut dbar, mut epsln) = (zero, zero);
+        let (mut cs, mut sn) = (-A::Real::one(), zero);
+        let mut w: Array1<A> = Array1::zeros(n);
+        let mut w2: Array1<A> = Array1::zeros(n);
+        let mut iterations = 0;
+
+        while phibar > threshold && iterations < max_iterations && !breakdown {
+            if beta.is_zero() {
+                breakdown = true;
+                break;
+            }
+            // Lanczos step for the preconditioned operator
+            let v = y.mapv(|y| y.div_real(beta));
+            y = a.apply(&v);
+            if iterations > 0 {
+                y.scaled_add(A::from_real(-beta / oldb), &r1);
+            }
+            let alpha = v.inner(&y).re();
+            y.scaled_add(A::from_real(-alpha / beta), &r2);
+            r1 = std::mem::replace(&mut r2, y);
+            y = precondition(m, &r2);
+            oldb = beta;
+            let beta2 = r2.inner(&y).re();
+            if beta2 < zero {
+                breakdown = true;
+                break;
+            }
+            beta = Float::sqrt(beta2);
+
+            // Apply the previous rotation to the new column of T
+            let oldeps = epsln;
+            let delta = cs * dbar + sn * alpha;
+            let gbar = sn * dbar - cs * alpha;
+            epsln = sn * beta;
+            dbar = -cs * beta;
+
+            // New rotation to eliminate beta
+            let gamma = Float::max(Float::hypot(gbar, beta), <A::Real as Float>::epsilon());
+            cs = gbar / gamma;
+            sn = beta / gamma;
+            let phi = cs * phibar;
+            phibar = sn * phibar;
+
+            // Update the solution
+            let w1 = std::mem::replace(&mut w2, w);
+            w = Zip::from(&v).and(&w1).and(&w2).map_collect(|&v, &w1, &w2| {
+                (v - w1.mul_real(oldeps) - w2.mul_real(delta)).div_real(gamma)
+            });
+            x.scaled_add(A::from_real(phi), &w);
+            iterations += 1;
+            residual_history.push(phibar);
+        }
+
+        let status = if phibar <= threshold {
+            SolverStatus::Converged
+        } else if breakdown {
+            SolverStatus::Breakdown
+        } else {
+            SolverStatus::MaxIterationsReached
+        };
+        Ok(IterativeSolverResult {
+            residual_norm: residual(a, &x, &b)?.norm_l2(),
+            solution: x,
+            iterations,
+            residual_history,
+            status,
+        })
+    }
+}
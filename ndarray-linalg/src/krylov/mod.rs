@@ -1,15 +1,27 @@
 //! Krylov subspace methods
 
-use crate::types::*;
+use crate::{operator::LinearOperator, types::*};
 use ndarray::*;
 
 pub mod arnoldi;
+pub mod bicgstab;
+pub mod cg;
+pub mod gmres;
 pub mod householder;
+pub mod lanczos;
 pub mod mgs;
+pub mod minres;
+pub mod tfqmr;
 
 pub use arnoldi::{arnoldi_householder, arnoldi_mgs, Arnoldi};
+pub use bicgstab::Bicgstab;
+pub use cg::ConjugateGradient;
+pub use gmres::Gmres;
 pub use householder::{householder, Householder};
+pub use lanczos::{lanczos_householder, lanczos_mgs, Lanczos, Reorthogonalization};
 pub use mgs::{mgs, MGS};
+pub use minres::Minres;
+pub use tfqmr::Tfqmr;
 
 /// Q-matrix
 ///
@@ -209,3 +221,192 @@ where
     }
     (ortho.get_q(), r)
 }
+
+/// Termination status of iterative linear solvers
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum SolverStatus {
+    /// The residual norm reached the tolerance
+    Converged,
+
+    /// The iteration limit was reached before convergence
+    MaxIterationsReached,
+
+    /// The iteration cannot be continued, e.g. by a division by zero, before convergence
+    Breakdown,
+}
+
+/// Side of preconditioner $M \approx A^{-1}$ applied to the linear system $Ax = b$
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum PreconditionerSide {
+    /// Solve $MAx = Mb$
+    Left,
+
+    /// Solve $AMy = b$ and $x = My$
+    Right,
+}
+
+/// Result of iterative linear solvers
+#[derive(Debug, Clone)]
+pub struct IterativeSolverResult<A: Scalar> {
+    /// The approximate solution $x$
+    pub solution: Array1<A>,
+    /// The true residual norm $\|b - Ax\|$ of the solution
+    pub residual_norm: A::Real,
+    /// The number of iterations, whose cost depends on the solver
+    pub iterations: usize,
+    /// The residual norms monitored by the solver, starting from the initial guess
+    pub residual_history: Vec<A::Real>,
+    /// Termination status
+    pub status: SolverStatus,
+}
+
+impl<A: Scalar> IterativeSolverResult<A> {
+    pub fn is_converged(&self) -> bool {
+        self.status == SolverStatus::Converged
+    }
+}
+
+/// Stopping criteria and the initial guess of iterative linear solvers
+///
+/// The iteration stops when the residual norm becomes smaller than
+/// `max(tolerance * |b|, absolute_tolerance)`.
+#[derive(Debug, Clone)]
+pub struct IterativeSolverOptions<A: Scalar> {
+    rtol: A::Real,
+    atol: A::Real,
+    max_iterations: Option<usize>,
+    initial_guess: Option<Array1<A>>,
+}
+
+impl<A: Scalar> Default for IterativeSolverOptions<A> {
+    /// The relative tolerance $\sqrt{\epsilon}$ and the initial guess zero
+    fn default() -> Self {
+        IterativeSolverOptions {
+            rtol: num_traits::Float::sqrt(<A::Real as num_traits::Float>::epsilon()),
+            atol: <A::Real as num_traits::Zero>::zero(),
+            max_iterations: None,
+            initial_guess: None,
+        }
+    }
+}
+
+impl<A: Scalar> IterativeSolverOptions<A> {
+    /// The residual norm to be reached for the right-hand side of the norm `b_norm`
+    fn threshold(&self, b_norm: A::Real) -> A::Real {
+        num_traits::Float::max(self.rtol * b_norm, self.atol)
+    }
+
+    /// The iteration limit for the dimension `n`
+    fn iteration_limit(&self, n: usize) -> usize {
+        self.max_iterations.unwrap_or(10 * n)
+    }
+
+    /// The initial guess or zero, which must have the dimension `n`
+    fn initial_solution(&self, n: usize) -> crate::error::Result<Array1<A>> {
+        match &self.initial_guess {
+            Some(x0) if x0.len() != n => {
+                Err(ShapeError::from_kind(ErrorKind::IncompatibleShape).into())
+            }
+            Some(x0) => Ok(x0.clone()),
+            None => Ok(Array1::zeros(n)),
+        }
+    }
+}
+
+/// Builder of iterative linear solvers configured by [IterativeSolverOptions]
+pub trait IterativeSolver<A: Scalar>: Sized {
+    fn options_mut(&mut self) -> &mut IterativeSolverOptions<A>;
+
+    /// Relative tolerance to the norm of the right-hand side
+    fn tolerance(mut self, rtol: A::Real) -> Self {
+        self.options_mut().rtol = rtol;
+        self
+    }
+
+    /// Absolute tolerance of the residual norm
+    fn absolute_tolerance(mut self, atol: A::Real) -> Self {
+        self.options_mut().atol = atol;
+        self
+    }
+
+    /// Maximum number of iterations in total, which is ten times the dimension by default
+    fn max_iterations(mut self, max_iterations: usize) -> Self {
+        self.options_mut().max_iterations = Some(max_iterations);
+        self
+    }
+
+    /// Initial guess of the solution
+    fn initial_guess(mut self, x0: Array1<A>) -> Self {
+        self.options_mut().initial_guess = Some(x0);
+        self
+    }
+}
+
+/// Iterative linear solver taking an optional preconditioner $M \approx A^{-1}$
+pub trait PreconditionedSolver<A: Scalar>: IterativeSolver<A> {
+    /// Solve $Ax = b$ with the preconditioner `m` if given
+    fn solve_with<F, P, S>(
+        &self,
+        a: &F,
+        m: Option<&P>,
+        b: &ArrayBase<S, Ix1>,
+    ) -> crate::error::Result<IterativeSolverResult<A>>
+    where
+        F: LinearOperator<Elem = A>,
+        P: LinearOperator<Elem = A>,
+        S: Data<Elem = A>;
+
+    /// Solve $Ax = b$
+    fn solve<F, S>(
+        &self,
+        a: &F,
+        b: &ArrayBase<S, Ix1>,
+    ) -> crate::error::Result<IterativeSolverResult<A>>
+    where
+        F: LinearOperator<Elem = A>,
+        S: Data<Elem = A>,
+    {
+        self.solve_with(a, None::<&F>, b)
+    }
+
+    /// Solve $Ax = b$ with the preconditioner `m` approximating $A^{-1}$
+    fn solve_preconditioned<F, P, S>(
+        &self,
+        a: &F,
+        m: &P,
+        b: &ArrayBase<S, Ix1>,
+    ) -> crate::error::Result<IterativeSolverResult<A>>
+    where
+        F: LinearOperator<Elem = A>,
+        P: LinearOperator<Elem = A>,
+        S: Data<Elem = A>,
+    {
+        self.solve_with(a, Some(m), b)
+    }
+}
+
+/// Apply the preconditioner if given
+fn precondition<A, P>(m: Option<&P>, r: &Array1<A>) -> Array1<A>
+where
+    A: Scalar,
+    P: LinearOperator<Elem = A>,
+{
+    match m {
+        Some(m) => m.apply(r),
+        None => r.clone(),
+    }
+}
+
+/// The residual vector $b - Ax$
+fn residual<A, F, S>(a: &F, x: &Array1<A>, b: &ArrayBase<S, Ix1>) -> crate::error::Result<Array1<A>>
+where
+    A: Scalar,
+    F: LinearOperator<Elem = A>,
+    S: Data<Elem = A>,
+{
+    let ax = a.apply(x);
+    if ax.len() != b.len() {
+        return Err(ShapeError::from_kind(ErrorKind::IncompatibleShape).into());
+    }
+    Ok(b - &ax)
+}
@@ -18,8 +18,19 @@
 //! `LeastSquaresSvdInPlace` avoid an extra allocation for `A` and `b` which
 //! `LeastSquaresSvd` has do perform to preserve the values in `A` and `b`.
 //!
-//! All methods use the Lapacke family of methods `*gelsd` which solves the least
+//! By default, all methods use the Lapacke family of methods `*gelsd` which solves the least
 //! squares problem using the SVD with a divide-and-conquer strategy.
+//! The `*_with` variants of these methods take a [LeastSquaresDriver] to choose a cheaper one:
+//! `*gels` using QR decomposition for full-rank matrices, or `*gelsy` using the complete
+//! orthogonal decomposition for rank-deficient matrices.
+//! If `A` has more columns than rows, all drivers compute the minimum norm solution
+//! of the underdetermined system, where `*gels` uses LQ decomposition.
+//!
+//! The linear equality-constrained least squares problem `min ||c - Ax||` subject to `Bx = d`
+//! is solved by [LeastSquaresConstrained] using `*gglse`, and the general Gauss-Markov linear model
+//! `min ||y||` subject to `d = Ax + By` is solved by [GeneralizedLinearModel] using `*ggglm`.
+//! Least squares with bounds on the variables, e.g. non-negative least squares,
+//! are solved by [LeastSquaresBounded](crate::nnls::LeastSquaresBounded).
 //!
 //! The traits are implemented for value types `f32`, `f64`, `c32` and `c64`
 //! and vector or matrix right-hand-sides (`ArrayBase<S, Ix1>` or `ArrayBase<S, Ix2>`).
@@ -78,7 +89,8 @@ use crate::types::*;
 /// the solution is a `m x k` matrix.
 #[derive(Debug, Clone)]
 pub struct LeastSquaresResult<E: Scalar, I: Dimension> {
-    /// The singular values of the matrix A in `Ax = b`
+    /// The singular values of the matrix A in `Ax = b`,
+    /// which is empty unless solved by [LeastSquaresDriver::Svd]
     pub singular_values: Array1<E::Real>,
     /// The solution vector or matrix `x` which is the best
     /// solution to `Ax = b`, i.e. minimizing the 2-norm `||b - Ax||`
@@ -90,6 +102,20 @@ pub struct LeastSquaresResult<E: Scalar, I: Dimension> {
     /// If b is a (m x k) matrix, this is a (k x 1) column vector
     pub residual_sum_of_squares: Option<Array<E::Real, I::Smaller>>,
 }
+/// LAPACK driver to solve the least squares problem
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LeastSquaresDriver {
+    /// SVD with divide-and-conquer (`*gelsd`), which handles rank-deficient matrices
+    /// and computes the singular values
+    Svd,
+    /// QR decomposition, or LQ decomposition for underdetermined systems (`*gels`),
+    /// which is the cheapest but requires `A` to have full rank
+    Qr,
+    /// Complete orthogonal decomposition with column pivoting (`*gelsy`),
+    /// which handles rank-deficient matrices at a lower cost than SVD
+    CompleteOrthogonal,
+}
+
 /// Solve least squares for immutable references
 pub trait LeastSquaresSvd<D, E, I>
 where
@@ -104,7 +130,19 @@ where
     /// `A` and `rhs` must have the same layout, i.e. they must
     /// be both either row- or column-major format, otherwise a
     /// `IncompatibleShape` error is raised.
-    fn least_squares(&self, rhs: &ArrayBase<D, I>) -> Result<LeastSquaresResult<E, I>>;
+    fn least_squares(&self, rhs: &ArrayBase<D, I>) -> Result<LeastSquaresResult<E, I>> {
+        self.least_squares_with(rhs, LeastSquaresDriver::Svd)
+    }
+
+    /// Solve a least squares problem of the form `Ax = rhs`
+    /// using the given LAPACK driver. `A` and `rhs` are unchanged.
+    ///
+    /// If `A` has more columns than rows, the minimum norm solution is computed.
+    fn least_squares_with(
+        &self,
+        rhs: &ArrayBase<D, I>,
+        driver: LeastSquaresDriver,
+    ) -> Result<LeastSquaresResult<E, I>>;
 }
 
 /// Solve least squares for owned matrices
@@ -122,7 +160,22 @@ where
     /// `A` and `rhs` must have the same layout, i.e. they must
     /// be both either row- or column-major format, otherwise a
     /// `IncompatibleShape` error is raised.
-    fn least_squares_into(self, rhs: ArrayBase<D, I>) -> Result<LeastSquaresResult<E, I>>;
+    fn least_squares_into(self, rhs: ArrayBase<D, I>) -> Result<LeastSquaresResult<E, I>>
+    where
+        Self: Sized,
+    {
+        self.least_squares_into_with(rhs, LeastSquaresDriver::Svd)
+    }
+
+    /// Solve a least squares problem of the form `Ax = rhs`
+    /// using the given LAPACK driver, consuming both `A` and `rhs`.
+    ///
+    /// If `A` has more columns than rows, the minimum norm solution is computed.
+    fn least_squares_into_with(
+        self,
+        rhs: ArrayBase<D, I>,
+        driver: LeastSquaresDriver,
+    ) -> Result<LeastSquaresResult<E, I>>;
 }
 
 /// Solve least squares for mutable references, overwriting
@@ -144,6 +197,18 @@ where
     fn least_squares_in_place(
         &mut self,
         rhs: &mut ArrayBase<D, I>,
+    ) -> Result<LeastSquaresResult<E, I>> {
+        self.least_squares_in_place_with(rhs, LeastSquaresDriver::Svd)
+    }
+
+    /// Solve a least squares problem of the form `Ax = rhs`
+    /// using the given LAPACK driver, overwriting both `A` and `rhs`.
+    ///
+    /// If `A` has more columns than rows, the minimum norm solution is computed.
+    fn least_squares_in_place_with(
+        &mut self,
+        rhs: &mut ArrayBase<D, I>,
+        driver: LeastSquaresDriver,
     ) -> Result<LeastSquaresResult<E, I>>;
 }
 
@@ -158,16 +223,16 @@ where
     D2: Data<Elem = E>,
 {
     /// Solve a least squares problem of the form `Ax = rhs`
-    /// by calling `A.least_squares(&rhs)`, where `rhs` is a
+    /// by calling `A.least_squares_with(&rhs, driver)`, where `rhs` is a
     /// single column vector. `A` and `rhs` are unchanged.
-    ///
-    /// `A` and `rhs` must have the same layout, i.e. they must
-    /// be both either row- or column-major format, otherwise a
-    /// `IncompatibleShape` error is raised.
-    fn least_squares(&self, rhs: &ArrayBase<D2, Ix1>) -> Result<LeastSquaresResult<E, Ix1>> {
+    fn least_squares_with(
+        &self,
+        rhs: &ArrayBase<D2, Ix1>,
+        driver: LeastSquaresDriver,
+    ) -> Result<LeastSquaresResult<E, Ix1>> {
         let a = self.to_owned();
         let b = rhs.to_owned();
-        a.least_squares_into(b)
+        a.least_squares_into_with(b, driver)
     }
 }
 
@@ -182,16 +247,16 @@ where
     D2: Data<Elem = E>,
 {
     /// Solve a least squares problem of the form `Ax = rhs`
-    /// by calling `A.least_squares(&rhs)`, where `rhs` is
+    /// by calling `A.least_squares_with(&rhs, driver)`, where `rhs` is
     /// matrix. `A` and `rhs` are unchanged.
-    ///
-    /// `A` and `rhs` must have the same layout, i.e. they must
-    /// be both either row- or column-major format, otherwise a
-    /// `IncompatibleShape` error is raised.
-    fn least_squares(&self, rhs: &ArrayBase<D2, Ix2>) -> Result<LeastSquaresResult<E, Ix2>> {
+    fn least_squares_with(
+        &self,
+        rhs: &ArrayBase<D2, Ix2>,
+        driver: LeastSquaresDriver,
+    ) -> Result<LeastSquaresResult<E, Ix2>> {
         let a = self.to_owned();
         let b = rhs.to_owned();
-        a.least_squares_into(b)
+        a.least_squares_into_with(b, driver)
     }
 }
 
@@ -208,17 +273,14 @@ where
     D2: DataMut<Elem = E>,
 {
     /// Solve a least squares problem of the form `Ax = rhs`
-    /// by calling `A.least_squares(rhs)`, where `rhs` is a
+    /// by calling `A.least_squares_into_with(rhs, driver)`, where `rhs` is a
     /// single column vector. `A` and `rhs` are consumed.
-    ///
-    /// `A` and `rhs` must have the same layout, i.e. they must
-    /// be both either row- or column-major format, otherwise a
-    /// `IncompatibleShape` error is raised.
-    fn least_squares_into(
+    fn least_squares_into_with(
         mut self,
         mut rhs: ArrayBase<D2, Ix1>,
+        driver: LeastSquaresDriver,
     ) -> Result<LeastSquaresResult<E, Ix1>> {
-        self.least_squares_in_place(&mut rhs)
+        self.least_squares_in_place_with(&mut rhs, driver)
     }
 }
 
@@ -235,17 +297,14 @@ where
     D2: DataMut<Elem = E>,
 {
     /// Solve a least squares problem of the form `Ax = rhs`
-    /// by calling `A.least_squares(rhs)`, where `rhs` is a
+    /// by calling `A.least_squares_into_with(rhs, driver)`, where `rhs` is a
     /// matrix. `A` and `rhs` are consumed.
-    ///
-    /// `A` and `rhs` must have the same layout, i.e. they must
-    /// be both either row- or column-major format, otherwise a
-    /// `IncompatibleShape` error is raised.
-    fn least_squares_into(
+    fn least_squares_into_with(
         mut self,
         mut rhs: ArrayBase<D2, Ix2>,
+        driver: LeastSquaresDriver,
     ) -> Result<LeastSquaresResult<E, Ix2>> {
-        self.least_squares_in_place(&mut rhs)
+        self.least_squares_in_place_with(&mut rhs, driver)
     }
 }
 
@@ -262,15 +321,12 @@ where
     D2: DataMut<Elem = E>,
 {
     /// Solve a least squares problem of the form `Ax = rhs`
-    /// by calling `A.least_squares(rhs)`, where `rhs` is a
+    /// by calling `A.least_squares_in_place_with(rhs, driver)`, where `rhs` is a
     /// vector. `A` and `rhs` are overwritten in the call.
-    ///
-    /// `A` and `rhs` must have the same layout, i.e. they must
-    /// be both either row- or column-major format, otherwise a
-    /// `IncompatibleShape` error is raised.
-    fn least_squares_in_place(
+    fn least_squares_in_place_with(
         &mut self,
         rhs: &mut ArrayBase<D2, Ix1>,
+        driver: LeastSquaresDriver,
     ) -> Result<LeastSquaresResult<E, Ix1>> {
         if self.shape()[0] != rhs.shape()[0] {
             return Err(ShapeError::from_kind(ErrorKind::IncompatibleShape).into());
@@ -281,28 +337,50 @@ where
             // for which we need `n` entries
             let mut new_rhs = Array1::<E>::zeros((n,));
             new_rhs.slice_mut(s![0..m]).assign(rhs);
-            compute_least_squares_srhs(self, &mut new_rhs)
+            compute_least_squares_srhs(self, &mut new_rhs, driver)
         } else {
-            compute_least_squares_srhs(self, rhs)
+            compute_least_squares_srhs(self, rhs, driver)
         }
     }
 }
 
+/// Call the LAPACK driver
+fn least_squares_driver<E: Scalar + Lapack>(
+    driver: LeastSquaresDriver,
+    a_layout: MatrixLayout,
+    a: &mut [E],
+    b_layout: MatrixLayout,
+    b: &mut [E],
+) -> Result<LeastSquaresOutput<E>> {
+    Ok(match driver {
+        LeastSquaresDriver::Svd => E::least_squares_nrhs(a_layout, a, b_layout, b)?,
+        LeastSquaresDriver::Qr => E::least_squares_qr(a_layout, a, b_layout, b)?,
+        LeastSquaresDriver::CompleteOrthogonal => {
+            E::least_squares_complete_orthogonal(a_layout, a, b_layout, b)?
+        }
+    })
+}
+
 fn compute_least_squares_srhs<E, D1, D2>(
     a: &mut ArrayBase<D1, Ix2>,
     rhs: &mut ArrayBase<D2, Ix1>,
+    driver: LeastSquaresDriver,
 ) -> Result<LeastSquaresResult<E, Ix1>>
 where
     E: Scalar + Lapack,
     D1: DataMut<Elem = E>,
     D2: DataMut<Elem = E>,
 {
+    let a_layout = a.layout()?;
+    let rhs_layout = a_layout.resized(rhs.len() as i32, 1);
     let LeastSquaresOutput::<E> {
         singular_values,
         rank,
-    } = E::least_squares(
-        a.layout()?,
+    } = least_squares_driver(
+        driver,
+        a_layout,
         a.as_allocated_mut()?,
+        rhs_layout,
         rhs.as_slice_memory_order_mut()
             .ok_or_else(|| LinalgError::MemoryNotCont)?,
     )?;
@@ -345,15 +423,12 @@ where
     D2: DataMut<Elem = E>,
 {
     /// Solve a least squares problem of the form `Ax = rhs`
-    /// by calling `A.least_squares(rhs)`, where `rhs` is a
+    /// by calling `A.least_squares_in_place_with(rhs, driver)`, where `rhs` is a
     /// matrix. `A` and `rhs` are overwritten in the call.
-    ///
-    /// `A` and `rhs` must have the same layout, i.e. they must
-    /// be both either row- or column-major format, otherwise a
-    /// `IncompatibleShape` error is raised.
-    fn least_squares_in_place(
+    fn least_squares_in_place_with(
         &mut self,
         rhs: &mut ArrayBase<D2, Ix2>,
+        driver: LeastSquaresDriver,
     ) -> Result<LeastSquaresResult<E, Ix2>> {
         if self.shape()[0] != rhs.shape()[0] {
             return Err(ShapeError::from_kind(ErrorKind::IncompatibleShape).into());
@@ -368,9 +443,9 @@ where
                 MatrixLayout::F { .. } => Array2::<E>::zeros((n, k).f()),
             };
             new_rhs.slice_mut(s![0..m, ..]).assign(rhs);
-            compute_least_squares_nrhs(self, &mut new_rhs)
+            compute_least_squares_nrhs(self, &mut new_rhs, driver)
         } else {
-            compute_least_squares_nrhs(self, rhs)
+            compute_least_squares_nrhs(self, rhs, driver)
         }
     }
 }
@@ -378,6 +453,7 @@ where
 fn compute_least_squares_nrhs<E, D1, D2>(
     a: &mut ArrayBase<D1, Ix2>,
     rhs: &mut ArrayBase<D2, Ix2>,
+    driver: LeastSquaresDriver,
 ) -> Result<LeastSquaresResult<E, Ix2>>
 where
     E: Scalar + Lapack,
@@ -389,7 +465,8 @@ where
     let LeastSquaresOutput::<E> {
         singular_values,
         rank,
-    } = E::least_squares_nrhs(
+    } = least_squares_driver(
+        driver,
         a_layout,
         a.as_allocated_mut()?,
         rhs_layout,
@@ -424,6 +501,143 @@ fn compute_residual_array1<E: Scalar, D: Data<Elem = E>>(
     )
 }
 
+/// Result of the equality-constrained least squares problem
+#[derive(Debug, Clone)]
+pub struct LeastSquaresConstrainedResult<E: Scalar> {
+    /// The solution `x` minimizing `||c - Ax||` subject to `Bx = d`
+    pub solution: Array1<E>,
+    /// The residual sum of squares `||c - Ax||^2` at the solution
+    pub residual_sum_of_squares: E::Real,
+}
+
+/// Result of the general Gauss-Markov linear model problem
+#[derive(Debug, Clone)]
+pub struct GeneralizedLinearModelResult<E: Scalar> {
+    /// The solution `x` of `d = Ax + By`
+    pub solution: Array1<E>,
+    /// The minimum norm `y` of `d = Ax + By`
+    pub noise: Array1<E>,
+}
+
+/// Solve the linear equality-constrained least squares problem (`*gglse`)
+pub trait LeastSquaresConstrained<E: Scalar> {
+    /// Minimize `||c - Ax||` subject to the exact linear constraints `Bx = d`,
+    /// where `A` is `self`.
+    ///
+    /// `A` is `m x n` and `B` is `p x n`, where `p <= n <= m + p` is required,
+    /// otherwise a `IncompatibleShape` error is raised.
+    /// `B` must have full row rank and `[A; B]` must have full column rank,
+    /// otherwise `LapackComputationalFailure` is returned.
+    fn least_squares_constrained<Sc, Sb, Sd>(
+        &self,
+        c: &ArrayBase<Sc, Ix1>,
+        b: &ArrayBase<Sb, Ix2>,
+        d: &ArrayBase<Sd, Ix1>,
+    ) -> Result<LeastSquaresConstrainedResult<E>>
+    where
+        Sc: Data<Elem = E>,
+        Sb: Data<Elem = E>,
+        Sd: Data<Elem = E>;
+}
+
+impl<E, S> LeastSquaresConstrained<E> for ArrayBase<S, Ix2>
+where
+    E: Scalar + Lapack,
+    S: Data<Elem = E>,
+{
+    fn least_squares_constrained<Sc, Sb, Sd>(
+        &self,
+        c: &ArrayBase<Sc, Ix1>,
+        b: &ArrayBase<Sb, Ix2>,
+        d: &ArrayBase<Sd, Ix1>,
+    ) -> Result<LeastSquaresConstrainedResult<E>>
+    where
+        Sc: Data<Elem = E>,
+        Sb: Data<Elem = E>,
+        Sd: Data<Elem = E>,
+    {
+        let (m, n) = self.dim();
+        let p = b.nrows();
+        if c.len() != m || b.ncols() != n || d.len() != p || p > n || n > m + p {
+            return Err(ShapeError::from_kind(ErrorKind::IncompatibleShape).into());
+        }
+        let mut a = self.to_owned();
+        let mut b = b.to_owned();
+        let mut c = c.to_owned();
+        let mut d = d.to_owned();
+        let x = E::least_squares_equality_constrained(
+            a.layout()?,
+            a.as_allocated_mut()?,
+            b.layout()?,
+            b.as_allocated_mut()?,
+            c.as_slice_mut().unwrap(),
+            d.as_slice_mut().unwrap(),
+        )?;
+        let residual_sum_of_squares = c.slice(s![n - p..]).iter().map(|x| x.square()).sum();
+        Ok(LeastSquaresConstrainedResult {
+            solution: Array1::from(x),
+            residual_sum_of_squares,
+        })
+    }
+}
+
+/// Solve the general Gauss-Markov linear model problem (`*ggglm`)
+pub trait GeneralizedLinearModel<E: Scalar> {
+    /// Find `x` and the minimum norm `y` satisfying `d = Ax + By`, where `A` is `self`.
+    ///
+    /// When `B` is square and nonsingular, `x` is the solution of
+    /// the weighted least squares problem `min ||B^{-1} (d - Ax)||`.
+    ///
+    /// `A` is `n x m` and `B` is `n x p`, where `m <= n <= m + p` is required,
+    /// otherwise a `IncompatibleShape` error is raised.
+    /// `A` must have full column rank and `[A, B]` must have full row rank,
+    /// otherwise `LapackComputationalFailure` is returned.
+    fn generalized_linear_model<Sb, Sd>(
+        &self,
+        b: &ArrayBase<Sb, Ix2>,
+        d: &ArrayBase<Sd, Ix1>,
+    ) -> Result<GeneralizedLinearModelResult<E>>
+    where
+        Sb: Data<Elem = E>,
+        Sd: Data<Elem = E>;
+}
+
+impl<E, S> GeneralizedLinearModel<E> for ArrayBase<S, Ix2>
+where
+    E: Scalar + Lapack,
+    S: Data<Elem = E>,
+{
+    fn generalized_linear_model<Sb, Sd>(
+        &self,
+        b: &ArrayBase<Sb, Ix2>,
+        d: &ArrayBase<Sd, Ix1>,
+    ) -> Result<GeneralizedLinearModelResult<E>>
+    where
+        Sb: Data<Elem = E>,
+        Sd: Data<Elem = E>,
+    {
+        let (n, m) = self.dim();
+        let p = b.ncols();
+        if b.nrows() != n || d.len() != n || m > n || n > m + p {
+            return Err(ShapeError::from_kind(ErrorKind::IncompatibleShape).into());
+        }
+        let mut a = self.to_owned();
+        let mut b = b.to_owned();
+        let mut d = d.to_owned();
+        let (x, y) = E::generalized_linear_model(
+            a.layout()?,
+            a.as_allocated_mut()?,
+            b.layout()?,
+            b.as_allocated_mut()?,
+            d.as_slice_mut().unwrap(),
+        )?;
+        Ok(GeneralizedLinearModelResult {
+            solution: Array1::from(x),
+            noise: Array1::from(y),
+        })
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::{error::LinalgError, *};
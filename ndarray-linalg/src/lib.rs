@@ -6,16 +6,29 @@
 //! -----------------------
 //! - Decomposition methods:
 //!     - [QR decomposition](qr/index.html)
+//!     - [Updating QR decomposition](qr_update/index.html)
 //!     - [Cholesky/LU decomposition](cholesky/index.html)
 //!     - [Eigenvalue decomposition](eig/index.html)
 //!     - [Eigenvalue decomposition for Hermite matrices](eigh/index.html)
+//!     - [Schur decomposition](schur/index.html)
 //!     - [**S**ingular **V**alue **D**ecomposition](svd/index.html)
+//!     - [Incremental SVD for streaming columns](incremental_svd/index.html)
+//!     - [Randomized SVD for low-rank approximation](randomized_svd/index.html)
 //! - Solution of linear systems:
 //!    - [General matrices](solve/index.html)
 //!    - [Triangular matrices](triangular/index.html)
 //!    - [Hermitian/real symmetric matrices](solveh/index.html)
 //!    - [Tridiagonal matrices](tridiagonal/index.html)
+//!    - [With error bounds by expert drivers](solvex/index.html)
+//!    - [Mixed-precision iterative refinement](mixed_precision/index.html)
 //! - [Inverse matrix computation](solve/trait.Inverse.html)
+//! - [Pseudo-inverse matrix computation](pinv/index.html)
+//! - [Condition number computation](cond/index.html)
+//! - [Linear regression by weighted and generalized least squares](regression/index.html)
+//! - [Non-negative and bounded-variable least squares](nnls/index.html)
+//! - [Tikhonov-regularized least squares](tikhonov/index.html)
+//! - [Total least squares for errors in both matrix and right-hand side](total_least_squares/index.html)
+//! - [Functions of general square matrices](funm/index.html)
 //!
 //! Naming Convention
 //! -----------------------
@@ -50,25 +63,40 @@ extern crate ndarray;
 
 pub mod assert;
 pub mod cholesky;
+pub mod cond;
 pub mod convert;
 pub mod diagonal;
 pub mod eig;
 pub mod eigh;
+pub mod equilibrate;
 pub mod error;
+pub mod funm;
 pub mod generate;
+pub mod incremental_svd;
 pub mod inner;
 pub mod krylov;
 pub mod layout;
 pub mod least_squares;
 pub mod lobpcg;
+pub mod mixed_precision;
+pub mod nnls;
 pub mod norm;
 pub mod operator;
 pub mod opnorm;
+pub mod pinv;
 pub mod qr;
+pub mod qr_update;
+pub mod randomized_svd;
+pub mod regression;
+pub mod schur;
 pub mod solve;
 pub mod solveh;
+pub mod solvex;
+pub mod subspace;
 pub mod svd;
 pub mod svddc;
+pub mod tikhonov;
+pub mod total_least_squares;
 pub mod trace;
 pub mod triangular;
 pub mod tridiagonal;
@@ -76,23 +104,38 @@ pub mod types;
 
 pub use assert::*;
 pub use cholesky::*;
+pub use cond::*;
 pub use convert::*;
 pub use diagonal::*;
 pub use eig::*;
 pub use eigh::*;
+pub use equilibrate::*;
+pub use funm::*;
 pub use generate::*;
+pub use incremental_svd::*;
 pub use inner::*;
 pub use layout::*;
 pub use least_squares::*;
 pub use lobpcg::{TruncatedEig, TruncatedOrder, TruncatedSvd};
+pub use mixed_precision::*;
+pub use nnls::*;
 pub use norm::*;
 pub use operator::*;
 pub use opnorm::*;
+pub use pinv::*;
 pub use qr::*;
+pub use qr_update::*;
+pub use randomized_svd::*;
+pub use regression::*;
+pub use schur::*;
 pub use solve::*;
 pub use solveh::*;
+pub use solvex::*;
+pub use subspace::*;
 pub use svd::*;
 pub use svddc::*;
+pub use tikhonov::*;
+pub use total_least_squares::*;
 pub use trace::*;
 pub use triangular::*;
 pub use tridiagonal::*;
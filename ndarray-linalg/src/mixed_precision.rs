@@ -0,0 +1,152 @@
+//! Solve linear equations using mixed-precision iterative refinement
+//!
+//! For `f64` and `c64` matrices, the matrix is factorized in single precision,
+//! which is about twice faster than double precision for large matrices,
+//! and the solution is refined in double precision to the full accuracy.
+//! If the refinement does not converge, e.g. for ill-conditioned matrices,
+//! the matrix is factorized again in double precision as [`Factorize`](crate::solve::Factorize)
+//! (or [`FactorizeC`](crate::cholesky::FactorizeC)) and the equation is solved directly.
+//!
+//! ```
+//! use ndarray::*;
+//! use ndarray_linalg::*;
+//!
+//! let a: Array2<f64> = array![[3.0, 1.0], [1.0, 2.0]];
+//! let b: Array1<f64> = array![9.0, 8.0];
+//! let result = a.solve_mixed_precision(&b).unwrap();
+//! assert_close_l2!(&result.solution, &array![2.0, 3.0], 1e-12);
+//! assert!(result.refined);
+//! ```
+
+use lax::MixedPrecision_;
+use ndarray::*;
+
+use crate::error::*;
+use crate::layout::*;
+use crate::types::*;
+use crate::UPLO;
+
+/// Maximum number of the refinement iterations in LAPACK
+const MAX_ITERATIONS: usize = 30;
+
+/// `iter` returned by LAPACK when the refinement does not converge in `MAX_ITERATIONS`
+const ITERATION_LIMIT_EXCEEDED: i32 = -31;
+
+/// Solution of the linear equation by the mixed-precision iterative refinement
+#[derive(Debug, Clone)]
+pub struct MixedPrecisionResult<A: Scalar, I: Dimension> {
+    /// The solution `x` of `Ax = b`
+    pub solution: Array<A, I>,
+    /// The number of the refinement iterations taken
+    pub iterations: usize,
+    /// `true` if the refinement converged, and `false` if the solution is computed
+    /// by the full-precision factorization instead
+    pub refined: bool,
+}
+
+/// Solve general linear equation by LU decomposition in single precision with refinement (`dsgesv`/`zcgesv`)
+pub trait SolveMixedPrecision<A: Scalar, I: Dimension> {
+    /// Solve `Ax = b`
+    fn solve_mixed_precision<S: Data<Elem = A>>(
+        &self,
+        b: &ArrayBase<S, I>,
+    ) -> Result<MixedPrecisionResult<A, I>>;
+}
+
+/// Solve positive-definite Hermitian linear equation by Cholesky decomposition in single precision with refinement (`dsposv`/`zcposv`)
+pub trait SolveCMixedPrecision<A: Scalar, I: Dimension> {
+    /// Solve `Ax = b` using the upper triangular portion of `A`
+    fn solvec_mixed_precision<S: Data<Elem = A>>(
+        &self,
+        b: &ArrayBase<S, I>,
+    ) -> Result<MixedPrecisionResult<A, I>>;
+}
+
+fn solve_nrhs<A, Sa, Sb>(
+    a: &ArrayBase<Sa, Ix2>,
+    b: &ArrayBase<Sb, Ix2>,
+    cholesky: bool,
+) -> Result<MixedPrecisionResult<A, Ix2>>
+where
+    A: Scalar + Lapack + MixedPrecision_,
+    Sa: Data<Elem = A>,
+    Sb: Data<Elem = A>,
+{
+    let a_layout = a.square_layout()?;
+    let a = a.as_allocated()?;
+    let mut x = b.to_owned();
+    let b_layout = x.layout()?;
+    let iter = if cholesky {
+        A::solve_cholesky_mixed_precision(
+            a_layout,
+            UPLO::Upper,
+            a,
+            b_layout,
+            x.as_allocated_mut()?,
+        )?
+    } else {
+        A::solve_mixed_precision(a_layout, a, b_layout, x.as_allocated_mut()?)?
+    };
+    let (iterations, refined) = match iter {
+        iter if iter >= 0 => (iter as usize, true),
+        ITERATION_LIMIT_EXCEEDED => (MAX_ITERATIONS, false),
+        _ => (0, false),
+    };
+    Ok(MixedPrecisionResult {
+        solution: x,
+        iterations,
+        refined,
+    })
+}
+
+fn solve_vector<A, Sa, Sb>(
+    a: &ArrayBase<Sa, Ix2>,
+    b: &ArrayBase<Sb, Ix1>,
+    cholesky: bool,
+) -> Result<MixedPrecisionResult<A, Ix1>>
+where
+    A: Scalar + Lapack + MixedPrecision_,
+    Sa: Data<Elem = A>,
+    Sb: Data<Elem = A>,
+{
+    let b = b.view().insert_axis(Axis(1));
+    let result = solve_nrhs(a, &b, cholesky)?;
+    Ok(MixedPrecisionResult {
+        solution: result.solution.index_axis_move(Axis(1), 0),
+        iterations: result.iterations,
+        refined: result.refined,
+    })
+}
+
+macro_rules! impl_solve_mixed_precision {
+    ($trait:ident, $method:ident, $cholesky:expr) => {
+        impl<A, Sa> $trait<A, Ix1> for ArrayBase<Sa, Ix2>
+        where
+            A: Scalar + Lapack + MixedPrecision_,
+            Sa: Data<Elem = A>,
+        {
+            fn $method<S: Data<Elem = A>>(
+                &self,
+                b: &ArrayBase<S, Ix1>,
+            ) -> Result<MixedPrecisionResult<A, Ix1>> {
+                solve_vector(self, b, $cholesky)
+            }
+        }
+
+        impl<A, Sa> $trait<A, Ix2> for ArrayBase<Sa, Ix2>
+        where
+            A: Scalar + Lapack + MixedPrecision_,
+            Sa: Data<Elem = A>,
+        {
+            fn $method<S: Data<Elem = A>>(
+                &self,
+                b: &ArrayBase<S, Ix2>,
+            ) -> Result<MixedPrecisionResult<A, Ix2>> {
+                solve_nrhs(self, b, $cholesky)
+            }
+        }
+    };
+}
+
+impl_solve_mixed_precision!(SolveMixedPrecision, solve_mixed_precision, false);
+impl_solve_mixed_precision!(SolveCMixedPrecision, solvec_mixed_precision, true);
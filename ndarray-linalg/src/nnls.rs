@@ -0,0 +1,307 @@
+//! Non-negative and bounded-variable least squares
+//!
+//! Solve the least squares problem with simple bounds on the variables
+//!
+//! $$ \min_x \\| Ax - b \\|_2 \quad \text{subject to} \quad l \le x \le u $$
+//!
+//! by the active set method of Lawson and Hanson, and its extension to the upper and lower bounds
+//! by Stark and Parker (BVLS). Each variable is either fixed at a bound or free,
+//! and the free variables solve the unconstrained least squares problem with the other variables fixed.
+//! The QR decomposition of the free columns of `A` is updated by Givens rotations
+//! whenever a variable is freed or fixed, so that each step costs $O(m^2)$ instead of a new factorization.
+//! The normal equations are never formed, and the free columns are accepted
+//! up to the condition number of about $1/\varepsilon$.
+//!
+//! The bounds can be infinite, and the non-negative least squares (NNLS) is the case of $l = 0$ and $u = \infty$.
+//! These problems are defined only for real matrices, i.e. `f32` and `f64`.
+//!
+//! ```
+//! use ndarray::*;
+//! use ndarray_linalg::*;
+//!
+//! let a: Array2<f64> = array![[1.0, 0.0], [1.0, 1.0], [0.0, 1.0]];
+//! let b = array![2.0, 1.0, -1.0];
+//! let result = a.least_squares_nonnegative(&b).unwrap();
+//! assert_close_l2!(&result.solution, &array![1.5, 0.0], 1e-12);
+//!
+//! let lower = array![0.0, -0.2];
+//! let upper = array![1.0, f64::INFINITY];
+//! let result = a.least_squares_bounded(&b, &lower, &upper).unwrap();
+//! assert_eq!(result.solution[0], 1.0);
+//! ```
+
+use ndarray::*;
+use num_traits::Float;
+
+use crate::error::*;
+use crate::qr_update::*;
+use crate::types::*;
+
+/// Result of the bounded-variable least squares
+#[derive(Debug, Clone)]
+pub struct BoundedLeastSquaresResult<A> {
+    /// The solution $x$ within the bounds
+    pub solution: Array1<A>,
+    /// The residual norm $\\| b - Ax \\|_2$
+    pub residual_norm: A,
+    /// The negative gradient $w = A^T (b - Ax)$, i.e. the Lagrange multipliers of the active bounds.
+    /// It is zero for the free variables, non-positive for the variables at the lower bounds,
+    /// and non-negative for the variables at the upper bounds.
+    pub dual: Array1<A>,
+    /// The number of iterations of the active set method
+    pub iterations: usize,
+}
+
+/// Least squares with bounds on the variables `min |b - Ax|`, where `A` is `self`
+pub trait LeastSquaresBounded<A> {
+    /// Non-negative least squares subject to $x \ge 0$
+    fn least_squares_nonnegative<Sb>(
+        &self,
+        b: &ArrayBase<Sb, Ix1>,
+    ) -> Result<BoundedLeastSquaresResult<A>>
+    where
+        Sb: Data<Elem = A>;
+
+    /// Bounded-variable least squares subject to `lower <= x <= upper`
+    ///
+    /// The bounds can be infinite to leave the variables unbounded on the side.
+    ///
+    /// [LinalgError::InvalidParameter] is returned if `lower[i] > upper[i]` or a bound is NaN.
+    fn least_squares_bounded<Sb, Sl, Su>(
+        &self,
+        b: &ArrayBase<Sb, Ix1>,
+        lower: &ArrayBase<Sl, Ix1>,
+        upper: &ArrayBase<Su, Ix1>,
+    ) -> Result<BoundedLeastSquaresResult<A>>
+    where
+        Sb: Data<Elem = A>,
+        Sl: Data<Elem = A>,
+        Su: Data<Elem = A>;
+}
+
+impl<A, S> LeastSquaresBounded<A> for ArrayBase<S, Ix2>
+where
+    A: Float + Scalar + Lapack,
+    S: Data<Elem = A>,
+{
+    fn least_squares_nonnegative<Sb>(
+        &self,
+        b: &ArrayBase<Sb, Ix1>,
+    ) -> Result<BoundedLeastSquaresResult<A>>
+    where
+        Sb: Data<Elem = A>,
+    {
+        let n = self.ncols();
+        self.least_squares_bounded(b, &Array1::zeros(n), &Array1::from_elem(n, A::infinity()))
+    }
+
+    fn least_squares_bounded<Sb, Sl, Su>(
+        &self,
+        b: &ArrayBase<Sb, Ix1>,
+        lower: &ArrayBase<Sl, Ix1>,
+        upper: &ArrayBase<Su, Ix1>,
+    ) -> Result<BoundedLeastSquaresResult<A>>
+    where
+        Sb: Data<Elem = A>,
+        Sl: Data<Elem = A>,
+        Su: Data<Elem = A>,
+    {
+        let (m, n) = self.dim();
+        if b.len() != m || lower.len() != n || upper.len() != n {
+            return Err(ShapeError::from_kind(ErrorKind::IncompatibleShape).into());
+        }
+        if lower
+            .iter()
+            .zip(upper)
+            .any(|(&l, &u)| l.is_nan() || u.is_nan() || l > u)
+        {
+            return Err(LinalgError::InvalidParameter { name: "bounds" });
+        }
+        bounded_least_squares(self.view(), b.view(), lower.view(), upper.view())
+    }
+}
+
+/// QR decomposition of the free columns $A_F = QR$
+struct FreeColumnsQR<A: Scalar> {
+    /// Indices of the free variables in the order of the columns of $R$
+    free: Vec<usize>,
+    qr: QRFactorized<A>,
+}
+
+impl<A: Float + Scalar + Lapack> FreeColumnsQR<A> {
+    fn new(m: usize) -> Result<Self> {
+        Ok(FreeColumnsQR {
+            free: Vec::new(),
+            qr: Array2::<A>::zeros((m, 0)).factorize_qr()?,
+        })
+    }
+
+    /// Append the column `j` of `a` to the decomposition, or return `false` if it is numerically
+    /// dependent on the free columns
+    fn insert(&mut self, a: &ArrayView2<A>, j: usize) -> Result<bool> {
+        let (m, _) = a.dim();
+        let k = self.free.len();
+        if k >= m {
+            return Ok(false);
+        }
+        let column = a.column(j);
+        self.qr.insert_column(k, &column)?;
+        let column_norm = Float::sqrt(column.dot(&column));
+        let eps = <A as Float>::epsilon() * A::from(m).unwrap();
+        if Float::abs(self.qr.r_full()[(k, k)]) <= eps * column_norm {
+            self.qr.delete_column(k)?;
+            return Ok(false);
+        }
+        self.free.push(j);
+        Ok(true)
+    }
+
+    /// Remove the `p`-th free column
+    fn remove(&mut self, p: usize) -> Result<()> {
+        self.qr.delete_column(p)?;
+        self.free.remove(p);
+        Ok(())
+    }
+
+    /// Solve $\min_z \| A_F z - c \|_2$ by $R z = Q^T c$
+    fn solve(&self, c: &Array1<A>) -> Vec<A> {
+        let k = self.free.len();
+        let q = self.qr.q_full();
+        let r = self.qr.r_full();
+        let mut z: Vec<A> = (0..k).map(|i| q.column(i).dot(c)).collect();
+        for i in (0..k).rev() {
+            let v = (i + 1..k).fold(z[i], |acc, l| acc - r[(i, l)] * z[l]);
+            z[i] = v / r[(i, i)];
+        }
+        z
+    }
+}
+
+fn bounded_least_squares<A: Float + Scalar + Lapack>(
+    a: ArrayView2<A>,
+    b: ArrayView1<A>,
+    lower: ArrayView1<A>,
+    upper: ArrayView1<A>,
+) -> Result<BoundedLeastSquaresResult<A>> {
+    let (m, n) = a.dim();
+    let a_norm = a
+        .axis_iter(Axis(1))
+        .map(|col| col.iter().fold(A::zero(), |acc, &x| acc + Float::abs(x)))
+        .fold(A::zero(), Float::max);
+    let tol = A::from(10 * m.max(n)).unwrap() * <A as Float>::epsilon() * a_norm;
+    let max_iter = 3 * n;
+
+    // Start from the bounds, or zero for the variables unbounded on both sides
+    let mut x = Array1::from_shape_fn(n, |i| {
+        if lower[i].is_finite() {
+            lower[i]
+        } else if upper[i].is_finite() {
+            upper[i]
+        } else {
+            A::zero()
+        }
+    });
+    let mut qr = FreeColumnsQR::new(m)?;
+    let mut is_free = vec![false; n];
+    let mut rejected = vec![false; n];
+    let mut iterations = 0;
+
+    loop {
+        let w = a.t().dot(&(&b - &a.dot(&x)));
+        let entering = (0..n)
+            .filter(|&i| !is_free[i] && !rejected[i])
+            .filter(|&i| (w[i] > tol && x[i] < upper[i]) || (w[i] < -tol && x[i] > lower[i]))
+            .fold(None, |best: Option<usize>, i| match best {
+                Some(j) if Float::abs(w[j]) >= Float::abs(w[i]) => Some(j),
+                _ => Some(i),
+            });
+        let t = match entering {
+            Some(t) => t,
+            None => break,
+        };
+        if iterations >= max_iter {
+            return Err(LinalgError::NotConverged { iterations });
+        }
+        iterations += 1;
+        if !qr.insert(&a, t)? {
+            rejected[t] = true;
+            continue;
+        }
+        is_free[t] = true;
+
+        let mut first = true;
+        loop {
+            // Unconstrained solution z of the free variables with the others fixed
+            let mut rhs = b.to_owned();
+            for j in (0..n).filter(|&j| !is_free[j]) {
+                rhs.scaled_add(-x[j], &a.column(j));
+            }
+            let z = qr.solve(&rhs);
+
+            // The entering variable must move away from its bound, otherwise it is roundoff
+            if first {
+                first = false;
+                let p = qr.free.len() - 1;
+                if (w[t] > A::zero() && z[p] <= x[t]) || (w[t] < A::zero() && z[p] >= x[t]) {
+                    qr.remove(p)?;
+                    is_free[t] = false;
+                    rejected[t] = true;
+                    break;
+                }
+            }
+
+            // Step toward z as far as the bounds allow
+            let mut alpha = A::one();
+            let mut blocking = None;
+            for (p, (&i, &zi)) in qr.free.iter().zip(&z).enumerate() {
+                let bound = if zi <= lower[i] {
+                    lower[i]
+                } else if zi >= upper[i] {
+                    upper[i]
+                } else {
+                    continue;
+                };
+                let step = (bound - x[i]) / (zi - x[i]);
+                if blocking.is_none() || step < alpha {
+                    alpha = Float::min(step, A::one());
+                    blocking = Some(p);
+                }
+            }
+            let blocking = match blocking {
+                Some(p) => p,
+                None => {
+                    for (&i, &zi) in qr.free.iter().zip(&z) {
+                        x[i] = zi;
+                    }
+                    rejected.iter_mut().for_each(|r| *r = false);
+                    break;
+                }
+            };
+
+            // Fix the blocking variable and the others which reach their bounds
+            for (p, (&i, &zi)) in qr.free.iter().zip(&z).enumerate() {
+                x[i] = x[i] + alpha * (zi - x[i]);
+                if p == blocking {
+                    x[i] = if zi <= lower[i] { lower[i] } else { upper[i] };
+                }
+            }
+            for p in (0..qr.free.len()).rev() {
+                let i = qr.free[p];
+                if x[i] <= lower[i] || x[i] >= upper[i] {
+                    x[i] = Float::max(lower[i], Float::min(x[i], upper[i]));
+                    is_free[i] = false;
+                    qr.remove(p)?;
+                }
+            }
+        }
+    }
+
+    let residual = &b - &a.dot(&x);
+    let dual = a.t().dot(&residual);
+    Ok(BoundedLeastSquaresResult {
+        solution: x,
+        residual_norm: Float::sqrt(residual.dot(&residual)),
+        dual,
+        iterations,
+    })
+}
@@ -80,3 +80,26 @@ where
         self.dot(a)
     }
 }
+
+/// Linear operator which can also apply its adjoint (conjugate transpose) $A^\dagger$
+pub trait AdjointOperator: LinearOperator {
+    /// Apply the adjoint operator out-place
+    fn apply_adjoint<S>(&self, a: &ArrayBase<S, Ix1>) -> Array1<S::Elem>
+    where
+        S: Data<Elem = Self::Elem>;
+}
+
+impl<A, Sa> AdjointOperator for ArrayBase<Sa, Ix2>
+where
+    A: Scalar,
+    Sa: Data<Elem = A>,
+{
+    fn apply_adjoint<S>(&self, a: &ArrayBase<S, Ix1>) -> Array1<A>
+    where
+        S: Data<Elem = A>,
+    {
+        // A^H x = conj(A^T conj(x))
+        let a = a.mapv(|x| x.conj());
+        self.t().dot(&a).mapv_into(|x| x.conj())
+    }
+}
@@ -1,10 +1,12 @@
 //! Operator norm
 
-use lax::Tridiagonal;
+use lax::{Tridiagonal, UPLO};
 use ndarray::*;
+use num_traits::{Float, One, Zero};
 
 use crate::error::*;
 use crate::layout::*;
+use crate::svddc::*;
 use crate::types::*;
 
 pub use lax::NormType;
@@ -101,3 +103,84 @@ where
         Ok(A::opnorm(t, l, a))
     }
 }
+
+/// Norms defined by the singular values $\sigma_i$ of a matrix
+///
+/// [Wikipedia article on Schatten norm](https://en.wikipedia.org/wiki/Schatten_norm)
+pub trait SpectralNorm {
+    /// the value of norm
+    type Output: Scalar;
+
+    /// Schatten p-norm $ \left( \sum_i \sigma_i^p \right)^{1/p} $
+    ///
+    /// `p = 1`, `p = 2` and `p = ∞` correspond to the nuclear, Frobenius and spectral norms, respectively.
+    /// [LinalgError::InvalidParameter] is returned for `p < 1` or NaN, where this is not a norm.
+    fn opnorm_schatten(&self, p: Self::Output) -> Result<Self::Output>;
+
+    /// the spectral norm of a matrix (largest singular value)
+    fn opnorm_2(&self) -> Result<Self::Output>;
+
+    /// the nuclear norm of a matrix (sum of singular values)
+    fn opnorm_nuclear(&self) -> Result<Self::Output> {
+        self.opnorm_schatten(Self::Output::one())
+    }
+}
+
+impl<A, S> SpectralNorm for ArrayBase<S, Ix2>
+where
+    A: Scalar + Lapack,
+    S: Data<Elem = A>,
+{
+    type Output = A::Real;
+
+    fn opnorm_schatten(&self, p: Self::Output) -> Result<Self::Output> {
+        if p.is_nan() || p < A::Real::one() {
+            return Err(LinalgError::InvalidParameter { name: "p" });
+        }
+        if Float::is_infinite(p) {
+            return self.opnorm_2();
+        }
+        let s = singular_values(self)?;
+        let sum: A::Real = s.iter().map(|&s| Float::powf(s, p)).sum();
+        Ok(Float::powf(sum, p.recip()))
+    }
+
+    fn opnorm_2(&self) -> Result<Self::Output> {
+        // singular values are sorted in descending order
+        Ok(singular_values(self)?
+            .first()
+            .cloned()
+            .unwrap_or_else(A::Real::zero))
+    }
+}
+
+/// 1-norm of Hermitian matrix using only its triangular portion specified by `uplo`
+pub(crate) fn opnorm_one_hermitian<A, S>(a: &ArrayBase<S, Ix2>, uplo: UPLO) -> A::Real
+where
+    A: Scalar,
+    S: Data<Elem = A>,
+{
+    (0..a.ncols())
+        .map(|j| {
+            (0..a.nrows())
+                .map(|i| match uplo {
+                    UPLO::Upper if i > j => a[(j, i)].abs(),
+                    UPLO::Lower if i < j => a[(j, i)].abs(),
+                    _ => a[(i, j)].abs(),
+                })
+                .sum::<A::Real>()
+        })
+        .fold(A::Real::zero(), Float::max)
+}
+
+fn singular_values<A, S>(a: &ArrayBase<S, Ix2>) -> Result<Array1<A::Real>>
+where
+    A: Scalar + Lapack,
+    S: Data<Elem = A>,
+{
+    if a.is_empty() {
+        return Ok(Array1::zeros(0));
+    }
+    let (_, s, _) = a.to_owned().svddc_into(UVTFlag::None)?;
+    Ok(s)
+}
@@ -0,0 +1,157 @@
+//! Moore-Penrose pseudo-inverse
+//!
+//! $$ A^+ = V \Sigma^+ U^\dagger $$
+//!
+//! where $A = U \Sigma V^\dagger$ is the singular value decomposition,
+//! and $\Sigma^+$ inverts only the singular values larger than a cutoff.
+//! For Hermitian matrices, the eigendecomposition is used instead of SVD.
+//!
+//! ```
+//! use ndarray::*;
+//! use ndarray_linalg::*;
+//!
+//! // Rank-deficient matrix
+//! let a: Array2<f64> = array![[1.0, 2.0], [2.0, 4.0], [3.0, 6.0]];
+//! let (a_pinv, rank) = a.pinv(Cutoff::Auto).unwrap();
+//! assert_eq!(rank, 1);
+//! assert_close_l2!(&a.dot(&a_pinv).dot(&a), &a, 1e-9);
+//! ```
+
+use ndarray::*;
+use num_traits::{Float, Zero};
+
+use crate::eigh::*;
+use crate::error::*;
+use crate::generate::*;
+use crate::svddc::*;
+use crate::types::*;
+use crate::UPLO;
+
+/// Cutoff of small singular values (or eigenvalues) regarded as zero
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Cutoff<A> {
+    /// $\sigma_i \le \max(m, n) \epsilon \sigma_\text{max}$ is regarded as zero,
+    /// where $\epsilon$ is the machine epsilon
+    Auto,
+    /// $\sigma_i \le r \sigma_\text{max}$ is regarded as zero
+    Relative(A),
+    /// $\sigma_i \le r$ is regarded as zero
+    Absolute(A),
+}
+
+impl<A: Float> Cutoff<A> {
+    /// Threshold for the singular values of a `(rows, cols)` matrix whose largest singular value is `max`
+    pub fn threshold(&self, rows: usize, cols: usize, max: A) -> A {
+        match *self {
+            Cutoff::Auto => A::from(rows.max(cols)).unwrap() * A::epsilon() * max,
+            Cutoff::Relative(r) => r * max,
+            Cutoff::Absolute(r) => r,
+        }
+    }
+}
+
+/// Pseudo-inverse of general matrix reference
+pub trait Pinv<A: Scalar> {
+    type Output;
+    /// Compute the pseudo-inverse and the effective rank, i.e. the number of
+    /// singular values larger than the cutoff
+    fn pinv(&self, cutoff: Cutoff<A::Real>) -> Result<(Self::Output, usize)>;
+}
+
+/// Pseudo-inverse of general matrix
+pub trait PinvInto<A: Scalar> {
+    type Output;
+    /// Compute the pseudo-inverse and the effective rank by consuming the matrix
+    fn pinv_into(self, cutoff: Cutoff<A::Real>) -> Result<(Self::Output, usize)>;
+}
+
+/// Pseudo-inverse of Hermitian matrix reference
+pub trait Pinvh<A: Scalar> {
+    type Output;
+    /// Compute the pseudo-inverse and the effective rank using the eigendecomposition,
+    /// where the cutoff is applied to the absolute values of the eigenvalues
+    fn pinvh(&self, uplo: UPLO, cutoff: Cutoff<A::Real>) -> Result<(Self::Output, usize)>;
+}
+
+/// Pseudo-inverse of Hermitian matrix
+pub trait PinvhInto<A: Scalar> {
+    type Output;
+    /// Compute the pseudo-inverse and the effective rank using the eigendecomposition
+    /// by consuming the matrix
+    fn pinvh_into(self, uplo: UPLO, cutoff: Cutoff<A::Real>) -> Result<(Self::Output, usize)>;
+}
+
+impl<A, S> Pinv<A> for ArrayBase<S, Ix2>
+where
+    A: Scalar + Lapack,
+    S: Data<Elem = A>,
+{
+    type Output = Array2<A>;
+
+    fn pinv(&self, cutoff: Cutoff<A::Real>) -> Result<(Self::Output, usize)> {
+        self.to_owned().pinv_into(cutoff)
+    }
+}
+
+impl<A, S> PinvInto<A> for ArrayBase<S, Ix2>
+where
+    A: Scalar + Lapack,
+    S: DataMut<Elem = A>,
+{
+    type Output = Array2<A>;
+
+    fn pinv_into(self, cutoff: Cutoff<A::Real>) -> Result<(Self::Output, usize)> {
+        let (m, n) = self.dim();
+        if m == 0 || n == 0 {
+            return Ok((Array2::zeros((n, m)), 0));
+        }
+        let (u, s, vt) = self.svddc_into(UVTFlag::Some)?;
+        let (u, vt) = (u.unwrap(), vt.unwrap());
+        // singular values are sorted in descending order
+        let threshold = cutoff.threshold(m, n, s[0]);
+        let rank = s.iter().take_while(|&&s| s > threshold).count();
+
+        let mut v: Array2<A> = conjugate(&vt.slice(s![..rank, ..]));
+        for (mut col, &s) in v.axis_iter_mut(Axis(1)).zip(s.iter()) {
+            col.mapv_inplace(|x| x.div_real(s));
+        }
+        let uh: Array2<A> = conjugate(&u.slice(s![.., ..rank]));
+        Ok((v.dot(&uh), rank))
+    }
+}
+
+impl<A, S> Pinvh<A> for ArrayBase<S, Ix2>
+where
+    A: Scalar + Lapack,
+    S: Data<Elem = A>,
+{
+    type Output = Array2<A>;
+
+    fn pinvh(&self, uplo: UPLO, cutoff: Cutoff<A::Real>) -> Result<(Self::Output, usize)> {
+        self.to_owned().pinvh_into(uplo, cutoff)
+    }
+}
+
+impl<A, S> PinvhInto<A> for ArrayBase<S, Ix2>
+where
+    A: Scalar + Lapack,
+    S: DataMut<Elem = A>,
+{
+    type Output = Array2<A>;
+
+    fn pinvh_into(self, uplo: UPLO, cutoff: Cutoff<A::Real>) -> Result<(Self::Output, usize)> {
+        let n = self.nrows();
+        let (e, v) = self.eigh_into(uplo)?;
+        let max = e.iter().fold(A::Real::zero(), |m, e| m.max(Float::abs(*e)));
+        let threshold = cutoff.threshold(n, n, max);
+
+        let selected: Vec<usize> = (0..n).filter(|&i| Float::abs(e[i]) > threshold).collect();
+        let v = v.select(Axis(1), &selected);
+        let mut ve = v.clone();
+        for (mut col, &i) in ve.axis_iter_mut(Axis(1)).zip(selected.iter()) {
+            col.mapv_inplace(|x| x.div_real(e[i]));
+        }
+        let vh: Array2<A> = conjugate(&v);
+        Ok((ve.dot(&vh), selected.len()))
+    }
+}
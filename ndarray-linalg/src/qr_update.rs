@@ -0,0 +1,268 @@
+//! Updating QR decomposition
+//!
+//! [QRFactorized] keeps the full QR decomposition $A = QR$, where $Q$ is an `m x m` unitary matrix
+//! and $R$ is an `m x n` upper triangular matrix, and updates it by Givens rotations
+//! when a row or column is inserted into or deleted from $A$.
+//! Each update costs $O(m^2)$ operations (or $O(mn)$ for deleting a column)
+//! instead of $O(mn^2)$ for a new decomposition.
+//!
+//! ```
+//! use ndarray::*;
+//! use ndarray_linalg::*;
+//!
+//! let a: Array2<f64> = random((5, 3));
+//! let mut qr = a.factorize_qr().unwrap();
+//!
+//! // Append an observation and drop the second feature
+//! let row: Array1<f64> = random(3);
+//! qr.insert_row(5, &row).unwrap();
+//! qr.delete_column(1).unwrap();
+//!
+//! let (q, r) = qr.qr();
+//! assert_eq!(q.dim(), (6, 2));
+//! assert_eq!(r.dim(), (2, 2));
+//! let mut b = Array2::zeros((6, 2));
+//! b.slice_mut(s![..5, 0]).assign(&a.column(0));
+//! b.slice_mut(s![..5, 1]).assign(&a.column(2));
+//! b[(5, 0)] = row[0];
+//! b[(5, 1)] = row[2];
+//! assert_close_l2!(&q.dot(&r), &b, 1e-9);
+//! ```
+
+use ndarray::*;
+use num_traits::{Float, Zero};
+
+use crate::error::*;
+use crate::layout::*;
+use crate::types::*;
+
+/// Full QR decomposition $A = QR$ which can be updated
+#[derive(Debug, Clone)]
+pub struct QRFactorized<A: Scalar> {
+    /// `m x m` unitary matrix
+    q: Array2<A>,
+    /// `m x n` upper triangular matrix
+    r: Array2<A>,
+}
+
+/// Compute the updatable QR decomposition of the matrix `A`, where `A` is `self`
+pub trait FactorizeQR<A: Scalar> {
+    fn factorize_qr(&self) -> Result<QRFactorized<A>>;
+}
+
+impl<A, S> FactorizeQR<A> for ArrayBase<S, Ix2>
+where
+    A: Scalar + Lapack,
+    S: Data<Elem = A>,
+{
+    fn factorize_qr(&self) -> Result<QRFactorized<A>> {
+        let (m, n) = self.dim();
+        let k = m.min(n);
+        let mut a = Array2::zeros((m, n).f());
+        a.assign(self);
+        let mut q = Array2::zeros((m, m).f());
+        if k > 0 {
+            let tau = A::householder(a.layout()?, a.as_allocated_mut()?)?;
+            // The reflectors with zero scalar factors are identity, which extends Q to m x m
+            q.slice_mut(s![.., ..k]).assign(&a.slice(s![.., ..k]));
+            let mut tau = tau;
+            tau.resize(m, A::zero());
+            A::q(q.layout()?, q.as_allocated_mut()?, &tau)?;
+        } else {
+            q.diag_mut().fill(A::one());
+        }
+        Zip::indexed(&mut a).for_each(|(i, j), x| {
+            if i > j {
+                *x = A::zero();
+            }
+        });
+        Ok(QRFactorized { q, r: a })
+    }
+}
+
+/// Givens rotation $G = \begin{pmatrix} c & s \\\\ -\bar{s} & c \end{pmatrix}$ with real $c$
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct Givens<A: Scalar> {
+    pub(crate) c: A::Real,
+    pub(crate) s: A,
+}
+
+impl<A: Scalar> Givens<A> {
+    /// Rotation which maps $(a, b)$ to $(r, 0)$
+    pub(crate) fn new(a: A, b: A) -> Self {
+        let abs_a = a.abs();
+        let r = Float::sqrt(a.square() + b.square());
+        if abs_a.is_zero() {
+            Givens {
+                c: A::Real::zero(),
+                s: A::one(),
+            }
+        } else {
+            Givens {
+                c: abs_a / r,
+                s: (a.div_real(abs_a) * b.conj()).div_real(r),
+            }
+        }
+    }
+
+    /// Apply $G$ to the vector $(x, y)$
+    pub(crate) fn rotate(&self, x: A, y: A) -> (A, A) {
+        (
+            x.mul_real(self.c) + self.s * y,
+            y.mul_real(self.c) - self.s.conj() * x,
+        )
+    }
+
+    /// Apply $G$ to the rows `p` and `q` of `a` from the column `from`
+    fn rotate_rows(&self, a: &mut Array2<A>, p: usize, q: usize, from: usize) {
+        for j in from..a.ncols() {
+            let (x, y) = self.rotate(a[(p, j)], a[(q, j)]);
+            a[(p, j)] = x;
+            a[(q, j)] = y;
+        }
+    }
+
+    /// Apply $G^\dagger$ to the columns `p` and `q` of `a` from the right
+    fn rotate_columns(&self, a: &mut Array2<A>, p: usize, q: usize) {
+        for i in 0..a.nrows() {
+            let (x, y) = (a[(i, p)], a[(i, q)]);
+            a[(i, p)] = x.mul_real(self.c) + self.s.conj() * y;
+            a[(i, q)] = y.mul_real(self.c) - self.s * x;
+        }
+    }
+}
+
+impl<A> QRFactorized<A>
+where
+    A: Scalar + Lapack,
+{
+    /// The full `m x m` unitary matrix $Q$
+    pub fn q_full(&self) -> &Array2<A> {
+        &self.q
+    }
+
+    /// The full `m x n` upper triangular matrix $R$
+    pub fn r_full(&self) -> &Array2<A> {
+        &self.r
+    }
+
+    /// The reduced decomposition in the same shape as [QR::qr](crate::qr::QR::qr),
+    /// i.e. `m x k` matrix $Q$ and `k x n` matrix $R$ where `k = min(m, n)`
+    pub fn qr(&self) -> (Array2<A>, Array2<A>) {
+        let (m, n) = self.r.dim();
+        let k = m.min(n);
+        (
+            self.q.slice(s![.., ..k]).to_owned(),
+            self.r.slice(s![..k, ..]).to_owned(),
+        )
+    }
+
+    /// Insert `row` into $A$ as the `index`-th row
+    pub fn insert_row<Sr>(&mut self, index: usize, row: &ArrayBase<Sr, Ix1>) -> Result<()>
+    where
+        Sr: Data<Elem = A>,
+    {
+        let (m, n) = self.r.dim();
+        if row.len() != n {
+            return Err(ShapeError::from_kind(ErrorKind::IncompatibleShape).into());
+        }
+        if index > m {
+            return Err(ShapeError::from_kind(ErrorKind::OutOfBounds).into());
+        }
+        // [row; A] = diag(1, Q) [row; R], where [row; R] is upper Hessenberg
+        let mut q = Array2::zeros((m + 1, m + 1));
+        q[(0, 0)] = A::one();
+        q.slice_mut(s![1.., 1..]).assign(&self.q);
+        let mut r = Array2::zeros((m + 1, n));
+        r.row_mut(0).assign(row);
+        r.slice_mut(s![1.., ..]).assign(&self.r);
+        for j in 0..n.min(m) {
+            let g = Givens::new(r[(j, j)], r[(j + 1, j)]);
+            g.rotate_rows(&mut r, j, j + 1, j);
+            r[(j + 1, j)] = A::zero();
+            g.rotate_columns(&mut q, j, j + 1);
+        }
+        // Move the first row of Q to the `index`-th row
+        let first = q.row(0).to_owned();
+        for i in 0..index {
+            let next = q.row(i + 1).to_owned();
+            q.row_mut(i).assign(&next);
+        }
+        q.row_mut(index).assign(&first);
+        self.q = q;
+        self.r = r;
+        Ok(())
+    }
+
+    /// Delete the `index`-th row of $A$
+    pub fn delete_row(&mut self, index: usize) -> Result<()> {
+        let (m, _) = self.r.dim();
+        if index >= m {
+            return Err(ShapeError::from_kind(ErrorKind::OutOfBounds).into());
+        }
+        // Rotate the `index`-th row of Q into the first column
+        let mut v = self.q.row(index).mapv(|x| x.conj());
+        for j in (1..m).rev() {
+            let g = Givens::new(v[j - 1], v[j]);
+            let (x, y) = (v[j - 1], v[j]);
+            v[j - 1] = x.mul_real(g.c) + g.s * y;
+            v[j] = A::zero();
+            g.rotate_rows(&mut self.r, j - 1, j, 0);
+            g.rotate_columns(&mut self.q, j - 1, j);
+        }
+        // Now the `index`-th row of Q is a unit multiple of e_1, and the rest of R is upper triangular
+        let rows: Vec<usize> = (0..m).filter(|&i| i != index).collect();
+        self.q = self.q.select(Axis(0), &rows).slice(s![.., 1..]).to_owned();
+        self.r = self.r.slice(s![1.., ..]).to_owned();
+        Ok(())
+    }
+
+    /// Insert `column` into $A$ as the `index`-th column
+    pub fn insert_column<Sc>(&mut self, index: usize, column: &ArrayBase<Sc, Ix1>) -> Result<()>
+    where
+        Sc: Data<Elem = A>,
+    {
+        let (m, n) = self.r.dim();
+        if column.len() != m {
+            return Err(ShapeError::from_kind(ErrorKind::IncompatibleShape).into());
+        }
+        if index > n {
+            return Err(ShapeError::from_kind(ErrorKind::OutOfBounds).into());
+        }
+        let w = self.q.t().mapv(|x| x.conj()).dot(column);
+        let mut r = Array2::zeros((m, n + 1));
+        r.slice_mut(s![.., ..index])
+            .assign(&self.r.slice(s![.., ..index]));
+        r.column_mut(index).assign(&w);
+        r.slice_mut(s![.., index + 1..])
+            .assign(&self.r.slice(s![.., index..]));
+        // Zero the new column below the diagonal from the bottom
+        for j in (index + 1..m).rev() {
+            let g = Givens::new(r[(j - 1, index)], r[(j, index)]);
+            g.rotate_rows(&mut r, j - 1, j, index);
+            r[(j, index)] = A::zero();
+            g.rotate_columns(&mut self.q, j - 1, j);
+        }
+        self.r = r;
+        Ok(())
+    }
+
+    /// Delete the `index`-th column of $A$
+    pub fn delete_column(&mut self, index: usize) -> Result<()> {
+        let (m, n) = self.r.dim();
+        if index >= n {
+            return Err(ShapeError::from_kind(ErrorKind::OutOfBounds).into());
+        }
+        let columns: Vec<usize> = (0..n).filter(|&j| j != index).collect();
+        let mut r = self.r.select(Axis(1), &columns);
+        // R is upper Hessenberg from the `index`-th column
+        for j in index..(n - 1).min(m.saturating_sub(1)) {
+            let g = Givens::new(r[(j, j)], r[(j + 1, j)]);
+            g.rotate_rows(&mut r, j, j + 1, j);
+            r[(j + 1, j)] = A::zero();
+            g.rotate_columns(&mut self.q, j, j + 1);
+        }
+        self.r = r;
+        Ok(())
+    }
+}
@@ -0,0 +1,237 @@
+//! Randomized singular value decomposition
+//!
+//! [RandomizedSvd] computes the `k` largest singular values and vectors
+//! by the algorithm of Halko, Martinsson and Tropp (2011).
+//! First [range_finder] builds an orthonormal basis $Q$ of the approximate range of $A$
+//! from the samples $Y = (A A^\dagger)^q A \Omega$ of a random `n x (k + p)` matrix $\Omega$,
+//! where `p` is the oversampling and `q` is the number of power iterations.
+//! Then the small matrix $B = Q^\dagger A = U_B \Sigma V^\dagger$ is decomposed directly,
+//! and $A \approx (Q U_B) \Sigma V^\dagger$.
+//!
+//! Unlike [TruncatedSvd](crate::TruncatedSvd), which solves the eigenvalue problem of $A^\dagger A$,
+//! this does not square the condition number, and only needs the products of $A$ and $A^\dagger$
+//! with blocks of vectors. Hence it also works on any [AdjointOperator].
+//! The power iterations improve the accuracy when the singular values decay slowly,
+//! and each of them takes the QR decomposition to keep the samples from losing the smaller singular directions.
+//!
+//! ```
+//! use ndarray::*;
+//! use ndarray_linalg::*;
+//!
+//! // Rank 3 matrix
+//! let x: Array2<f64> = random((20, 3));
+//! let y: Array2<f64> = random((3, 15));
+//! let a = x.dot(&y);
+//!
+//! let (u, s, vt) = RandomizedSvd::new(3).seed(42).decompose(&a).unwrap();
+//! assert_eq!(u.dim(), (20, 3));
+//! assert_eq!(vt.dim(), (3, 15));
+//!
+//! let s_ref = a.svd(false, false).unwrap().1;
+//! assert_close_l2!(&s, &s_ref.slice(s![..3]), 1e-9);
+//! ```
+
+use ndarray::*;
+use rand::prelude::*;
+
+use crate::error::*;
+use crate::generate::*;
+use crate::operator::*;
+use crate::qr::*;
+use crate::svddc::*;
+use crate::types::*;
+
+/// Orthonormal basis $Q$ of the approximate range of the operator $A$ acting on `n`-dimensional vectors
+///
+/// The range is sampled by `size` random vectors drawn from `rng`,
+/// and refined by `power_iterations` steps of the subspace iteration with $A A^\dagger$.
+/// The returned $Q$ has `min(m, n, size)` columns where `m` is the dimension of the range,
+/// so that it is empty if `n` or `size` is zero.
+pub fn range_finder<A, Op, R>(
+    a: &Op,
+    n: usize,
+    size: usize,
+    power_iterations: usize,
+    rng: &mut R,
+) -> Result<Array2<A>>
+where
+    A: Scalar + Lapack,
+    Op: AdjointOperator<Elem = A>,
+    R: Rng,
+{
+    sample_range(
+        |x| a.apply2(x),
+        |x| apply2_adjoint(a, x),
+        n,
+        size,
+        power_iterations,
+        rng,
+    )
+}
+
+/// [range_finder] using the products `mul(X) = AX` and `mul_adjoint(X) = A^H X` with blocks of vectors
+fn sample_range<A, F, G, R>(
+    mul: F,
+    mul_adjoint: G,
+    n: usize,
+    size: usize,
+    power_iterations: usize,
+    rng: &mut R,
+) -> Result<Array2<A>>
+where
+    A: Scalar + Lapack,
+    F: Fn(&Array2<A>) -> Array2<A>,
+    G: Fn(&Array2<A>) -> Array2<A>,
+    R: Rng,
+{
+    if n == 0 || size == 0 {
+        // The dimension of the range is known only from a product
+        let m = mul(&Array2::zeros((n, 1))).nrows();
+        return Ok(Array2::zeros((m, 0)));
+    }
+    let omega: Array2<A> = random_using((n, size.min(n)), rng);
+    let y = mul(&omega);
+    if y.nrows() == 0 {
+        return Ok(Array2::zeros((0, 0)));
+    }
+    let (mut q, _) = y.qr_into()?;
+    for _ in 0..power_iterations {
+        let (z, _) = mul_adjoint(&q).qr_into()?;
+        q = mul(&z).qr_into()?.0;
+    }
+    Ok(q)
+}
+
+/// Apply the adjoint operator to each column of `a`
+fn apply2_adjoint<A, Op>(op: &Op, a: &Array2<A>) -> Array2<A>
+where
+    A: Scalar,
+    Op: AdjointOperator<Elem = A>,
+{
+    let cols: Vec<_> = a
+        .axis_iter(Axis(1))
+        .map(|col| op.apply_adjoint(&col))
+        .collect();
+    hstack(&cols).unwrap()
+}
+
+/// Randomized SVD for the `rank` largest singular values and vectors
+#[derive(Debug, Clone)]
+pub struct RandomizedSvd {
+    rank: usize,
+    oversampling: usize,
+    power_iterations: usize,
+    seed: Option<u64>,
+}
+
+impl RandomizedSvd {
+    /// Compute the `rank` largest singular values with 10 oversamples and 2 power iterations
+    pub fn new(rank: usize) -> Self {
+        RandomizedSvd {
+            rank,
+            oversampling: 10,
+            power_iterations: 2,
+            seed: None,
+        }
+    }
+
+    /// Number of additional samples of the range
+    pub fn oversampling(mut self, oversampling: usize) -> Self {
+        self.oversampling = oversampling;
+        self
+    }
+
+    /// Number of power iterations
+    pub fn power_iterations(mut self, power_iterations: usize) -> Self {
+        self.power_iterations = power_iterations;
+        self
+    }
+
+    /// Seed of the random samples for reproducible results, which are drawn from `thread_rng` by default
+    pub fn seed(mut self, seed: u64) -> Self {
+        self.seed = Some(seed);
+        self
+    }
+
+    /// Decompose the dense matrix into the left singular vectors `m x k`,
+    /// the `k` singular values in descending order and the right singular vectors `k x n`
+    pub fn decompose<A, S>(
+        &self,
+        a: &ArrayBase<S, Ix2>,
+    ) -> Result<(Array2<A>, Array1<A::Real>, Array2<A>)>
+    where
+        A: Scalar + Lapack,
+        S: Data<Elem = A>,
+    {
+        let ah: Array2<A> = conjugate(a);
+        let n = a.ncols();
+        let mul = |x: &Array2<A>| a.dot(x);
+        let mul_adjoint = |x: &Array2<A>| ah.dot(x);
+        match self.seed {
+            Some(seed) => {
+                self.decompose_using(mul, mul_adjoint, n, &mut StdRng::seed_from_u64(seed))
+            }
+            None => self.decompose_using(mul, mul_adjoint, n, &mut thread_rng()),
+        }
+    }
+
+    /// Decompose the operator acting on `n`-dimensional vectors in the same way as [RandomizedSvd::decompose]
+    pub fn decompose_operator<A, Op>(
+        &self,
+        a: &Op,
+        n: usize,
+    ) -> Result<(Array2<A>, Array1<A::Real>, Array2<A>)>
+    where
+        A: Scalar + Lapack,
+        Op: AdjointOperator<Elem = A>,
+    {
+        let mul = |x: &Array2<A>| a.apply2(x);
+        let mul_adjoint = |x: &Array2<A>| apply2_adjoint(a, x);
+        match self.seed {
+            Some(seed) => {
+                self.decompose_using(mul, mul_adjoint, n, &mut StdRng::seed_from_u64(seed))
+            }
+            None => self.decompose_using(mul, mul_adjoint, n, &mut thread_rng()),
+        }
+    }
+
+    fn decompose_using<A, F, G, R>(
+        &self,
+        mul: F,
+        mul_adjoint: G,
+        n: usize,
+        rng: &mut R,
+    ) -> Result<(Array2<A>, Array1<A::Real>, Array2<A>)>
+    where
+        A: Scalar + Lapack,
+        F: Fn(&Array2<A>) -> Array2<A>,
+        G: Fn(&Array2<A>) -> Array2<A>,
+        R: Rng,
+    {
+        let q = sample_range(
+            &mul,
+            &mul_adjoint,
+            n,
+            self.rank + self.oversampling,
+            self.power_iterations,
+            rng,
+        )?;
+        if q.ncols() == 0 {
+            return Ok((
+                Array2::zeros((q.nrows(), 0)),
+                Array1::zeros(0),
+                Array2::zeros((0, n)),
+            ));
+        }
+        // B = Q^H A = (A^H Q)^H
+        let b: Array2<A> = conjugate(&mul_adjoint(&q));
+        let (ub, s, vt) = b.svddc_into(UVTFlag::Some)?;
+        let (ub, vt) = (ub.unwrap(), vt.unwrap());
+        let k = self.rank.min(s.len());
+        Ok((
+            q.dot(&ub.slice(s![.., ..k])),
+            s.slice(s![..k]).to_owned(),
+            vt.slice(s![..k, ..]).to_owned(),
+        ))
+    }
+}
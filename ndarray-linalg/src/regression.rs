@@ -0,0 +1,247 @@
+//! Linear regression by ordinary, weighted and generalized least squares
+//!
+//! The parameters $x$ of the linear model
+//!
+//! $$ b = Ax + \varepsilon, \quad \mathrm{Cov}(\varepsilon) = \sigma^2 \Sigma $$
+//!
+//! are estimated by minimizing $(b - Ax)^\dagger \Sigma^{-1} (b - Ax)$, where
+//!
+//! - ordinary least squares assumes $\Sigma = I$,
+//! - weighted least squares assumes $\Sigma = W^{-1}$ with the diagonal weights $W$, and
+//! - generalized least squares takes a full covariance $\Sigma = LL^\dagger$,
+//!   and the model is whitened by the Cholesky factor as $L^{-1} b = L^{-1} A x + L^{-1} \varepsilon$.
+//!
+//! Along with the solution, the covariance matrix of the estimated parameters
+//! $\hat\sigma^2 (A^\dagger \Sigma^{-1} A)^{-1}$, their standard errors and
+//! the coefficient of determination $R^2$ are computed.
+//!
+//! ```
+//! use ndarray::*;
+//! use ndarray_linalg::*;
+//!
+//! // Fit b = x0 + x1 t
+//! let t = array![0.0, 1.0, 2.0, 3.0, 4.0];
+//! let b: Array1<f64> = array![1.1, 2.9, 5.2, 6.8, 9.1];
+//! let mut a = Array2::ones((5, 2));
+//! a.column_mut(1).assign(&t);
+//! let result = a.ordinary_least_squares(&b).unwrap();
+//! assert_close_l2!(&result.solution, &array![1.0, 2.0], 0.05);
+//! assert!(result.r_squared > 0.99);
+//! assert_eq!(result.standard_errors.len(), 2);
+//! ```
+
+use ndarray::*;
+use num_traits::{Float, One, Zero};
+
+use crate::cholesky::*;
+use crate::error::*;
+use crate::inner::*;
+use crate::pinv::Cutoff;
+use crate::svddc::*;
+use crate::triangular::*;
+use crate::types::*;
+
+/// Result of the linear regression
+#[derive(Debug, Clone)]
+pub struct RegressionResult<A: Scalar> {
+    /// The estimated parameters $x$
+    pub solution: Array1<A>,
+    /// The effective rank of the (whitened) design matrix,
+    /// where the singular values are truncated by [`Cutoff::Auto`]
+    pub rank: usize,
+    /// The (weighted) residual sum of squares $(b - Ax)^\dagger \Sigma^{-1} (b - Ax)$
+    pub residual_sum_of_squares: A::Real,
+    /// The degrees of freedom of the residual, i.e. the number of observations minus `rank`,
+    /// where the observations of zero weights are not counted
+    pub degrees_of_freedom: usize,
+    /// The covariance matrix of the estimated parameters $\hat\sigma^2 (A^\dagger \Sigma^{-1} A)^+$,
+    /// where $\hat\sigma^2$ is the residual sum of squares divided by the degrees of freedom.
+    /// This is NaN if the degrees of freedom is zero.
+    pub covariance: Array2<A>,
+    /// The standard errors of the estimated parameters, i.e. the square root of the diagonal of `covariance`
+    pub standard_errors: Array1<A::Real>,
+    /// The coefficient of determination $R^2 = 1 - \mathrm{RSS} / \mathrm{TSS}$,
+    /// where the total sum of squares is taken around the (weighted) mean of $b$
+    /// assuming that the model includes an intercept.
+    /// This is NaN if the total sum of squares is zero, i.e. $b$ is constant.
+    pub r_squared: A::Real,
+}
+
+/// Linear regression of the observations `b` on the design matrix `A`, where `A` is `self`
+pub trait LeastSquaresRegression<A: Scalar> {
+    /// Ordinary least squares, where the errors are uncorrelated with the same variance
+    fn ordinary_least_squares<Sb>(&self, b: &ArrayBase<Sb, Ix1>) -> Result<RegressionResult<A>>
+    where
+        Sb: Data<Elem = A>;
+
+    /// Weighted least squares, where the variance of the `i`-th error is inversely proportional
+    /// to the positive weight `weights[i]`
+    ///
+    /// An observation of zero weight is excluded from the fit and from the degrees of freedom.
+    /// [LinalgError::InvalidParameter] is returned if a weight is negative or not finite.
+    fn weighted_least_squares<Sb, Sw>(
+        &self,
+        b: &ArrayBase<Sb, Ix1>,
+        weights: &ArrayBase<Sw, Ix1>,
+    ) -> Result<RegressionResult<A>>
+    where
+        Sb: Data<Elem = A>,
+        Sw: Data<Elem = A::Real>;
+
+    /// Generalized least squares, where the errors have the covariance proportional to
+    /// the Hermitian positive definite matrix `covariance`
+    fn generalized_least_squares<Sb, Sc>(
+        &self,
+        b: &ArrayBase<Sb, Ix1>,
+        covariance: &ArrayBase<Sc, Ix2>,
+    ) -> Result<RegressionResult<A>>
+    where
+        Sb: Data<Elem = A>,
+        Sc: Data<Elem = A>;
+}
+
+impl<A, S> LeastSquaresRegression<A> for ArrayBase<S, Ix2>
+where
+    A: Scalar + Lapack,
+    S: Data<Elem = A>,
+{
+    fn ordinary_least_squares<Sb>(&self, b: &ArrayBase<Sb, Ix1>) -> Result<RegressionResult<A>>
+    where
+        Sb: Data<Elem = A>,
+    {
+        check_shape(self, b)?;
+        regression(
+            self.to_owned(),
+            b.to_owned(),
+            Array1::ones(b.len()),
+            b.len(),
+        )
+    }
+
+    fn weighted_least_squares<Sb, Sw>(
+        &self,
+        b: &ArrayBase<Sb, Ix1>,
+        weights: &ArrayBase<Sw, Ix1>,
+    ) -> Result<RegressionResult<A>>
+    where
+        Sb: Data<Elem = A>,
+        Sw: Data<Elem = A::Real>,
+    {
+        check_shape(self, b)?;
+        if weights.len() != b.len() {
+            return Err(ShapeError::from_kind(ErrorKind::IncompatibleShape).into());
+        }
+        if weights
+            .iter()
+            .any(|&w| !Float::is_finite(w) || w < A::Real::zero())
+        {
+            return Err(LinalgError::InvalidParameter { name: "weights" });
+        }
+        let sqrt_w = weights.mapv(|w| A::from_real(Float::sqrt(w)));
+        let mut a = self.to_owned();
+        for (mut row, &w) in a.axis_iter_mut(Axis(0)).zip(&sqrt_w) {
+            row.mapv_inplace(|x| x * w);
+        }
+        // The observations of zero weights drop out of the fit
+        let observations = weights.iter().filter(|&&w| w > A::Real::zero()).count();
+        regression(a, &sqrt_w * b, sqrt_w.clone(), observations)
+    }
+
+    fn generalized_least_squares<Sb, Sc>(
+        &self,
+        b: &ArrayBase<Sb, Ix1>,
+        covariance: &ArrayBase<Sc, Ix2>,
+    ) -> Result<RegressionResult<A>>
+    where
+        Sb: Data<Elem = A>,
+        Sc: Data<Elem = A>,
+    {
+        check_shape(self, b)?;
+        if covariance.dim() != (b.len(), b.len()) {
+            return Err(ShapeError::from_kind(ErrorKind::IncompatibleShape).into());
+        }
+        let l = covariance.cholesky(UPLO::Lower)?;
+        let a = l.solve_triangular(UPLO::Lower, Diag::NonUnit, &self.to_owned())?;
+        let b = l.solve_triangular(UPLO::Lower, Diag::NonUnit, &b.to_owned())?;
+        let ones = l.solve_triangular(UPLO::Lower, Diag::NonUnit, &Array1::ones(b.len()))?;
+        let observations = b.len();
+        regression(a, b, ones, observations)
+    }
+}
+
+fn check_shape<A, Sa, Sb>(a: &ArrayBase<Sa, Ix2>, b: &ArrayBase<Sb, Ix1>) -> Result<()>
+where
+    Sa: Data<Elem = A>,
+    Sb: Data<Elem = A>,
+{
+    if a.nrows() != b.len() {
+        return Err(ShapeError::from_kind(ErrorKind::IncompatibleShape).into());
+    }
+    Ok(())
+}
+
+/// Ordinary least squares of the whitened model,
+/// where `ones` is the whitened intercept used to compute the total sum of squares,
+/// and `observations` is the number of the observations contributing to the fit
+fn regression<A: Scalar + Lapack>(
+    a: Array2<A>,
+    b: Array1<A>,
+    ones: Array1<A>,
+    observations: usize,
+) -> Result<RegressionResult<A>> {
+    let (m, n) = a.dim();
+    let (u, s, vt) = a.clone().svddc_into(UVTFlag::Some)?;
+    let u = u.unwrap();
+    let vt = vt.unwrap();
+
+    let s_max = s.iter().cloned().fold(A::Real::zero(), Float::max);
+    let threshold = Cutoff::Auto.threshold(m, n, s_max);
+    let rank = s.iter().filter(|&&s| s > threshold).count();
+
+    // x = V S^+ U^H b, and the scaled right singular vectors S^{-1} V^H
+    let mut coef: Array1<A> = Array1::zeros(n);
+    let mut scaled_vt = Array2::zeros((rank, n));
+    for i in 0..rank {
+        let s_inv = A::from_real(s[i].recip());
+        let ub = u.column(i).inner(&b);
+        coef.scaled_add(ub * s_inv, &vt.row(i).mapv(|v| v.conj()));
+        scaled_vt.row_mut(i).assign(&vt.row(i).mapv(|v| v * s_inv));
+    }
+    let solution = coef;
+
+    let residual = &b - &a.dot(&solution);
+    let residual_sum_of_squares: A::Real = residual.iter().map(|r| r.square()).sum();
+    let degrees_of_freedom = observations - rank;
+    let sigma2 = if degrees_of_freedom > 0 {
+        residual_sum_of_squares / A::real(degrees_of_freedom)
+    } else {
+        A::Real::nan()
+    };
+
+    let mut covariance = scaled_vt.t().mapv(|x| x.conj()).dot(&scaled_vt);
+    covariance.mapv_inplace(|c| c.mul_real(sigma2));
+    let standard_errors = covariance.diag().mapv(|c| Float::sqrt(c.re()));
+
+    let ones_square: A::Real = ones.iter().map(|x| x.square()).sum();
+    let mean = ones.inner(&b).div_real(ones_square);
+    let total_sum_of_squares: A::Real = b
+        .iter()
+        .zip(&ones)
+        .map(|(&b, &one)| (b - one * mean).square())
+        .sum();
+    let r_squared = if total_sum_of_squares.is_zero() {
+        A::Real::nan()
+    } else {
+        A::Real::one() - residual_sum_of_squares / total_sum_of_squares
+    };
+
+    Ok(RegressionResult {
+        solution,
+        rank,
+        residual_sum_of_squares,
+        degrees_of_freedom,
+        covariance,
+        standard_errors,
+        r_squared,
+    })
+}
@@ -0,0 +1,68 @@
+//! Schur decomposition for general square matrices
+//!
+//! $$ A = Z T Z^\dagger $$
+//!
+//! where $Z$ is unitary and $T$ is upper triangular.
+//! For real matrices, $T$ is quasi-upper triangular (real Schur form)
+//! and has 2x2 blocks on its diagonal corresponding to the complex conjugate pairs of eigenvalues.
+
+use crate::error::*;
+use crate::layout::*;
+use crate::types::*;
+use ndarray::*;
+
+/// Schur decomposition of general matrix reference
+pub trait Schur {
+    type T;
+    type Z;
+    /// Calculate the Schur decomposition $A = Z T Z^\dagger$, and returns $(T, Z)$
+    ///
+    /// ```
+    /// use ndarray::*;
+    /// use ndarray_linalg::*;
+    ///
+    /// let a: Array2<f64> = random((4, 4));
+    /// let (t, z) = a.schur().unwrap();
+    /// assert_close_l2!(&z.dot(&t).dot(&z.t()), &a, 1e-7);
+    /// ```
+    fn schur(&self) -> Result<(Self::T, Self::Z)>;
+}
+
+/// Schur decomposition of general matrix
+pub trait SchurInto: Sized {
+    type Z;
+    /// Calculate the Schur decomposition, and returns $(T, Z)$ where $T$ reuses the memory of $A$
+    fn schur_into(self) -> Result<(Self, Self::Z)>;
+}
+
+impl<A, S> Schur for ArrayBase<S, Ix2>
+where
+    A: Scalar + Lapack,
+    S: Data<Elem = A>,
+{
+    type T = Array2<A>;
+    type Z = Array2<A>;
+
+    fn schur(&self) -> Result<(Self::T, Self::Z)> {
+        self.to_owned().schur_into()
+    }
+}
+
+impl<A, S> SchurInto for ArrayBase<S, Ix2>
+where
+    A: Scalar + Lapack,
+    S: DataMut<Elem = A>,
+{
+    type Z = Array2<A>;
+
+    fn schur_into(mut self) -> Result<(Self, Self::Z)> {
+        let layout = self.square_layout()?;
+        let out = A::schur(layout, self.as_allocated_mut()?)?;
+        let n = layout.len() as usize;
+        let z = match layout {
+            MatrixLayout::C { .. } => Array2::from_shape_vec((n, n), out.z).unwrap(),
+            MatrixLayout::F { .. } => Array2::from_shape_vec((n, n).f(), out.z).unwrap(),
+        };
+        Ok((self, z))
+    }
+}
@@ -50,6 +50,7 @@ use ndarray::*;
 use num_traits::{Float, Zero};
 
 use crate::convert::*;
+use crate::equilibrate::*;
 use crate::error::*;
 use crate::layout::*;
 use crate::opnorm::OperationNorm;
@@ -71,18 +72,21 @@ pub use lax::{Pivot, Transpose};
 /// * `*_into` methods take ownership of `b`, store the result in it, and return it.
 /// * `*_inplace` methods take a mutable reference to `b` and store the result in that array.
 ///
+/// The right-hand side `b` is either a vector (`D = Ix1`) or a matrix
+/// (`D = Ix2`) whose columns are solved at once.
+///
 /// If you plan to solve many equations with the same `A` matrix but different
 /// `b` vectors, it's faster to factor the `A` matrix once using the
 /// `Factorize` trait, and then solve using the `LUFactorized` struct.
-pub trait Solve<A: Scalar> {
+pub trait Solve<A: Scalar, D: Dimension = Ix1> {
     /// Solves a system of linear equations `A * x = b` where `A` is `self`, `b`
     /// is the argument, and `x` is the successful result.
     ///
     /// # Panics
     ///
-    /// Panics if the length of `b` is not the equal to the number of columns
-    /// of `A`.
-    fn solve<S: Data<Elem = A>>(&self, b: &ArrayBase<S, Ix1>) -> Result<Array1<A>> {
+    /// Panics if the length (or the number of rows) of `b` is not equal to
+    /// the number of columns of `A`.
+    fn solve<S: Data<Elem = A>>(&self, b: &ArrayBase<S, D>) -> Result<Array<A, D>> {
         let mut b = replicate(b);
         self.solve_inplace(&mut b)?;
         Ok(b)
@@ -93,12 +97,9 @@ pub trait Solve<A: Scalar> {
     ///
     /// # Panics
     ///
-    /// Panics if the length of `b` is not the equal to the number of columns
-    /// of `A`.
-    fn solve_into<S: DataMut<Elem = A>>(
-        &self,
-        mut b: ArrayBase<S, Ix1>,
-    ) -> Result<ArrayBase<S, Ix1>> {
+    /// Panics if the length (or the number of rows) of `b` is not equal to
+    /// the number of columns of `A`.
+    fn solve_into<S: DataMut<Elem = A>>(&self, mut b: ArrayBase<S, D>) -> Result<ArrayBase<S, D>> {
         self.solve_inplace(&mut b)?;
         Ok(b)
     }
@@ -108,21 +109,21 @@ pub trait Solve<A: Scalar> {
     ///
     /// # Panics
     ///
-    /// Panics if the length of `b` is not the equal to the number of columns
-    /// of `A`.
+    /// Panics if the length (or the number of rows) of `b` is not equal to
+    /// the number of columns of `A`.
     fn solve_inplace<'a, S: DataMut<Elem = A>>(
         &self,
-        b: &'a mut ArrayBase<S, Ix1>,
-    ) -> Result<&'a mut ArrayBase<S, Ix1>>;
+        b: &'a mut ArrayBase<S, D>,
+    ) -> Result<&'a mut ArrayBase<S, D>>;
 
     /// Solves a system of linear equations `A^T * x = b` where `A` is `self`, `b`
     /// is the argument, and `x` is the successful result.
     ///
     /// # Panics
     ///
-    /// Panics if the length of `b` is not the equal to the number of rows of
-    /// `A`.
-    fn solve_t<S: Data<Elem = A>>(&self, b: &ArrayBase<S, Ix1>) -> Result<Array1<A>> {
+    /// Panics if the length (or the number of rows) of `b` is not equal to
+    /// the number of rows of `A`.
+    fn solve_t<S: Data<Elem = A>>(&self, b: &ArrayBase<S, D>) -> Result<Array<A, D>> {
         let mut b = replicate(b);
         self.solve_t_inplace(&mut b)?;
         Ok(b)
@@ -133,12 +134,12 @@ pub trait Solve<A: Scalar> {
     ///
     /// # Panics
     ///
-    /// Panics if the length of `b` is not the equal to the number of rows of
-    /// `A`.
+    /// Panics if the length (or the number of rows) of `b` is not equal to
+    /// the number of rows of `A`.
     fn solve_t_into<S: DataMut<Elem = A>>(
         &self,
-        mut b: ArrayBase<S, Ix1>,
-    ) -> Result<ArrayBase<S, Ix1>> {
+        mut b: ArrayBase<S, D>,
+    ) -> Result<ArrayBase<S, D>> {
         self.solve_t_inplace(&mut b)?;
         Ok(b)
     }
@@ -148,21 +149,21 @@ pub trait Solve<A: Scalar> {
     ///
     /// # Panics
     ///
-    /// Panics if the length of `b` is not the equal to the number of rows of
-    /// `A`.
+    /// Panics if the length (or the number of rows) of `b` is not equal to
+    /// the number of rows of `A`.
     fn solve_t_inplace<'a, S: DataMut<Elem = A>>(
         &self,
-        b: &'a mut ArrayBase<S, Ix1>,
-    ) -> Result<&'a mut ArrayBase<S, Ix1>>;
+        b: &'a mut ArrayBase<S, D>,
+    ) -> Result<&'a mut ArrayBase<S, D>>;
 
     /// Solves a system of linear equations `A^H * x = b` where `A` is `self`, `b`
     /// is the argument, and `x` is the successful result.
     ///
     /// # Panics
     ///
-    /// Panics if the length of `b` is not the equal to the number of rows of
-    /// `A`.
-    fn solve_h<S: Data<Elem = A>>(&self, b: &ArrayBase<S, Ix1>) -> Result<Array1<A>> {
+    /// Panics if the length (or the number of rows) of `b` is not equal to
+    /// the number of rows of `A`.
+    fn solve_h<S: Data<Elem = A>>(&self, b: &ArrayBase<S, D>) -> Result<Array<A, D>> {
         let mut b = replicate(b);
         self.solve_h_inplace(&mut b)?;
         Ok(b)
@@ -172,12 +173,12 @@ pub trait Solve<A: Scalar> {
     ///
     /// # Panics
     ///
-    /// Panics if the length of `b` is not the equal to the number of rows of
-    /// `A`.
+    /// Panics if the length (or the number of rows) of `b` is not equal to
+    /// the number of rows of `A`.
     fn solve_h_into<S: DataMut<Elem = A>>(
         &self,
-        mut b: ArrayBase<S, Ix1>,
-    ) -> Result<ArrayBase<S, Ix1>> {
+        mut b: ArrayBase<S, D>,
+    ) -> Result<ArrayBase<S, D>> {
         self.solve_h_inplace(&mut b)?;
         Ok(b)
     }
@@ -186,16 +187,15 @@ pub trait Solve<A: Scalar> {
     ///
     /// # Panics
     ///
-    /// Panics if the length of `b` is not the equal to the number of rows of
-    /// `A`.
+    /// Panics if the length (or the number of rows) of `b` is not equal to
+    /// the number of rows of `A`.
     fn solve_h_inplace<'a, S: DataMut<Elem = A>>(
         &self,
-        b: &'a mut ArrayBase<S, Ix1>,
-    ) -> Result<&'a mut ArrayBase<S, Ix1>>;
+        b: &'a mut ArrayBase<S, D>,
+    ) -> Result<&'a mut ArrayBase<S, D>>;
 }
 
 /// Represents the LU factorization of a matrix `A` as `A = P*L*U`.
-#[derive(Clone)]
 pub struct LUFactorized<S: Data + RawDataClone> {
     /// The factors `L` and `U`; the unit diagonal elements of `L` are not
     /// stored.
@@ -204,7 +204,88 @@ pub struct LUFactorized<S: Data + RawDataClone> {
     ipiv: Pivot,
 }
 
-impl<A, S> Solve<A> for LUFactorized<S>
+impl<A, S> Clone for LUFactorized<S>
+where
+    A: Clone,
+    S: Data<Elem = A> + RawDataClone,
+{
+    fn clone(&self) -> Self {
+        LUFactorized {
+            a: self.a.clone(),
+            ipiv: self.ipiv.clone(),
+        }
+    }
+}
+
+impl<A, S> LUFactorized<S>
+where
+    A: Scalar + Lapack,
+    S: Data<Elem = A> + RawDataClone,
+{
+    /// Solve `op(A) x = b` for the columns of `rhs` and overwrite it by `x`
+    fn solve_columns<Sb>(&self, t: Transpose, rhs: &mut ArrayBase<Sb, Ix2>) -> Result<()>
+    where
+        Sb: DataMut<Elem = A>,
+    {
+        let l = self.a.square_layout()?;
+        let a = self.a.as_allocated()?;
+        with_fortran_slice(rhs, |b| Ok(A::solve(l, t, a, &self.ipiv, b)?))
+    }
+}
+
+impl<A, S> Solve<A, Ix2> for LUFactorized<S>
+where
+    A: Scalar + Lapack,
+    S: Data<Elem = A> + RawDataClone,
+{
+    fn solve_inplace<'a, Sb>(
+        &self,
+        rhs: &'a mut ArrayBase<Sb, Ix2>,
+    ) -> Result<&'a mut ArrayBase<Sb, Ix2>>
+    where
+        Sb: DataMut<Elem = A>,
+    {
+        assert_eq!(
+            rhs.nrows(),
+            self.a.len_of(Axis(1)),
+            "The number of rows of `rhs` must be compatible with the shape of the factored matrix.",
+        );
+        self.solve_columns(Transpose::No, rhs)?;
+        Ok(rhs)
+    }
+    fn solve_t_inplace<'a, Sb>(
+        &self,
+        rhs: &'a mut ArrayBase<Sb, Ix2>,
+    ) -> Result<&'a mut ArrayBase<Sb, Ix2>>
+    where
+        Sb: DataMut<Elem = A>,
+    {
+        assert_eq!(
+            rhs.nrows(),
+            self.a.len_of(Axis(0)),
+            "The number of rows of `rhs` must be compatible with the shape of the factored matrix.",
+        );
+        self.solve_columns(Transpose::Transpose, rhs)?;
+        Ok(rhs)
+    }
+    fn solve_h_inplace<'a, Sb>(
+        &self,
+        rhs: &'a mut ArrayBase<Sb, Ix2>,
+    ) -> Result<&'a mut ArrayBase<Sb, Ix2>>
+    where
+        Sb: DataMut<Elem = A>,
+    {
+        assert_eq!(
+            rhs.nrows(),
+            self.a.len_of(Axis(0)),
+            "The number of rows of `rhs` must be compatible with the shape of the factored matrix.",
+        );
+        self.solve_columns(Transpose::Hermite, rhs)?;
+        Ok(rhs)
+    }
+}
+
+impl<A, S> Solve<A, Ix1> for LUFactorized<S>
 where
     A: Scalar + Lapack,
     S: Data<Elem = A> + RawDataClone,
@@ -221,13 +302,7 @@ where
             self.a.len_of(Axis(1)),
             "The length of `rhs` must be compatible with the shape of the factored matrix.",
         );
-        A::solve(
-            self.a.square_layout()?,
-            Transpose::No,
-            self.a.as_allocated()?,
-            &self.ipiv,
-            rhs.as_slice_mut().unwrap(),
-        )?;
+        self.solve_inplace(&mut rhs.view_mut().insert_axis(Axis(1)))?;
         Ok(rhs)
     }
     fn solve_t_inplace<'a, Sb>(
@@ -242,13 +317,7 @@ where
             self.a.len_of(Axis(0)),
             "The length of `rhs` must be compatible with the shape of the factored matrix.",
         );
-        A::solve(
-            self.a.square_layout()?,
-            Transpose::Transpose,
-            self.a.as_allocated()?,
-            &self.ipiv,
-            rhs.as_slice_mut().unwrap(),
-        )?;
+        self.solve_t_inplace(&mut rhs.view_mut().insert_axis(Axis(1)))?;
         Ok(rhs)
     }
     fn solve_h_inplace<'a, Sb>(
@@ -263,26 +332,22 @@ where
             self.a.len_of(Axis(0)),
             "The length of `rhs` must be compatible with the shape of the factored matrix.",
         );
-        A::solve(
-            self.a.square_layout()?,
-            Transpose::Hermite,
-            self.a.as_allocated()?,
-            &self.ipiv,
-            rhs.as_slice_mut().unwrap(),
-        )?;
+        self.solve_h_inplace(&mut rhs.view_mut().insert_axis(Axis(1)))?;
         Ok(rhs)
     }
 }
 
-impl<A, S> Solve<A> for ArrayBase<S, Ix2>
+impl<A, S, D> Solve<A, D> for ArrayBase<S, Ix2>
 where
     A: Scalar + Lapack,
     S: Data<Elem = A>,
+    D: Dimension,
+    LUFactorized<OwnedRepr<A>>: Solve<A, D>,
 {
     fn solve_inplace<'a, Sb>(
         &self,
-        rhs: &'a mut ArrayBase<Sb, Ix1>,
-    ) -> Result<&'a mut ArrayBase<Sb, Ix1>>
+        rhs: &'a mut ArrayBase<Sb, D>,
+    ) -> Result<&'a mut ArrayBase<Sb, D>>
     where
         Sb: DataMut<Elem = A>,
     {
@@ -291,8 +356,8 @@ where
     }
     fn solve_t_inplace<'a, Sb>(
         &self,
-        rhs: &'a mut ArrayBase<Sb, Ix1>,
-    ) -> Result<&'a mut ArrayBase<Sb, Ix1>>
+        rhs: &'a mut ArrayBase<Sb, D>,
+    ) -> Result<&'a mut ArrayBase<Sb, D>>
     where
         Sb: DataMut<Elem = A>,
     {
@@ -301,8 +366,8 @@ where
     }
     fn solve_h_inplace<'a, Sb>(
         &self,
-        rhs: &'a mut ArrayBase<Sb, Ix1>,
-    ) -> Result<&'a mut ArrayBase<Sb, Ix1>>
+        rhs: &'a mut ArrayBase<Sb, D>,
+    ) -> Result<&'a mut ArrayBase<Sb, D>>
     where
         Sb: DataMut<Elem = A>,
     {
@@ -316,6 +381,12 @@ pub trait Factorize<S: Data + RawDataClone> {
     /// Computes the LU factorization `A = P*L*U`, where `P` is a permutation
     /// matrix.
     fn factorize(&self) -> Result<LUFactorized<S>>;
+
+    /// Computes the LU factorization `R*A*C = P*L*U` of the equilibrated matrix,
+    /// where `R` and `C` are the diagonal row and column scale factors computed by
+    /// [`Equilibrate::equilibrate`]. The rows and columns are scaled only if it is
+    /// worthwhile. See [`EquilibratedLUFactorized`] for the operations on `A`.
+    fn factorize_equilibrated(&self) -> Result<EquilibratedLUFactorized<S>>;
 }
 
 /// An interface for computing LU factorizations of matrices.
@@ -323,6 +394,10 @@ pub trait FactorizeInto<S: Data + RawDataClone> {
     /// Computes the LU factorization `A = P*L*U`, where `P` is a permutation
     /// matrix.
     fn factorize_into(self) -> Result<LUFactorized<S>>;
+
+    /// Computes the LU factorization `R*A*C = P*L*U` of the equilibrated matrix.
+    /// See [`Factorize::factorize_equilibrated`] for detail.
+    fn factorize_equilibrated_into(self) -> Result<EquilibratedLUFactorized<S>>;
 }
 
 impl<A, S> FactorizeInto<S> for ArrayBase<S, Ix2>
@@ -334,6 +409,19 @@ where
         let ipiv = A::lu(self.layout()?, self.as_allocated_mut()?)?;
         Ok(LUFactorized { a: self, ipiv })
     }
+
+    fn factorize_equilibrated_into(mut self) -> Result<EquilibratedLUFactorized<S>> {
+        let (row_scale, col_scale) = self.equilibrate()?.into_applied();
+        scale_rows(&mut self, &row_scale, |x, r| x * r);
+        scale_rows(&mut self.view_mut().reversed_axes(), &col_scale, |x, c| {
+            x * c
+        });
+        Ok(EquilibratedLUFactorized {
+            lu: self.factorize_into()?,
+            row_scale,
+            col_scale,
+        })
+    }
 }
 
 impl<A, Si> Factorize<OwnedRepr<A>> for ArrayBase<Si, Ix2>
@@ -346,6 +434,107 @@ where
         let ipiv = A::lu(a.layout()?, a.as_allocated_mut()?)?;
         Ok(LUFactorized { a, ipiv })
     }
+
+    fn factorize_equilibrated(&self) -> Result<EquilibratedLUFactorized<OwnedRepr<A>>> {
+        let a: Array2<A> = replicate(self);
+        a.factorize_equilibrated_into()
+    }
+}
+
+/// Represents the LU factorization `R*A*C = P*L*U` of the equilibrated matrix
+/// with the diagonal row and column scale factors `R` and `C`.
+///
+/// The equations are solved, and the inverse and the determinant are computed,
+/// for the original matrix `A`.
+pub struct EquilibratedLUFactorized<S: Data + RawDataClone> {
+    /// The LU factorization of `R*A*C`.
+    lu: LUFactorized<S>,
+    /// The row scale factors `R`, which are ones if the rows are not scaled.
+    row_scale: Array1<S::Elem>,
+    /// The column scale factors `C`, which are ones if the columns are not scaled.
+    col_scale: Array1<S::Elem>,
+}
+
+impl<A, S> Clone for EquilibratedLUFactorized<S>
+where
+    A: Clone,
+    S: Data<Elem = A> + RawDataClone,
+{
+    fn clone(&self) -> Self {
+        EquilibratedLUFactorized {
+            lu: self.lu.clone(),
+            row_scale: self.row_scale.clone(),
+            col_scale: self.col_scale.clone(),
+        }
+    }
+}
+
+impl<A, S> EquilibratedLUFactorized<S>
+where
+    A: Scalar,
+    S: Data<Elem = A> + RawDataClone,
+{
+    /// The LU factorization of the equilibrated matrix `R*A*C`,
+    /// e.g. for the reciprocal condition number of the equilibrated matrix
+    pub fn lu(&self) -> &LUFactorized<S> {
+        &self.lu
+    }
+
+    /// The row scale factors `R`
+    pub fn row_scale(&self) -> &Array1<A> {
+        &self.row_scale
+    }
+
+    /// The column scale factors `C`
+    pub fn col_scale(&self) -> &Array1<A> {
+        &self.col_scale
+    }
+}
+
+impl<A, S, D> Solve<A, D> for EquilibratedLUFactorized<S>
+where
+    A: Scalar + Lapack,
+    S: Data<Elem = A> + RawDataClone,
+    D: RemoveAxis,
+    LUFactorized<S>: Solve<A, D>,
+{
+    fn solve_inplace<'a, Sb>(
+        &self,
+        rhs: &'a mut ArrayBase<Sb, D>,
+    ) -> Result<&'a mut ArrayBase<Sb, D>>
+    where
+        Sb: DataMut<Elem = A>,
+    {
+        // A x = b is (RAC) (C^{-1} x) = R b
+        scale_rows(rhs, &self.row_scale, |x, r| x * r);
+        self.lu.solve_inplace(rhs)?;
+        scale_rows(rhs, &self.col_scale, |x, c| x * c);
+        Ok(rhs)
+    }
+    fn solve_t_inplace<'a, Sb>(
+        &self,
+        rhs: &'a mut ArrayBase<Sb, D>,
+    ) -> Result<&'a mut ArrayBase<Sb, D>>
+    where
+        Sb: DataMut<Elem = A>,
+    {
+        scale_rows(rhs, &self.col_scale, |x, c| x * c);
+        self.lu.solve_t_inplace(rhs)?;
+        scale_rows(rhs, &self.row_scale, |x, r| x * r);
+        Ok(rhs)
+    }
+    fn solve_h_inplace<'a, Sb>(
+        &self,
+        rhs: &'a mut ArrayBase<Sb, D>,
+    ) -> Result<&'a mut ArrayBase<Sb, D>>
+    where
+        Sb: DataMut<Elem = A>,
+    {
+        scale_rows(rhs, &self.col_scale, |x, c| x * c);
+        self.lu.solve_h_inplace(rhs)?;
+        scale_rows(rhs, &self.row_scale, |x, r| x * r);
+        Ok(rhs)
+    }
 }
 
 /// An interface for inverting matrix refs.
@@ -402,6 +591,44 @@ where
     }
 }
 
+/// `A^{-1} = C (RAC)^{-1} R`
+fn unscale_inverse<A, S>(inv: &mut ArrayBase<S, Ix2>, row_scale: &Array1<A>, col_scale: &Array1<A>)
+where
+    A: Scalar,
+    S: DataMut<Elem = A>,
+{
+    scale_rows(inv, col_scale, |x, c| x * c);
+    scale_rows(&mut inv.view_mut().reversed_axes(), row_scale, |x, r| x * r);
+}
+
+impl<A, S> InverseInto for EquilibratedLUFactorized<S>
+where
+    A: Scalar + Lapack,
+    S: DataMut<Elem = A> + RawDataClone,
+{
+    type Output = ArrayBase<S, Ix2>;
+
+    fn inv_into(self) -> Result<ArrayBase<S, Ix2>> {
+        let mut inv = self.lu.inv_into()?;
+        unscale_inverse(&mut inv, &self.row_scale, &self.col_scale);
+        Ok(inv)
+    }
+}
+
+impl<A, S> Inverse for EquilibratedLUFactorized<S>
+where
+    A: Scalar + Lapack,
+    S: Data<Elem = A> + RawDataClone,
+{
+    type Output = Array2<A>;
+
+    fn inv(&self) -> Result<Array2<A>> {
+        let mut inv = self.lu.inv()?;
+        unscale_inverse(&mut inv, &self.row_scale, &self.col_scale);
+        Ok(inv)
+    }
+}
+
 impl<A, S> InverseInto for ArrayBase<S, Ix2>
 where
     A: Scalar + Lapack,
@@ -530,6 +757,34 @@ where
     }
 }
 
+impl<A, S> Determinant<A> for EquilibratedLUFactorized<S>
+where
+    A: Scalar + Lapack,
+    S: Data<Elem = A> + RawDataClone,
+{
+    fn sln_det(&self) -> Result<(A, A::Real)> {
+        // det(A) = det(RAC) / (det(R) det(C)) with the positive scale factors
+        let (sign, ln_det) = self.lu.sln_det()?;
+        let ln_det_scale: A::Real = self
+            .row_scale
+            .iter()
+            .chain(self.col_scale.iter())
+            .map(|s| Float::ln(s.re()))
+            .sum();
+        Ok((sign, ln_det - ln_det_scale))
+    }
+}
+
+impl<A, S> DeterminantInto<A> for EquilibratedLUFactorized<S>
+where
+    A: Scalar + Lapack,
+    S: Data<Elem = A> + RawDataClone,
+{
+    fn sln_det_into(self) -> Result<(A, A::Real)> {
+        self.sln_det()
+    }
+}
+
 impl<A, S> Determinant<A> for ArrayBase<S, Ix2>
 where
     A: Scalar + Lapack,
@@ -539,9 +794,7 @@ where
         self.ensure_square()?;
         match self.factorize() {
             Ok(fac) => fac.sln_det(),
-            Err(LinalgError::Lapack(e))
-                if matches!(e, lax::error::Error::LapackComputationalFailure { .. }) =>
-            {
+            Err(LinalgError::Lapack(lax::error::Error::LapackComputationalFailure { .. })) => {
                 // The determinant is zero.
                 Ok((A::zero(), A::Real::neg_infinity()))
             }
@@ -559,9 +812,7 @@ where
         self.ensure_square()?;
         match self.factorize_into() {
             Ok(fac) => fac.sln_det_into(),
-            Err(LinalgError::Lapack(e))
-                if matches!(e, lax::error::Error::LapackComputationalFailure { .. }) =>
-            {
+            Err(LinalgError::Lapack(lax::error::Error::LapackComputationalFailure { .. })) => {
                 // The determinant is zero.
                 Ok((A::zero(), A::Real::neg_infinity()))
             }
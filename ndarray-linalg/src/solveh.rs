@@ -55,6 +55,7 @@ use num_traits::{Float, One, Zero};
 use crate::convert::*;
 use crate::error::*;
 use crate::layout::*;
+use crate::opnorm::*;
 use crate::types::*;
 
 pub use lax::{Pivot, UPLO};
@@ -65,16 +66,19 @@ pub use lax::{Pivot, UPLO};
 /// symmetric) coefficient matrix `A` but different `b` vectors, it's faster to
 /// factor the `A` matrix once using the `FactorizeH` trait, and then solve
 /// using the `BKFactorized` struct.
-pub trait SolveH<A: Scalar> {
+///
+/// The right-hand side `b` is either a vector (`D = Ix1`) or a matrix
+/// (`D = Ix2`) whose columns are solved at once.
+pub trait SolveH<A: Scalar, D: Dimension = Ix1> {
     /// Solves a system of linear equations `A * x = b` with Hermitian (or real
     /// symmetric) matrix `A`, where `A` is `self`, `b` is the argument, and
     /// `x` is the successful result.
     ///
     /// # Panics
     ///
-    /// Panics if the length of `b` is not the equal to the number of columns
-    /// of `A`.
-    fn solveh<S: Data<Elem = A>>(&self, b: &ArrayBase<S, Ix1>) -> Result<Array1<A>> {
+    /// Panics if the length (or the number of rows) of `b` is not equal to
+    /// the number of columns of `A`.
+    fn solveh<S: Data<Elem = A>>(&self, b: &ArrayBase<S, D>) -> Result<Array<A, D>> {
         let mut b = replicate(b);
         self.solveh_inplace(&mut b)?;
         Ok(b)
@@ -86,12 +90,9 @@ pub trait SolveH<A: Scalar> {
     ///
     /// # Panics
     ///
-    /// Panics if the length of `b` is not the equal to the number of columns
-    /// of `A`.
-    fn solveh_into<S: DataMut<Elem = A>>(
-        &self,
-        mut b: ArrayBase<S, Ix1>,
-    ) -> Result<ArrayBase<S, Ix1>> {
+    /// Panics if the length (or the number of rows) of `b` is not equal to
+    /// the number of columns of `A`.
+    fn solveh_into<S: DataMut<Elem = A>>(&self, mut b: ArrayBase<S, D>) -> Result<ArrayBase<S, D>> {
         self.solveh_inplace(&mut b)?;
         Ok(b)
     }
@@ -103,12 +104,12 @@ pub trait SolveH<A: Scalar> {
     ///
     /// # Panics
     ///
-    /// Panics if the length of `b` is not the equal to the number of columns
-    /// of `A`.
+    /// Panics if the length (or the number of rows) of `b` is not equal to
+    /// the number of columns of `A`.
     fn solveh_inplace<'a, S: DataMut<Elem = A>>(
         &self,
-        b: &'a mut ArrayBase<S, Ix1>,
-    ) -> Result<&'a mut ArrayBase<S, Ix1>>;
+        b: &'a mut ArrayBase<S, D>,
+    ) -> Result<&'a mut ArrayBase<S, D>>;
 }
 
 /// Represents the Bunch–Kaufman factorization of a Hermitian (or real
@@ -118,7 +119,31 @@ pub struct BKFactorized<S: Data> {
     pub ipiv: Pivot,
 }
 
-impl<A, S> SolveH<A> for BKFactorized<S>
+impl<A, S> SolveH<A, Ix2> for BKFactorized<S>
+where
+    A: Scalar + Lapack,
+    S: Data<Elem = A>,
+{
+    fn solveh_inplace<'a, Sb>(
+        &self,
+        rhs: &'a mut ArrayBase<Sb, Ix2>,
+    ) -> Result<&'a mut ArrayBase<Sb, Ix2>>
+    where
+        Sb: DataMut<Elem = A>,
+    {
+        assert_eq!(
+            rhs.nrows(),
+            self.a.len_of(Axis(1)),
+            "The number of rows of `rhs` must be compatible with the shape of the factored matrix.",
+        );
+        let l = self.a.square_layout()?;
+        let a = self.a.as_allocated()?;
+        with_fortran_slice(rhs, |b| Ok(A::solveh(l, UPLO::Upper, a, &self.ipiv, b)?))?;
+        Ok(rhs)
+    }
+}
+
+impl<A, S> SolveH<A, Ix1> for BKFactorized<S>
 where
     A: Scalar + Lapack,
     S: Data<Elem = A>,
@@ -135,26 +160,22 @@ where
             self.a.len_of(Axis(1)),
             "The length of `rhs` must be compatible with the shape of the factored matrix.",
         );
-        A::solveh(
-            self.a.square_layout()?,
-            UPLO::Upper,
-            self.a.as_allocated()?,
-            &self.ipiv,
-            rhs.as_slice_mut().unwrap(),
-        )?;
+        self.solveh_inplace(&mut rhs.view_mut().insert_axis(Axis(1)))?;
         Ok(rhs)
     }
 }
 
-impl<A, S> SolveH<A> for ArrayBase<S, Ix2>
+impl<A, S, D> SolveH<A, D> for ArrayBase<S, Ix2>
 where
     A: Scalar + Lapack,
     S: Data<Elem = A>,
+    D: Dimension,
+    BKFactorized<OwnedRepr<A>>: SolveH<A, D>,
 {
     fn solveh_inplace<'a, Sb>(
         &self,
-        rhs: &'a mut ArrayBase<Sb, Ix1>,
-    ) -> Result<&'a mut ArrayBase<Sb, Ix1>>
+        rhs: &'a mut ArrayBase<Sb, D>,
+    ) -> Result<&'a mut ArrayBase<Sb, D>>
     where
         Sb: DataMut<Elem = A>,
     {
@@ -202,6 +223,77 @@ where
     }
 }
 
+/// An interface for *estimating* the reciprocal condition number of
+/// Hermitian (or real symmetric) matrix refs.
+pub trait ReciprocalConditionNumH<A: Scalar> {
+    /// *Estimates* the reciprocal of the condition number of the Hermitian (or
+    /// real symmetric) matrix in 1-norm.
+    ///
+    /// This method uses the LAPACK `*sycon`/`*hecon` routines on the
+    /// Bunch–Kaufman factorization.
+    ///
+    /// * If `rcond` is near `0.`, the matrix is badly conditioned.
+    /// * If `rcond` is near `1.`, the matrix is well conditioned.
+    fn rcondh(&self) -> Result<A::Real>;
+}
+
+/// An interface for *estimating* the reciprocal condition number of
+/// Hermitian (or real symmetric) matrices.
+pub trait ReciprocalConditionNumHInto<A: Scalar> {
+    /// *Estimates* the reciprocal of the condition number of the Hermitian (or
+    /// real symmetric) matrix in 1-norm.
+    ///
+    /// This method uses the LAPACK `*sycon`/`*hecon` routines on the
+    /// Bunch–Kaufman factorization.
+    ///
+    /// * If `rcond` is near `0.`, the matrix is badly conditioned.
+    /// * If `rcond` is near `1.`, the matrix is well conditioned.
+    fn rcondh_into(self) -> Result<A::Real>;
+}
+
+impl<A, S> BKFactorized<S>
+where
+    A: Scalar + Lapack,
+    S: Data<Elem = A>,
+{
+    /// *Estimates* the reciprocal of the condition number in 1-norm,
+    /// where `anorm` is the 1-norm of the original matrix.
+    ///
+    /// The factorization does not keep the original matrix,
+    /// and `anorm` should be computed before factorizing it.
+    pub fn rcondh_with_norm(&self, anorm: A::Real) -> Result<A::Real> {
+        Ok(A::rcond_bk(
+            self.a.square_layout()?,
+            UPLO::Upper,
+            self.a.as_allocated()?,
+            &self.ipiv,
+            anorm,
+        )?)
+    }
+}
+
+impl<A, S> ReciprocalConditionNumH<A> for ArrayBase<S, Ix2>
+where
+    A: Scalar + Lapack,
+    S: Data<Elem = A>,
+{
+    fn rcondh(&self) -> Result<A::Real> {
+        let anorm = opnorm_one_hermitian(self, UPLO::Upper);
+        self.factorizeh()?.rcondh_with_norm(anorm)
+    }
+}
+
+impl<A, S> ReciprocalConditionNumHInto<A> for ArrayBase<S, Ix2>
+where
+    A: Scalar + Lapack,
+    S: DataMut<Elem = A>,
+{
+    fn rcondh_into(self) -> Result<A::Real> {
+        let anorm = opnorm_one_hermitian(&self, UPLO::Upper);
+        self.factorizeh_into()?.rcondh_with_norm(anorm)
+    }
+}
+
 /// An interface for inverting Hermitian (or real symmetric) matrix refs.
 pub trait InverseH {
     type Output;
@@ -448,9 +540,7 @@ where
     fn sln_deth(&self) -> Result<(A::Real, A::Real)> {
         match self.factorizeh() {
             Ok(fac) => Ok(fac.sln_deth()),
-            Err(LinalgError::Lapack(e))
-                if matches!(e, lax::error::Error::LapackComputationalFailure { .. }) =>
-            {
+            Err(LinalgError::Lapack(lax::error::Error::LapackComputationalFailure { .. })) => {
                 // Determinant is zero.
                 Ok((A::Real::zero(), A::Real::neg_infinity()))
             }
@@ -474,9 +564,7 @@ where
     fn sln_deth_into(self) -> Result<(A::Real, A::Real)> {
         match self.factorizeh_into() {
             Ok(fac) => Ok(fac.sln_deth_into()),
-            Err(LinalgError::Lapack(e))
-                if matches!(e, lax::error::Error::LapackComputationalFailure { .. }) =>
-            {
+            Err(LinalgError::Lapack(lax::error::Error::LapackComputationalFailure { .. })) => {
                 // Determinant is zero.
                 Ok((A::Real::zero(), A::Real::neg_infinity()))
             }
@@ -0,0 +1,199 @@
+//! Solve linear equations with error bounds using the LAPACK expert drivers
+//!
+//! In addition to the solution $X$ of $AX = B$, these drivers return
+//!
+//! - the estimate of the reciprocal condition number of $A$,
+//! - the forward error bound $\\|x - x_\text{true}\\|_\infty / \\|x\\|_\infty$ of each column,
+//! - the componentwise relative backward error of each column,
+//! - the row and column scale factors $R$, $C$ if $A$ is equilibrated into $RAC$ before factorization.
+//!
+//! The solution is improved by the iterative refinement in the working precision.
+//!
+//! ```
+//! use ndarray::*;
+//! use ndarray_linalg::*;
+//!
+//! let a: Array2<f64> = array![[3.0, 1.0], [1.0, 2.0]];
+//! let b: Array1<f64> = array![9.0, 8.0];
+//! let result = a.solve_expert(&b, true).unwrap();
+//! assert_close_l2!(&result.solution, &array![2.0, 3.0], 1e-9);
+//! assert!(*result.forward_error.first().unwrap() < 1e-9);
+//! ```
+
+use ndarray::*;
+
+use crate::error::*;
+use crate::layout::*;
+use crate::types::*;
+use crate::UPLO;
+
+/// Solution of the linear equation with its diagnostics
+///
+/// `I` is the dimension of the right-hand side, `Ix1` for a vector and `Ix2` for a matrix.
+/// The error bounds are the 0-dimensional arrays for `Ix1`, and the vectors
+/// of the bounds for each column for `Ix2`.
+#[derive(Debug, Clone)]
+pub struct SolveExpertResult<A: Scalar, I: Dimension> {
+    /// The solution `x` of `Ax = b`
+    pub solution: Array<A, I>,
+    /// Estimate of the reciprocal condition number of the (equilibrated) matrix in 1-norm
+    pub rcond: A::Real,
+    /// Estimated forward error bound
+    pub forward_error: Array<A::Real, I::Smaller>,
+    /// Componentwise relative backward error
+    pub backward_error: Array<A::Real, I::Smaller>,
+    /// Row scale factors, if the rows of the matrix are equilibrated
+    pub row_scale: Option<Array1<A::Real>>,
+    /// Column scale factors, if the columns of the matrix are equilibrated
+    pub col_scale: Option<Array1<A::Real>>,
+}
+
+/// Solve general linear equation with diagnostics using LU decomposition (`*gesvx`)
+pub trait SolveExpert<A: Scalar, I: Dimension> {
+    /// Solve `Ax = b`, equilibrating the rows and/or columns of `A` if `equilibrate` is true
+    /// and the matrix is poorly scaled.
+    ///
+    /// A matrix which is singular to working precision, i.e. `rcond` is smaller than the machine epsilon,
+    /// is not regarded as an error. Check `rcond` and the error bounds for the reliability of the solution.
+    fn solve_expert<S: Data<Elem = A>>(
+        &self,
+        b: &ArrayBase<S, I>,
+        equilibrate: bool,
+    ) -> Result<SolveExpertResult<A, I>>;
+}
+
+/// Solve positive-definite Hermitian linear equation with diagnostics using Cholesky decomposition (`*posvx`)
+pub trait SolveCExpert<A: Scalar, I: Dimension> {
+    /// Solve `Ax = b` using the upper triangular portion of `A`,
+    /// equilibrating `A` symmetrically if `equilibrate` is true and the matrix is poorly scaled.
+    fn solvec_expert<S: Data<Elem = A>>(
+        &self,
+        b: &ArrayBase<S, I>,
+        equilibrate: bool,
+    ) -> Result<SolveExpertResult<A, I>>;
+}
+
+/// Solve Hermitian linear equation with diagnostics using Bunch-Kaufman diagonal pivoting (`*sysvx`/`*hesvx`)
+pub trait SolveHExpert<A: Scalar, I: Dimension> {
+    /// Solve `Ax = b` using the upper triangular portion of `A`
+    fn solveh_expert<S: Data<Elem = A>>(
+        &self,
+        b: &ArrayBase<S, I>,
+    ) -> Result<SolveExpertResult<A, I>>;
+}
+
+/// Which expert driver is called
+#[derive(Clone, Copy)]
+enum Driver {
+    General { equilibrate: bool },
+    Cholesky { equilibrate: bool },
+    Hermite,
+}
+
+fn solve_expert_nrhs<A, Sa, Sb>(
+    a: &ArrayBase<Sa, Ix2>,
+    b: &ArrayBase<Sb, Ix2>,
+    driver: Driver,
+) -> Result<SolveExpertResult<A, Ix2>>
+where
+    A: Scalar + Lapack,
+    Sa: Data<Elem = A>,
+    Sb: Data<Elem = A>,
+{
+    let a_layout = a.square_layout()?;
+    let a = a.as_allocated()?;
+    let mut x = b.to_owned();
+    let b_layout = x.layout()?;
+    let out = match driver {
+        Driver::General { equilibrate } => {
+            A::solve_expert(a_layout, a, b_layout, x.as_allocated_mut()?, equilibrate)?
+        }
+        Driver::Cholesky { equilibrate } => A::solve_cholesky_expert(
+            a_layout,
+            UPLO::Upper,
+            a,
+            b_layout,
+            x.as_allocated_mut()?,
+            equilibrate,
+        )?,
+        Driver::Hermite => {
+            A::solveh_expert(a_layout, UPLO::Upper, a, b_layout, x.as_allocated_mut()?)?
+        }
+    };
+    Ok(SolveExpertResult {
+        solution: x,
+        rcond: out.rcond,
+        forward_error: Array1::from(out.ferr),
+        backward_error: Array1::from(out.berr),
+        row_scale: out.row_scale.map(Array1::from),
+        col_scale: out.col_scale.map(Array1::from),
+    })
+}
+
+fn solve_expert_vector<A, Sa, Sb>(
+    a: &ArrayBase<Sa, Ix2>,
+    b: &ArrayBase<Sb, Ix1>,
+    driver: Driver,
+) -> Result<SolveExpertResult<A, Ix1>>
+where
+    A: Scalar + Lapack,
+    Sa: Data<Elem = A>,
+    Sb: Data<Elem = A>,
+{
+    let b = b.view().insert_axis(Axis(1));
+    let result = solve_expert_nrhs(a, &b, driver)?;
+    Ok(SolveExpertResult {
+        solution: result.solution.index_axis_move(Axis(1), 0),
+        rcond: result.rcond,
+        forward_error: result.forward_error.index_axis_move(Axis(0), 0),
+        backward_error: result.backward_error.index_axis_move(Axis(0), 0),
+        row_scale: result.row_scale,
+        col_scale: result.col_scale,
+    })
+}
+
+macro_rules! impl_solve_expert {
+    ($trait:ident, $method:ident, ($($arg:ident: $ty:ty),*), $driver:expr) => {
+        impl<A, Sa> $trait<A, Ix1> for ArrayBase<Sa, Ix2>
+        where
+            A: Scalar + Lapack,
+            Sa: Data<Elem = A>,
+        {
+            fn $method<S: Data<Elem = A>>(
+                &self,
+                b: &ArrayBase<S, Ix1>,
+                $($arg: $ty),*
+            ) -> Result<SolveExpertResult<A, Ix1>> {
+                solve_expert_vector(self, b, $driver)
+            }
+        }
+
+        impl<A, Sa> $trait<A, Ix2> for ArrayBase<Sa, Ix2>
+        where
+            A: Scalar + Lapack,
+            Sa: Data<Elem = A>,
+        {
+            fn $method<S: Data<Elem = A>>(
+                &self,
+                b: &ArrayBase<S, Ix2>,
+                $($arg: $ty),*
+            ) -> Result<SolveExpertResult<A, Ix2>> {
+                solve_expert_nrhs(self, b, $driver)
+            }
+        }
+    };
+}
+
+impl_solve_expert!(
+    SolveExpert,
+    solve_expert,
+    (equilibrate: bool),
+    Driver::General { equilibrate }
+);
+impl_solve_expert!(
+    SolveCExpert,
+    solvec_expert,
+    (equilibrate: bool),
+    Driver::Cholesky { equilibrate }
+);
+impl_solve_expert!(SolveHExpert, solveh_expert, (), Driver::Hermite);
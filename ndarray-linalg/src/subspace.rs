@@ -0,0 +1,146 @@
+//! Orthonormal bases of the fundamental subspaces of a matrix
+//!
+//! For a $m \times n$ matrix $A$ of rank $r$,
+//!
+//! - [`Orth`]: column space (range) $\\{ Ax \\}$, $m \times r$
+//! - [`NullSpace`]: null space $\\{ x \mid Ax = 0 \\}$, $n \times (n - r)$
+//! - [`LeftNullSpace`]: left null space $\\{ y \mid y^\dagger A = 0 \\}$, $m \times (m - r)$
+//!
+//! The rank is determined either by the singular values, or by the diagonal elements of $R$
+//! of the QR decomposition with column pivoting, which is faster but less reliable.
+//!
+//! ```
+//! use ndarray::*;
+//! use ndarray_linalg::*;
+//!
+//! let a: Array2<f64> = array![[1.0, 2.0, 3.0], [2.0, 4.0, 6.0]];
+//! let (n, rank) = a.null_space(SubspaceMethod::SVD, Cutoff::Auto).unwrap();
+//! assert_eq!(rank, 1);
+//! assert_eq!(n.dim(), (3, 2));
+//! assert!(a.dot(&n).norm_max() < 1e-9);
+//! ```
+
+use ndarray::*;
+
+use crate::error::*;
+use crate::generate::*;
+use crate::layout::*;
+use crate::pinv::Cutoff;
+use crate::svddc::*;
+use crate::types::*;
+
+/// Rank-revealing decomposition used to compute the bases
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SubspaceMethod {
+    /// Singular value decomposition
+    SVD,
+    /// QR decomposition with column pivoting
+    QR,
+}
+
+/// Orthonormal basis of the column space (range)
+pub trait Orth<A: Scalar> {
+    /// Returns the $m \times r$ orthonormal basis and the rank $r$
+    fn orth(&self, method: SubspaceMethod, cutoff: Cutoff<A::Real>) -> Result<(Array2<A>, usize)>;
+}
+
+/// Orthonormal basis of the (right) null space
+pub trait NullSpace<A: Scalar> {
+    /// Returns the $n \times (n - r)$ orthonormal basis and the rank $r$
+    fn null_space(
+        &self,
+        method: SubspaceMethod,
+        cutoff: Cutoff<A::Real>,
+    ) -> Result<(Array2<A>, usize)>;
+}
+
+/// Orthonormal basis of the left null space, i.e. the orthogonal complement of the column space
+pub trait LeftNullSpace<A: Scalar> {
+    /// Returns the $m \times (m - r)$ orthonormal basis and the rank $r$
+    fn left_null_space(
+        &self,
+        method: SubspaceMethod,
+        cutoff: Cutoff<A::Real>,
+    ) -> Result<(Array2<A>, usize)>;
+}
+
+impl<A, S> Orth<A> for ArrayBase<S, Ix2>
+where
+    A: Scalar + Lapack,
+    S: Data<Elem = A>,
+{
+    fn orth(&self, method: SubspaceMethod, cutoff: Cutoff<A::Real>) -> Result<(Array2<A>, usize)> {
+        let (u, rank) = left_basis(self, method, cutoff)?;
+        Ok((u.slice(s![.., ..rank]).to_owned(), rank))
+    }
+}
+
+impl<A, S> LeftNullSpace<A> for ArrayBase<S, Ix2>
+where
+    A: Scalar + Lapack,
+    S: Data<Elem = A>,
+{
+    fn left_null_space(
+        &self,
+        method: SubspaceMethod,
+        cutoff: Cutoff<A::Real>,
+    ) -> Result<(Array2<A>, usize)> {
+        let (u, rank) = left_basis(self, method, cutoff)?;
+        Ok((u.slice(s![.., rank..]).to_owned(), rank))
+    }
+}
+
+impl<A, S> NullSpace<A> for ArrayBase<S, Ix2>
+where
+    A: Scalar + Lapack,
+    S: Data<Elem = A>,
+{
+    fn null_space(
+        &self,
+        method: SubspaceMethod,
+        cutoff: Cutoff<A::Real>,
+    ) -> Result<(Array2<A>, usize)> {
+        // null space of A is the left null space of A^H
+        let ah: Array2<A> = conjugate(self);
+        ah.left_null_space(method, cutoff)
+    }
+}
+
+/// Unitary $m \times m$ matrix whose first $r$ columns span the column space, and the rank $r$
+fn left_basis<A, S>(
+    a: &ArrayBase<S, Ix2>,
+    method: SubspaceMethod,
+    cutoff: Cutoff<A::Real>,
+) -> Result<(Array2<A>, usize)>
+where
+    A: Scalar + Lapack,
+    S: Data<Elem = A>,
+{
+    let (m, n) = a.dim();
+    if m == 0 || n == 0 {
+        return Ok((Array2::eye(m), 0));
+    }
+    match method {
+        SubspaceMethod::SVD => {
+            let (u, s, _) = a.to_owned().svddc_into(UVTFlag::Full)?;
+            let threshold = cutoff.threshold(m, n, s[0]);
+            let rank = s.iter().take_while(|&&s| s > threshold).count();
+            Ok((u.unwrap(), rank))
+        }
+        SubspaceMethod::QR => {
+            let k = m.min(n);
+            let mut r = Array2::zeros((m, n).f());
+            r.assign(a);
+            let (mut tau, _) = A::householder_pivoted(r.layout()?, r.as_allocated_mut()?)?;
+            let threshold = cutoff.threshold(m, n, r[(0, 0)].abs());
+            let rank = (0..k).take_while(|&i| r[(i, i)].abs() > threshold).count();
+
+            // Full Q is reconstructed by the reflectors extended with identities (tau = 0)
+            let mut q = Array2::zeros((m, m).f());
+            q.slice_mut(s![.., ..k]).assign(&r.slice(s![.., ..k]));
+            tau.resize(m, A::zero());
+            A::q(q.layout()?, q.as_allocated_mut()?, &tau)?;
+            Ok((q, rank))
+        }
+    }
+}
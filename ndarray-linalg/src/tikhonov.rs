@@ -0,0 +1,470 @@
+//! Tikhonov-regularized least squares
+//!
+//! The regularized least squares problem
+//!
+//! $$ \min_x \\| Ax - b \\|_2^2 + \lambda^2 \\| Lx \\|_2^2 $$
+//!
+//! is solved through the singular value decomposition $A = U \Sigma V^\dagger$ as in
+//! [least_squares](crate::least_squares), where the regularization damps the
+//! singular components by the filter factors $f_i = \sigma_i^2 / (\sigma_i^2 + \lambda^2)$.
+//! The decomposition is computed once in [TikhonovFactorized],
+//! and then reused for any number of $\lambda$ and right-hand sides.
+//!
+//! The regularization parameter can be chosen automatically by
+//!
+//! - the generalized cross-validation, which minimizes
+//!   $G(\lambda) = \\| Ax_\lambda - b \\|^2 / (m - \sum_i f_i)^2$, or
+//! - the L-curve criterion, which takes the corner of the curve
+//!   $(\log \\| Ax_\lambda - b \\|, \log \\| Lx_\lambda \\|)$ of maximum curvature.
+//!
+//! The general-form regularization with an operator $L$ is reduced to the standard form $L = I$
+//! by the transformation of Eldén using the $A$-weighted pseudo-inverse of $L$.
+//! This requires that the null spaces of $A$ and $L$ intersect trivially.
+//!
+//! ```
+//! use ndarray::*;
+//! use ndarray_linalg::*;
+//!
+//! let a: Array2<f64> = random((10, 4));
+//! let b: Array1<f64> = random(10);
+//! let tikhonov = a.factorize_tikhonov().unwrap();
+//!
+//! // Ridge regression (A^T A + lambda^2 I) x = A^T b
+//! let result = tikhonov.solve(&b, 0.5).unwrap();
+//! let normal = a.t().dot(&a) + Array2::<f64>::eye(4) * 0.25;
+//! let expected = normal.solve(&a.t().dot(&b)).unwrap();
+//! assert_close_l2!(&result.solution, &expected, 1e-9);
+//!
+//! // Choose lambda by the generalized cross-validation
+//! let result = tikhonov
+//!     .solve_auto(&b, TikhonovParameterChoice::GeneralizedCrossValidation)
+//!     .unwrap();
+//! assert!(result.lambda > 0.0);
+//! ```
+
+use ndarray::*;
+use num_traits::{Float, One, Zero};
+
+use crate::error::*;
+use crate::generate::*;
+use crate::norm::*;
+use crate::pinv::Cutoff;
+use crate::qr::*;
+use crate::svddc::*;
+use crate::triangular::*;
+use crate::types::*;
+
+/// Number of grid points used to bracket the automatic choice of the regularization parameter
+const PARAMETER_GRID: usize = 200;
+
+/// Method to choose the regularization parameter automatically
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TikhonovParameterChoice {
+    /// Minimize the generalized cross-validation function
+    GeneralizedCrossValidation,
+    /// Maximize the curvature of the L-curve
+    LCurve,
+}
+
+/// Solution of the Tikhonov-regularized least squares problem
+#[derive(Debug, Clone)]
+pub struct TikhonovResult<A: Scalar> {
+    /// The regularized solution $x_\lambda$
+    pub solution: Array1<A>,
+    /// The regularization parameter $\lambda$
+    pub lambda: A::Real,
+    /// The residual norm $\\| Ax_\lambda - b \\|_2$
+    pub residual_norm: A::Real,
+    /// The (semi-)norm of the solution $\\| Lx_\lambda \\|_2$
+    pub solution_norm: A::Real,
+}
+
+/// SVD of the (standard-form) coefficient matrix reused for many regularization parameters
+#[derive(Debug, Clone)]
+pub struct TikhonovFactorized<A: Scalar> {
+    u: Array2<A>,
+    s: Array1<A::Real>,
+    vt: Array2<A>,
+    /// Number of rows of the standard-form problem
+    rows: usize,
+    general: Option<GeneralForm<A>>,
+}
+
+/// Transformation from the general form into the standard form
+#[derive(Debug, Clone)]
+struct GeneralForm<A> {
+    /// The $A$-weighted pseudo-inverse of $L$
+    l_pinv: Array2<A>,
+    /// Orthonormal basis of $A$ applied to the null space of $L$
+    h: Array2<A>,
+    /// Map from $b$ to the component of the solution in the null space of $L$
+    null_map: Array2<A>,
+}
+
+/// Projection of a right-hand side onto the left singular vectors
+struct Projected<A: Scalar> {
+    /// $U^\dagger \bar{b}$
+    beta: Array1<A>,
+    /// The squared norm of $\bar{b}$ outside the range of $U$
+    perp: A::Real,
+    /// The component of the solution in the null space of $L$
+    null: Option<Array1<A>>,
+}
+
+/// Factorize the matrix `A`, where `A` is `self`, for the Tikhonov-regularized least squares
+pub trait FactorizeTikhonov<A: Scalar> {
+    /// Prepare the standard-form regularization $L = I$, i.e. the ridge regression
+    fn factorize_tikhonov(&self) -> Result<TikhonovFactorized<A>>;
+
+    /// Prepare the general-form regularization with the `p x n` operator $L$
+    fn factorize_tikhonov_general<Sl>(
+        &self,
+        l: &ArrayBase<Sl, Ix2>,
+    ) -> Result<TikhonovFactorized<A>>
+    where
+        Sl: Data<Elem = A>;
+}
+
+impl<A, S> FactorizeTikhonov<A> for ArrayBase<S, Ix2>
+where
+    A: Scalar + Lapack,
+    S: Data<Elem = A>,
+{
+    fn factorize_tikhonov(&self) -> Result<TikhonovFactorized<A>> {
+        let (u, s, vt) = self.to_owned().svddc_into(UVTFlag::Some)?;
+        Ok(TikhonovFactorized {
+            u: u.unwrap(),
+            s,
+            vt: vt.unwrap(),
+            rows: self.nrows(),
+            general: None,
+        })
+    }
+
+    fn factorize_tikhonov_general<Sl>(
+        &self,
+        l: &ArrayBase<Sl, Ix2>,
+    ) -> Result<TikhonovFactorized<A>>
+    where
+        Sl: Data<Elem = A>,
+    {
+        let (m, n) = self.dim();
+        let p = l.nrows();
+        if l.ncols() != n {
+            return Err(ShapeError::from_kind(ErrorKind::IncompatibleShape).into());
+        }
+
+        // L^+ = V_r S_r^{-1} U_r^H, and the null space of L spanned by the rest of V
+        let (ul, sl, vlt) = l.to_owned().svddc_into(UVTFlag::Full)?;
+        let ul = ul.unwrap();
+        let vlt = vlt.unwrap();
+        let s_max = sl.iter().cloned().fold(A::Real::zero(), Float::max);
+        let threshold = Cutoff::Auto.threshold(p, n, s_max);
+        let rank = sl.iter().filter(|&&s| s > threshold).count();
+        let mut vr: Array2<A> = conjugate(&vlt.slice(s![..rank, ..]));
+        for (mut col, &s) in vr.axis_iter_mut(Axis(1)).zip(&sl) {
+            col.mapv_inplace(|v| v.div_real(s));
+        }
+        let ur_h: Array2<A> = conjugate(&ul.slice(s![.., ..rank]));
+        let l_pinv = vr.dot(&ur_h);
+        let a_l_pinv = self.dot(&l_pinv);
+
+        if rank == n {
+            let (u, s, vt) = a_l_pinv.svddc_into(UVTFlag::Some)?;
+            return Ok(TikhonovFactorized {
+                u: u.unwrap(),
+                s,
+                vt: vt.unwrap(),
+                rows: m,
+                general: Some(GeneralForm {
+                    l_pinv,
+                    h: Array2::zeros((m, 0)),
+                    null_map: Array2::zeros((n, m)),
+                }),
+            });
+        }
+        if m < n - rank {
+            return Err(ShapeError::from_kind(ErrorKind::IncompatibleShape).into());
+        }
+
+        // A K = H T, where K spans the null space of L
+        let k: Array2<A> = conjugate(&vlt.slice(s![rank.., ..]));
+        let (h, t) = self.dot(&k).qr_into()?;
+        let hh: Array2<A> = conjugate(&h);
+        let t_inv_ht = t.solve_triangular(UPLO::Upper, Diag::NonUnit, &hh)?;
+        let null_map = k.dot(&t_inv_ht);
+
+        // L_A^+ = (I - K T^{-1} H^H A) L^+, and the projected matrix (I - H H^H) A L^+
+        let l_pinv = &l_pinv - &null_map.dot(&a_l_pinv);
+        let a_bar = &a_l_pinv - &h.dot(&hh.dot(&a_l_pinv));
+        let (u, s, vt) = a_bar.svddc_into(UVTFlag::Some)?;
+        Ok(TikhonovFactorized {
+            u: u.unwrap(),
+            s,
+            vt: vt.unwrap(),
+            rows: m - (n - rank),
+            general: Some(GeneralForm {
+                l_pinv,
+                h,
+                null_map,
+            }),
+        })
+    }
+}
+
+impl<A> TikhonovFactorized<A>
+where
+    A: Scalar + Lapack,
+{
+    /// Singular values of the (standard-form) coefficient matrix in descending order
+    pub fn singular_values(&self) -> &Array1<A::Real> {
+        &self.s
+    }
+
+    /// Solve the regularized problem for the given $\lambda \ge 0$
+    pub fn solve<Sb>(&self, b: &ArrayBase<Sb, Ix1>, lambda: A::Real) -> Result<TikhonovResult<A>>
+    where
+        Sb: Data<Elem = A>,
+    {
+        let projected = self.project(b)?;
+        Ok(self.solve_projected(&projected, lambda))
+    }
+
+    /// Solve the regularized problem for each $\lambda$ on the path
+    pub fn solve_path<Sb>(
+        &self,
+        b: &ArrayBase<Sb, Ix1>,
+        lambdas: &[A::Real],
+    ) -> Result<Vec<TikhonovResult<A>>>
+    where
+        Sb: Data<Elem = A>,
+    {
+        let projected = self.project(b)?;
+        Ok(lambdas
+            .iter()
+            .map(|&lambda| self.solve_projected(&projected, lambda))
+            .collect())
+    }
+
+    /// Generalized cross-validation function $G(\lambda)$
+    pub fn gcv<Sb>(&self, b: &ArrayBase<Sb, Ix1>, lambda: A::Real) -> Result<A::Real>
+    where
+        Sb: Data<Elem = A>,
+    {
+        let projected = self.project(b)?;
+        Ok(self.gcv_projected(&projected, lambda))
+    }
+
+    /// Curvature of the L-curve at $\lambda$, which is maximum at the corner
+    pub fn l_curve_curvature<Sb>(&self, b: &ArrayBase<Sb, Ix1>, lambda: A::Real) -> Result<A::Real>
+    where
+        Sb: Data<Elem = A>,
+    {
+        let projected = self.project(b)?;
+        Ok(self.curvature_projected(&projected, lambda))
+    }
+
+    /// Solve the regularized problem with $\lambda$ chosen by `choice`
+    ///
+    /// The parameter is bracketed on a logarithmic grid between the smallest and largest
+    /// singular values, and then refined by the golden section search.
+    pub fn solve_auto<Sb>(
+        &self,
+        b: &ArrayBase<Sb, Ix1>,
+        choice: TikhonovParameterChoice,
+    ) -> Result<TikhonovResult<A>>
+    where
+        Sb: Data<Elem = A>,
+    {
+        let projected = self.project(b)?;
+        let objective = |lambda: A::Real| match choice {
+            TikhonovParameterChoice::GeneralizedCrossValidation => {
+                self.gcv_projected(&projected, lambda)
+            }
+            TikhonovParameterChoice::LCurve => -self.curvature_projected(&projected, lambda),
+        };
+        let lambda = self.minimize(objective);
+        Ok(self.solve_projected(&projected, lambda))
+    }
+
+    fn project<Sb>(&self, b: &ArrayBase<Sb, Ix1>) -> Result<Projected<A>>
+    where
+        Sb: Data<Elem = A>,
+    {
+        let m = self.u.nrows();
+        if b.len() != m {
+            return Err(ShapeError::from_kind(ErrorKind::IncompatibleShape).into());
+        }
+        let (b, null) = match &self.general {
+            Some(general) => {
+                let hh: Array2<A> = conjugate(&general.h);
+                let hb = hh.dot(b);
+                (b - &general.h.dot(&hb), Some(general.null_map.dot(b)))
+            }
+            None => (b.to_owned(), None),
+        };
+        let uh: Array2<A> = conjugate(&self.u);
+        let beta = uh.dot(&b);
+        let perp = (&b - &self.u.dot(&beta)).iter().map(|x| x.square()).sum();
+        Ok(Projected { beta, perp, null })
+    }
+
+    /// Filter factors $f_i = \sigma_i^2 / (\sigma_i^2 + \lambda^2)$
+    fn filter(&self, lambda: A::Real) -> Array1<A::Real> {
+        self.s.mapv(|s| {
+            if s.is_zero() {
+                s
+            } else {
+                s * s / (s * s + lambda * lambda)
+            }
+        })
+    }
+
+    fn residual_norm(&self, projected: &Projected<A>, f: &Array1<A::Real>) -> A::Real {
+        let rho: A::Real = projected
+            .beta
+            .iter()
+            .zip(f)
+            .map(|(&beta, &f)| (beta.mul_real(A::Real::one() - f)).square())
+            .sum();
+        Float::sqrt(rho + projected.perp)
+    }
+
+    fn solve_projected(&self, projected: &Projected<A>, lambda: A::Real) -> TikhonovResult<A> {
+        let f = self.filter(lambda);
+        let mut y: Array1<A> = Array1::zeros(self.vt.ncols());
+        for (i, (&s, &f)) in self.s.iter().zip(&f).enumerate() {
+            if f.is_zero() {
+                continue;
+            }
+            let coef = projected.beta[i].mul_real(f / s);
+            y.scaled_add(coef, &self.vt.row(i).mapv(|v| v.conj()));
+        }
+        let solution_norm = y.norm_l2();
+        let solution = match (&self.general, &projected.null) {
+            (Some(general), Some(null)) => general.l_pinv.dot(&y) + null,
+            _ => y,
+        };
+        TikhonovResult {
+            solution,
+            lambda,
+            residual_norm: self.residual_norm(projected, &f),
+            solution_norm,
+        }
+    }
+
+    fn gcv_projected(&self, projected: &Projected<A>, lambda: A::Real) -> A::Real {
+        let f = self.filter(lambda);
+        let rho = self.residual_norm(projected, &f);
+        let dof = A::real(self.rows) - f.sum();
+        rho * rho / (dof * dof)
+    }
+
+    /// Curvature of the L-curve in the log-log scale, following `lcfun` of Regularization Tools by Hansen
+    fn curvature_projected(&self, projected: &Projected<A>, lambda: A::Real) -> A::Real {
+        let one = A::Real::one();
+        let two = A::real(2.0);
+        let mut eta = A::Real::zero();
+        let mut rho = A::Real::zero();
+        let mut phi = A::Real::zero();
+        let mut psi = A::Real::zero();
+        let mut dphi = A::Real::zero();
+        let mut dpsi = A::Real::zero();
+        for (&s, beta) in self.s.iter().zip(&projected.beta) {
+            if s.is_zero() {
+                rho += beta.square();
+                continue;
+            }
+            let beta2 = beta.square();
+            let xi2 = beta2 / (s * s);
+            let f = s * s / (s * s + lambda * lambda);
+            let cf = one - f;
+            let f1 = -two * f * cf / lambda;
+            let f2 = -f1 * (A::real(3.0) - A::real(4.0) * f) / lambda;
+            eta += f * f * xi2;
+            rho += cf * cf * beta2;
+            phi += f * f1 * xi2;
+            psi += cf * f1 * beta2;
+            dphi += (f1 * f1 + f * f2) * xi2;
+            dpsi += (-f1 * f1 + cf * f2) * beta2;
+        }
+        let eta = Float::sqrt(eta);
+        let rho = Float::sqrt(rho + projected.perp);
+
+        let deta = phi / eta;
+        let drho = -psi / rho;
+        let ddeta = dphi / eta - deta * (deta / eta);
+        let ddrho = -dpsi / rho - drho * (drho / rho);
+
+        let dlogeta = deta / eta;
+        let dlogrho = drho / rho;
+        let ddlogeta = ddeta / eta - dlogeta * dlogeta;
+        let ddlogrho = ddrho / rho - dlogrho * dlogrho;
+        let denom = dlogrho * dlogrho + dlogeta * dlogeta;
+        -(dlogrho * ddlogeta - ddlogrho * dlogeta) / (denom * Float::sqrt(denom))
+    }
+
+    /// Minimize `objective` over $\lambda$ in the range of the singular values
+    fn minimize<F>(&self, objective: F) -> A::Real
+    where
+        F: Fn(A::Real) -> A::Real,
+    {
+        let s_max = self.s.iter().cloned().fold(A::Real::zero(), Float::max);
+        if s_max.is_zero() {
+            return s_max;
+        }
+        let s_min = self
+            .s
+            .iter()
+            .cloned()
+            .filter(|s| !s.is_zero())
+            .fold(s_max, Float::min);
+        let lo = Float::max(s_min, s_max * A::real(16.0) * <A::Real as Float>::epsilon());
+
+        // logarithmic grid from s_max down to lo
+        let ratio = Float::powf(lo / s_max, A::real(1.0 / (PARAMETER_GRID - 1) as f64));
+        let grid: Vec<A::Real> = (0..PARAMETER_GRID)
+            .scan(s_max, |lambda, _| {
+                let current = *lambda;
+                *lambda *= ratio;
+                Some(current)
+            })
+            .collect();
+        let values: Vec<A::Real> = grid.iter().map(|&lambda| objective(lambda)).collect();
+        let best = values
+            .iter()
+            .enumerate()
+            .filter(|(_, v)| !v.is_nan())
+            .fold(0, |best, (i, &v)| if v < values[best] { i } else { best });
+
+        // golden section search in the log scale between the neighbours of the best grid point
+        let mut a = Float::ln(grid[(best + 1).min(PARAMETER_GRID - 1)]);
+        let mut b = Float::ln(grid[best.saturating_sub(1)]);
+        let g = (Float::sqrt(A::real(5.0)) - A::real(1.0)) / A::real(2.0);
+        let mut c = b - g * (b - a);
+        let mut d = a + g * (b - a);
+        let mut fc = objective(Float::exp(c));
+        let mut fd = objective(Float::exp(d));
+        for _ in 0..50 {
+            if fc < fd {
+                b = d;
+                d = c;
+                fd = fc;
+                c = b - g * (b - a);
+                fc = objective(Float::exp(c));
+            } else {
+                a = c;
+                c = d;
+                fc = fd;
+                d = a + g * (b - a);
+                fd = objective(Float::exp(d));
+            }
+        }
+        let lambda = Float::exp((a + b) / A::real(2.0));
+        if objective(lambda) <= values[best] {
+            lambda
+        } else {
+            grid[best]
+        }
+    }
+}
@@ -0,0 +1,161 @@
+//! Total least squares
+//!
+//! When both the matrix `A` and the right-hand side `b` contain errors,
+//! the total least squares (TLS) problem
+//!
+//! $$ \min_{\Delta A, \Delta b} \\| [\Delta A \\; \Delta b] \\|_F \quad \text{subject to} \quad (A + \Delta A) x = b + \Delta b $$
+//!
+//! is solved instead of the ordinary least squares, where only `b` is corrected.
+//! Let the SVD of the augmented matrix be $[A \\; b] = U \Sigma V^\dagger$, and partition
+//!
+//! $$ V = \begin{pmatrix} V_{11} & V_{12} \\\\ V_{21} & V_{22} \end{pmatrix} $$
+//!
+//! where $V_{12}$ and $V_{22}$ are the blocks of the last columns starting from the truncation rank $k$,
+//! and $V_{22}$ has as many rows as the right-hand sides.
+//! The solution is $x = -V_{12} V_{22}^+$, which is the generic TLS solution for $k = n$
+//! and the minimum norm truncated TLS solution for $k < n$.
+//! It exists only if $V_{22}$ has full row rank, and [LinalgError::TotalLeastSquaresNotExist]
+//! is returned otherwise.
+//!
+//! ```
+//! use ndarray::*;
+//! use ndarray_linalg::*;
+//!
+//! // Fit a line through the origin, where both coordinates are noisy
+//! let t: Array2<f64> = array![[1.0], [2.0], [3.0], [4.0]];
+//! let y = array![2.1, 3.9, 6.2, 7.8];
+//! let result = t.total_least_squares(&y).unwrap();
+//! assert_close_l2!(&result.solution, &array![1.99], 1e-2);
+//! ```
+
+use ndarray::*;
+use num_traits::{Float, Zero};
+
+use crate::error::*;
+use crate::pinv::*;
+use crate::svd::*;
+use crate::types::*;
+
+/// Result of the total least squares
+#[derive(Debug, Clone)]
+pub struct TotalLeastSquaresResult<E: Scalar, I: Dimension> {
+    /// The singular values of the augmented matrix `[A b]`
+    pub singular_values: Array1<E::Real>,
+    /// The solution vector or matrix `x`
+    pub solution: Array<E, I>,
+    /// The truncation rank $k$ of the augmented matrix
+    pub rank: usize,
+    /// The Frobenius norm of the correction $\\| [\Delta A \\; \Delta b] \\|_F$,
+    /// i.e. the norm of the truncated singular values
+    pub correction_norm: E::Real,
+}
+
+/// Solve total least squares for immutable references
+pub trait TotalLeastSquares<D, E, I>
+where
+    D: Data<Elem = E>,
+    E: Scalar + Lapack,
+    I: Dimension,
+{
+    /// Solve the total least squares problem of the form `Ax = rhs`,
+    /// where `A` has `n` columns and the rank is truncated to `n`
+    fn total_least_squares(&self, rhs: &ArrayBase<D, I>) -> Result<TotalLeastSquaresResult<E, I>>;
+
+    /// Solve the truncated total least squares problem for rank-deficient `A`,
+    /// where the augmented matrix `[A rhs]` is approximated by the rank `rank <= n`
+    fn total_least_squares_truncated(
+        &self,
+        rhs: &ArrayBase<D, I>,
+        rank: usize,
+    ) -> Result<TotalLeastSquaresResult<E, I>>;
+}
+
+impl<E, D1, D2> TotalLeastSquares<D2, E, Ix1> for ArrayBase<D1, Ix2>
+where
+    E: Scalar + Lapack,
+    D1: Data<Elem = E>,
+    D2: Data<Elem = E>,
+{
+    fn total_least_squares(
+        &self,
+        rhs: &ArrayBase<D2, Ix1>,
+    ) -> Result<TotalLeastSquaresResult<E, Ix1>> {
+        self.total_least_squares_truncated(rhs, self.ncols())
+    }
+
+    fn total_least_squares_truncated(
+        &self,
+        rhs: &ArrayBase<D2, Ix1>,
+        rank: usize,
+    ) -> Result<TotalLeastSquaresResult<E, Ix1>> {
+        let rhs = rhs.view().insert_axis(Axis(1));
+        let result = total_least_squares(self.view(), rhs, rank)?;
+        Ok(TotalLeastSquaresResult {
+            singular_values: result.singular_values,
+            solution: result.solution.index_axis_move(Axis(1), 0),
+            rank: result.rank,
+            correction_norm: result.correction_norm,
+        })
+    }
+}
+
+impl<E, D1, D2> TotalLeastSquares<D2, E, Ix2> for ArrayBase<D1, Ix2>
+where
+    E: Scalar + Lapack,
+    D1: Data<Elem = E>,
+    D2: Data<Elem = E>,
+{
+    fn total_least_squares(
+        &self,
+        rhs: &ArrayBase<D2, Ix2>,
+    ) -> Result<TotalLeastSquaresResult<E, Ix2>> {
+        self.total_least_squares_truncated(rhs, self.ncols())
+    }
+
+    fn total_least_squares_truncated(
+        &self,
+        rhs: &ArrayBase<D2, Ix2>,
+        rank: usize,
+    ) -> Result<TotalLeastSquaresResult<E, Ix2>> {
+        total_least_squares(self.view(), rhs.view(), rank)
+    }
+}
+
+fn total_least_squares<E: Scalar + Lapack>(
+    a: ArrayView2<E>,
+    b: ArrayView2<E>,
+    rank: usize,
+) -> Result<TotalLeastSquaresResult<E, Ix2>> {
+    let (m, n) = a.dim();
+    let d = b.ncols();
+    if b.nrows() != m || rank > n {
+        return Err(ShapeError::from_kind(ErrorKind::IncompatibleShape).into());
+    }
+    let c = concatenate![Axis(1), a, b];
+    let (_, singular_values, vt) = c.svd(false, true)?;
+    let vt = vt.unwrap();
+
+    // V_12 and V_22 are the conjugate transpose of the trailing rows of V^H
+    let v12 = vt.slice(s![rank.., ..n]).t().mapv(|x| x.conj());
+    let v22 = vt.slice(s![rank.., n..]).t().mapv(|x| x.conj());
+    let threshold = E::real(n + d) * <E::Real as Float>::epsilon();
+    let (v22_pinv, v22_rank) = v22.pinv(Cutoff::Absolute(threshold))?;
+    if v22_rank < d {
+        return Err(LinalgError::TotalLeastSquaresNotExist);
+    }
+    let mut solution = v12.dot(&v22_pinv);
+    solution.mapv_inplace(|x| -x);
+
+    let correction_norm = Float::sqrt(
+        singular_values
+            .iter()
+            .skip(rank)
+            .fold(E::Real::zero(), |acc, &s| acc + s * s),
+    );
+    Ok(TotalLeastSquaresResult {
+        singular_values,
+        solution,
+        rank,
+        correction_norm,
+    })
+}
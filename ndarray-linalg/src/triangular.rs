@@ -142,6 +142,33 @@ where
     }
 }
 
+/// *Estimates* the reciprocal condition number of triangular matrix
+pub trait ReciprocalConditionNumTriangular<A: Scalar> {
+    /// *Estimates* the reciprocal of the condition number of the triangular
+    /// matrix in 1-norm using the LAPACK `*trcon` routines.
+    ///
+    /// Only the portion of the matrix specified by `uplo` is used.
+    ///
+    /// * If `rcond` is near `0.`, the matrix is badly conditioned.
+    /// * If `rcond` is near `1.`, the matrix is well conditioned.
+    fn rcond_triangular(&self, uplo: UPLO, diag: Diag) -> Result<A::Real>;
+}
+
+impl<A, S> ReciprocalConditionNumTriangular<A> for ArrayBase<S, Ix2>
+where
+    A: Scalar + Lapack,
+    S: Data<Elem = A>,
+{
+    fn rcond_triangular(&self, uplo: UPLO, diag: Diag) -> Result<A::Real> {
+        Ok(A::rcond_triangular(
+            self.square_layout()?,
+            uplo,
+            diag,
+            self.as_allocated()?,
+        )?)
+    }
+}
+
 pub trait IntoTriangular<T> {
     fn into_triangular(self, uplo: UPLO) -> T;
 }
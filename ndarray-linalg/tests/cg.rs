@@ -0,0 +1,81 @@
+use ndarray::*;
+use ndarray_linalg::{krylov::*, *};
+
+macro_rules! cg {
+    ($elem:ty) => {
+        paste::item! {
+            #[test]
+            fn [<cg_ $elem>]() {
+                let a: Array2<$elem> = random_hpd(30);
+                let b: Array1<$elem> = random(30);
+                let result = ConjugateGradient::new().tolerance(1e-10).solve(&a, &b).unwrap();
+                assert!(result.is_converged());
+                assert_eq!(result.residual_history.len(), result.iterations + 1);
+                assert_close_l2!(&a.dot(&result.solution), &b, 1e-9);
+            }
+
+            #[test]
+            fn [<pcg_ $elem>]() {
+                // Badly scaled positive definite matrix D A D, which the Jacobi preconditioner fixes
+                let n = 30;
+                let d = Array1::from_shape_fn(n, |i| <$elem>::from_real(10.0_f64.powi((i % 3) as i32)));
+                let a: Array2<$elem> = random_hpd(n);
+                let a = &d.view().insert_axis(Axis(1)) * &a * &d.view().insert_axis(Axis(0));
+                let b: Array1<$elem> = random(n);
+                let jacobi = a.diag().mapv(|x| <$elem>::from_real(1.0) / x).into_diagonal();
+
+                let cg = ConjugateGradient::new().tolerance(1e-10);
+                let pcg = cg.solve_preconditioned(&a, &jacobi, &b).unwrap();
+                assert!(pcg.is_converged());
+                assert_close_l2!(&a.dot(&pcg.solution), &b, 1e-8);
+                let plain = cg.solve(&a, &b).unwrap();
+                assert!(pcg.iterations < plain.iterations);
+            }
+
+            #[test]
+            fn [<cg_absolute_tolerance_ $elem>]() {
+                let a: Array2<$elem> = random_hpd(20);
+                let b: Array1<$elem> = random(20);
+                let result = ConjugateGradient::new()
+                    .tolerance(0.0)
+                    .absolute_tolerance(1e-3)
+                    .solve(&a, &b)
+                    .unwrap();
+                assert!(result.is_converged());
+                assert!(*result.residual_history.last().unwrap() <= 1e-3);
+                assert!(result.residual_history[..result.iterations].iter().all(|&r| r > 1e-3));
+            }
+
+            #[test]
+            fn [<cg_max_iterations_ $elem>]() {
+                let a: Array2<$elem> = random_hpd(30);
+                let b: Array1<$elem> = random(30);
+                let result = ConjugateGradient::new().max_iterations(2).solve(&a, &b).unwrap();
+                assert_eq!(result.status, SolverStatus::MaxIterationsReached);
+                assert_eq!(result.iterations, 2);
+            }
+
+            #[test]
+            fn [<cg_initial_guess_ $elem>]() {
+                let a: Array2<$elem> = random_hpd(10);
+                let x: Array1<$elem> = random(10);
+                let b = a.dot(&x);
+                let result = ConjugateGradient::new().initial_guess(x.clone()).solve(&a, &b).unwrap();
+                assert!(result.is_converged());
+                assert_eq!(result.iterations, 0);
+                assert_eq!(result.solution, x);
+            }
+
+            #[test]
+            fn [<cg_indefinite_ $elem>]() {
+                let d: Array1<$elem> = array![1.0, -1.0, 2.0].mapv(<$elem>::from_real);
+                let b: Array1<$elem> = array![1.0, 1.0, 0.0].mapv(<$elem>::from_real);
+                let result = ConjugateGradient::new().solve(&d.as_diagonal(), &b).unwrap();
+                assert_eq!(result.status, SolverStatus::Breakdown);
+            }
+        }
+    };
+}
+
+cg!(f64);
+cg!(c64);
@@ -226,3 +226,121 @@ cholesky_solve!(f64, 1e-9);
 cholesky_solve!(f32, 1e-3);
 cholesky_solve!(c64, 1e-9);
 cholesky_solve!(c32, 1e-3);
+
+macro_rules! cholesky_solve_nrhs {
+    ($elem:ty, $rtol:expr) => {
+        paste::item! {
+            #[test]
+            fn [<cholesky_solve_nrhs_ $elem>]() {
+                let a: Array2<$elem> = random_hpd(4);
+                let x: Array2<$elem> = random((4, 3));
+                let b = a.dot(&x);
+                assert_close_l2!(&a.solvec(&b).unwrap(), &x, $rtol);
+                for &uplo in &[UPLO::Upper, UPLO::Lower] {
+                    let f = a.factorizec(uplo).unwrap();
+                    assert_close_l2!(&f.solvec(&b).unwrap(), &x, $rtol);
+                    let mut b_f = Array2::zeros((4, 3).f());
+                    b_f.assign(&b);
+                    assert_close_l2!(&f.solvec_inplace(&mut b_f).unwrap(), &x, $rtol);
+                }
+                let f = a.factorizec_equilibrated(UPLO::Upper).unwrap();
+                assert_close_l2!(&f.solvec(&b).unwrap(), &x, $rtol);
+            }
+        }
+    };
+}
+cholesky_solve_nrhs!(f64, 1e-9);
+cholesky_solve_nrhs!(f32, 1e-3);
+cholesky_solve_nrhs!(c64, 1e-9);
+cholesky_solve_nrhs!(c32, 1e-3);
+
+#[test]
+fn cholesky_rcond() {
+    macro_rules! rcondc {
+        ($elem:ty, $rows:expr, $atol:expr) => {
+            let a: Array2<$elem> = random_hpd($rows);
+            let anorm = a.opnorm_one().unwrap();
+            let rcond = 1. / (anorm * a.inv().unwrap().opnorm_one().unwrap());
+            assert_aclose!(a.rcondc().unwrap(), rcond, $atol);
+            assert_aclose!(a.clone().rcondc_into().unwrap(), rcond, $atol);
+            assert_aclose!(
+                a.factorizec(UPLO::Lower)
+                    .unwrap()
+                    .rcondc_with_norm(anorm)
+                    .unwrap(),
+                rcond,
+                $atol
+            );
+            let a_t = a.t().to_owned();
+            assert_aclose!(
+                a_t.factorizec(UPLO::Upper)
+                    .unwrap()
+                    .rcondc_with_norm(anorm)
+                    .unwrap(),
+                rcond,
+                $atol
+            );
+        };
+    }
+    for rows in 1..6 {
+        rcondc!(f64, rows, 0.2);
+        rcondc!(f32, rows, 0.5);
+        rcondc!(c64, rows, 0.2);
+        rcondc!(c32, rows, 0.5);
+    }
+}
+
+#[test]
+fn cholesky_rcond_identity() {
+    let a = Array2::<f64>::eye(4);
+    assert_aclose!(a.rcondc().unwrap(), 1., 1e-9);
+}
+
+macro_rules! cholesky_update {
+    ($elem:ty, $rtol:expr) => {
+        paste::item! {
+            #[test]
+            fn [<cholesky_update_ $elem>]() {
+                let a: Array2<$elem> = random_hpd(4);
+                let x: Array1<$elem> = random(4);
+                let xxh = x
+                    .view()
+                    .insert_axis(Axis(1))
+                    .dot(&x.mapv(|x| x.conj()).insert_axis(Axis(0)));
+                let updated = &a + &xxh;
+                let b: Array1<$elem> = random(4);
+                for &uplo in &[UPLO::Upper, UPLO::Lower] {
+                    let mut f = a.factorizec(uplo).unwrap();
+                    f.update(&x).unwrap();
+                    assert_close_l2!(&f.solvec(&b).unwrap(), &updated.solvec(&b).unwrap(), $rtol);
+                    assert_close_l2!(&f.into_lower(), &updated.cholesky(UPLO::Lower).unwrap(), $rtol);
+
+                    let mut f = updated.factorizec(uplo).unwrap();
+                    f.downdate(&x).unwrap();
+                    assert_close_l2!(&f.solvec(&b).unwrap(), &a.solvec(&b).unwrap(), $rtol);
+                    assert_close_l2!(&f.into_lower(), &a.cholesky(UPLO::Lower).unwrap(), $rtol);
+                }
+                let mut f = a.factorizec_equilibrated(UPLO::Upper).unwrap();
+                f.update(&x).unwrap();
+                assert_close_l2!(&f.solvec(&b).unwrap(), &updated.solvec(&b).unwrap(), $rtol);
+                f.downdate(&x).unwrap();
+                assert_close_l2!(&f.solvec(&b).unwrap(), &a.solvec(&b).unwrap(), $rtol);
+            }
+        }
+    };
+}
+cholesky_update!(f64, 1e-9);
+cholesky_update!(f32, 1e-3);
+cholesky_update!(c64, 1e-9);
+cholesky_update!(c32, 1e-3);
+
+#[test]
+fn cholesky_downdate_not_positive_definite() {
+    let a: Array2<f64> = Array2::eye(3);
+    let mut f = a.factorizec(UPLO::Lower).unwrap();
+    assert!(f.downdate(&array![0.0, 1.0, 0.0]).is_err());
+    assert!(f.downdate(&array![1.0, 1.0, 1.0]).is_err());
+    assert!(f.update(&array![1.0, 0.0]).is_err());
+    // The decomposition is left unchanged
+    assert_close_l2!(&f.factor, &a, 1e-12);
+}
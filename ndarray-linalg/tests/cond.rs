@@ -0,0 +1,95 @@
+use ndarray::*;
+use ndarray_linalg::*;
+
+#[test]
+fn cond_diag() {
+    let a: Array2<f64> = array![[2.0, 0.0, 0.0], [0.0, -4.0, 0.0], [0.0, 0.0, 0.5]];
+    assert_rclose!(a.cond(CondType::One).unwrap(), 8.0, 1e-9);
+    assert_rclose!(a.cond(CondType::Two).unwrap(), 8.0, 1e-9);
+    assert_rclose!(a.cond(CondType::Infinity).unwrap(), 8.0, 1e-9);
+    let fro = (20.25_f64 * (0.25 + 0.0625 + 4.0)).sqrt();
+    assert_rclose!(a.cond(CondType::Frobenius).unwrap(), fro, 1e-9);
+}
+
+#[test]
+fn cond_singular() {
+    let a: Array2<f64> = array![[1.0, 2.0], [2.0, 4.0]];
+    assert!(a.cond(CondType::Two).unwrap().is_infinite());
+    assert!(a.cond(CondType::One).unwrap().is_infinite());
+    assert!(a.cond(CondType::Infinity).unwrap().is_infinite());
+
+    // Rank one in exact arithmetic, but the smallest singular value is rounded to nonzero
+    let x: Array1<f64> = random(4);
+    let y: Array1<f64> = random(4);
+    let a = x.insert_axis(Axis(1)).dot(&y.insert_axis(Axis(0)));
+    assert!(a.cond(CondType::Two).unwrap().is_infinite());
+    assert!(a.cond(CondType::Frobenius).unwrap().is_infinite());
+}
+
+#[test]
+fn cond_not_square() {
+    let a: Array2<f64> = random((4, 3));
+    assert!(a.cond(CondType::One).is_err());
+    let (_, s, _) = a.svd(false, false).unwrap();
+    assert_rclose!(a.cond(CondType::Two).unwrap(), s[0] / s[2], 1e-9);
+}
+
+macro_rules! impl_test {
+    ($scalar:ty, $rtol:expr) => {
+        paste::item! {
+            #[test]
+            fn [<cond_ $scalar>]() {
+                let a: Array2<$scalar> = random_regular(4);
+                let a_inv = a.inv().unwrap();
+                assert_rclose!(
+                    a.cond(CondType::One).unwrap(),
+                    a.opnorm_one().unwrap() * a_inv.opnorm_one().unwrap(),
+                    $rtol
+                );
+                assert_rclose!(
+                    a.cond(CondType::Infinity).unwrap(),
+                    a.opnorm_inf().unwrap() * a_inv.opnorm_inf().unwrap(),
+                    $rtol
+                );
+                assert_rclose!(
+                    a.cond(CondType::Two).unwrap(),
+                    a.opnorm_2().unwrap() * a_inv.opnorm_2().unwrap(),
+                    $rtol
+                );
+                assert_rclose!(
+                    a.cond(CondType::Frobenius).unwrap(),
+                    a.opnorm_fro().unwrap() * a_inv.opnorm_fro().unwrap(),
+                    $rtol
+                );
+            }
+
+            #[test]
+            fn [<estimate_cond_2_ $scalar>]() {
+                let a: Array2<$scalar> = random_regular(5);
+                assert_rclose!(
+                    estimate_opnorm_2(&a, 5, 5, $rtol).unwrap(),
+                    a.opnorm_2().unwrap(),
+                    $rtol
+                );
+                assert_rclose!(
+                    estimate_cond_2(&a, 5, 5, $rtol).unwrap(),
+                    a.cond(CondType::Two).unwrap(),
+                    $rtol
+                );
+            }
+        }
+    };
+}
+
+impl_test!(f64, 1e-7);
+impl_test!(f32, 1e-3);
+impl_test!(c64, 1e-7);
+impl_test!(c32, 1e-3);
+
+#[test]
+fn estimate_opnorm_2_diagonal() {
+    let d = array![1.0, -5.0, 2.0, 0.5];
+    let op = d.into_diagonal();
+    assert_rclose!(estimate_opnorm_2(&op, 4, 4, 1e-9).unwrap(), 5.0, 1e-7);
+    assert_rclose!(estimate_cond_2(&op, 4, 4, 1e-9).unwrap(), 10.0, 1e-7);
+}
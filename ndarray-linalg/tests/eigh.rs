@@ -128,3 +128,65 @@ fn ssqrt_t_lower() {
     println!("ss = {:?}", &ss);
     assert_close_l2!(&ss, &ans, 1e-7);
 }
+
+#[test]
+fn ssqrt_complex() {
+    let a: Array2<c64> = random_hpd(3);
+    let s = a.ssqrt(UPLO::Upper).unwrap();
+    assert_close_l2!(&s.t().mapv(|x| x.conj()), &s, 1e-7);
+    assert_close_l2!(&s.dot(&s), &a, 1e-7);
+}
+
+#[test]
+fn funh_identity() {
+    let a: Array2<f64> = random_hermite(3);
+    let fa = a.funh(UPLO::Upper, |e| e).unwrap();
+    assert_close_l2!(&fa, &a, 1e-7);
+}
+
+#[test]
+fn expmh_logmh() {
+    let a: Array2<c64> = random_hpd(3);
+    let log_a = a.logmh(UPLO::Upper).unwrap();
+    let exp_log_a = log_a.expmh(UPLO::Upper).unwrap();
+    assert_close_l2!(&exp_log_a, &a, 1e-7);
+
+    let d = arr2(&[[1.0, 0.0], [0.0, 2.0]]);
+    let exp_d = d.expmh(UPLO::Lower).unwrap();
+    assert_close_l2!(
+        &exp_d,
+        &arr2(&[[1.0_f64.exp(), 0.0], [0.0, 2.0_f64.exp()]]),
+        1e-7
+    );
+}
+
+#[test]
+fn powh() {
+    let a: Array2<f64> = random_hermite(3);
+    let a2 = a.powh(UPLO::Upper, 2.0).unwrap();
+    assert_close_l2!(&a2, &a.dot(&a), 1e-7);
+
+    let a: Array2<f64> = random_hpd(3);
+    let a_half = a.powh(UPLO::Lower, 0.5).unwrap();
+    assert_close_l2!(&a_half, &a.ssqrt(UPLO::Lower).unwrap(), 1e-7);
+}
+
+#[test]
+fn inv_sqrth() {
+    let a: Array2<c64> = random_hpd(3);
+    let s = a.inv_sqrth(UPLO::Upper).unwrap();
+    let i: Array2<c64> = Array::eye(3);
+    assert_close_l2!(&s.dot(&a).dot(&s), &i, 1e-7);
+}
+
+#[test]
+fn funh_complex() {
+    // exp(iA) is unitary for Hermitian A
+    let a: Array2<f64> = random_hermite(3);
+    let u = a
+        .funh_complex(UPLO::Upper, |e| c64::new(0.0, e).exp())
+        .unwrap();
+    let uh = u.t().mapv(|x| x.conj());
+    let i: Array2<c64> = Array::eye(3);
+    assert_close_l2!(&uh.dot(&u), &i, 1e-7);
+}
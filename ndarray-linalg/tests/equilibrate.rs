@@ -0,0 +1,123 @@
+use ndarray::*;
+use ndarray_linalg::*;
+
+/// Matrix whose rows and columns have very different scales
+fn badly_scaled<A: Scalar + Lapack>(n: usize) -> Array2<A> {
+    let mut a: Array2<A> = random_regular(n);
+    for ((i, j), x) in a.indexed_iter_mut() {
+        *x = x.mul_real(A::real(10.0_f64.powi(2 * i as i32 - j as i32)));
+    }
+    a
+}
+
+macro_rules! impl_test {
+    ($scalar:ty, $rtol:expr) => {
+        paste::item! {
+            #[test]
+            fn [<equilibrate_ $scalar>]() {
+                let a: Array2<$scalar> = badly_scaled(4);
+                for e in &[a.equilibrate().unwrap(), a.equilibrate_power_of_two().unwrap()] {
+                    assert!(e.needs_row_scaling());
+                    let mut a_eq = a.clone();
+                    e.apply(&mut a_eq);
+                    // the largest element in each row is O(1)
+                    for row in a_eq.axis_iter(Axis(0)) {
+                        assert!(row.norm_max() <= 1.0 + $rtol);
+                        assert!(row.norm_max() >= 0.1);
+                    }
+                    e.unapply(&mut a_eq);
+                    assert_close_l2!(&a_eq, &a, $rtol);
+                }
+            }
+
+            #[test]
+            fn [<equilibrate_c_ $scalar>]() {
+                let a: Array2<$scalar> = badly_scaled(4);
+                let a = a.t().to_owned();
+                let e = a.equilibrate().unwrap();
+                let mut a_eq = a.clone();
+                e.apply(&mut a_eq);
+                for col in a_eq.axis_iter(Axis(1)) {
+                    assert!(col.norm_max() <= 1.0 + $rtol);
+                }
+            }
+
+            #[test]
+            fn [<equilibrate_solve_ $scalar>]() {
+                let a: Array2<$scalar> = badly_scaled(4);
+                let x: Array1<$scalar> = random(4);
+                let b = a.dot(&x);
+                let e = a.equilibrate().unwrap();
+                let mut a_eq = a.clone();
+                e.apply(&mut a_eq);
+                let mut b_eq = b.clone();
+                e.scale_rhs(&mut b_eq);
+                let mut y = a_eq.solve_into(b_eq).unwrap();
+                e.unscale_solution(&mut y);
+                assert_close_l2!(&y, &x, $rtol);
+            }
+
+            #[test]
+            fn [<factorize_equilibrated_ $scalar>]() {
+                let a: Array2<$scalar> = badly_scaled(4);
+                let x: Array1<$scalar> = random(4);
+                let f = a.factorize_equilibrated().unwrap();
+                assert_close_l2!(&f.solve(&a.dot(&x)).unwrap(), &x, $rtol);
+                assert_close_l2!(&f.solve_t(&a.t().dot(&x)).unwrap(), &x, $rtol);
+                let ah = a.t().mapv(|x| x.conj());
+                assert_close_l2!(&f.solve_h(&ah.dot(&x)).unwrap(), &x, $rtol);
+                assert_close_l2!(&f.inv().unwrap().dot(&a), &Array2::eye(4), $rtol);
+                let (sign, ln_det) = f.sln_det().unwrap();
+                let (sign_, ln_det_) = a.sln_det().unwrap();
+                assert_rclose!(ln_det, ln_det_, $rtol);
+                assert_aclose!(sign, sign_, $rtol);
+            }
+
+            #[test]
+            fn [<factorizec_equilibrated_ $scalar>]() {
+                let mut a: Array2<$scalar> = random_hpd(4);
+                let s: Array1<$scalar> = array![1e-3, 1.0, 1e2, 1e3].mapv(<$scalar>::from_real);
+                for ((i, j), x) in a.indexed_iter_mut() {
+                    *x *= s[i] * s[j];
+                }
+                let x: Array1<$scalar> = random(4);
+                for &uplo in &[UPLO::Upper, UPLO::Lower] {
+                    let f = a.factorizec_equilibrated(uplo).unwrap();
+                    assert_close_l2!(&f.solvec(&a.dot(&x)).unwrap(), &x, $rtol);
+                    assert_close_l2!(&f.invc().unwrap().dot(&a), &Array2::eye(4), $rtol);
+                    assert_rclose!(f.ln_detc(), a.ln_detc().unwrap(), $rtol);
+
+                    // The inner decomposition is of S * A * S
+                    let s = f.scale();
+                    let mut sas = a.clone();
+                    for ((i, j), x) in sas.indexed_iter_mut() {
+                        *x *= s[i] * s[j];
+                    }
+                    assert_rclose!(f.cholesky().ln_detc(), sas.ln_detc().unwrap(), $rtol);
+                }
+            }
+        }
+    };
+}
+
+impl_test!(f64, 1e-7);
+impl_test!(f32, 1e-3);
+impl_test!(c64, 1e-7);
+impl_test!(c32, 1e-3);
+
+#[test]
+fn equilibrate_hermitian() {
+    let a: Array2<f64> = array![[4.0, 1.0], [1.0, 100.0]];
+    let e = a.equilibrate_hermitian().unwrap();
+    assert_close_l2!(&e.row, &array![0.5, 0.1], 1e-9);
+    assert_close_l2!(&e.row, &e.col, 1e-9);
+    let mut a_eq = a;
+    e.apply(&mut a_eq);
+    assert_close_l2!(&a_eq.diag().to_owned(), &array![1.0, 1.0], 1e-9);
+}
+
+#[test]
+fn equilibrate_zero_row() {
+    let a: Array2<f64> = array![[1.0, 2.0], [0.0, 0.0]];
+    assert!(a.equilibrate().is_err());
+}
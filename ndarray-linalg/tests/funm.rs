@@ -0,0 +1,142 @@
+use ndarray::*;
+use ndarray_linalg::*;
+
+#[test]
+fn funm_hermitian() {
+    let a: Array2<c64> = random_hermite(4);
+    let e = a.funm(|z, _| z.exp()).unwrap();
+    let eh = a.expmh(UPLO::Upper).unwrap();
+    assert_close_l2!(&e, &eh, 1e-9);
+}
+
+#[test]
+fn funm_diagonal() {
+    let a: Array2<f64> = arr2(&[[1.0, 0.0, 0.0], [0.0, 2.0, 0.0], [0.0, 0.0, 3.0]]);
+    let s = a.funm(|z, _| z.sqrt()).unwrap();
+    let expected = arr2(&[[1.0, 0.0, 0.0], [0.0, 2.0, 0.0], [0.0, 0.0, 3.0]])
+        .mapv(|x: f64| c64::new(x.sqrt(), 0.0));
+    assert_close_l2!(&s, &expected, 1e-9);
+}
+
+#[test]
+fn funm_polynomial() {
+    // f(z) = z^3 with its derivatives
+    let cube = |z: c64, k: usize| match k {
+        0 => z * z * z,
+        1 => z * z * 3.0,
+        2 => z * 6.0,
+        3 => c64::new(6.0, 0.0),
+        _ => c64::new(0.0, 0.0),
+    };
+    let a: Array2<f64> = random((5, 5));
+    let a3 = a.dot(&a).dot(&a).mapv(|x| c64::new(x, 0.0));
+    assert_close_l2!(&a.funm(cube).unwrap(), &a3, 1e-9);
+
+    // Clustered eigenvalues, evaluated by the Taylor series
+    let a: Array2<f64> = arr2(&[
+        [1.0, 1.0, 2.0, 0.5],
+        [0.0, 1.01, 1.0, -1.0],
+        [0.0, 0.0, 3.0, 1.0],
+        [0.0, 0.0, 0.0, 1.02],
+    ]);
+    let a3 = a.dot(&a).dot(&a).mapv(|x| c64::new(x, 0.0));
+    assert_close_l2!(&a.funm(cube).unwrap(), &a3, 1e-9);
+}
+
+#[test]
+fn funm_trigonometric() {
+    let a: Array2<c64> = random((4, 4));
+    let sin = a.funm(|z, k| match k % 4 {
+        0 => z.sin(),
+        1 => z.cos(),
+        2 => -z.sin(),
+        _ => -z.cos(),
+    });
+    let cos = a.funm(|z, k| match k % 4 {
+        0 => z.cos(),
+        1 => -z.sin(),
+        2 => -z.cos(),
+        _ => z.sin(),
+    });
+    let (sin, cos) = (sin.unwrap(), cos.unwrap());
+    let i: Array2<c64> = Array::eye(4);
+    assert_close_l2!(&(sin.dot(&sin) + cos.dot(&cos)), &i, 1e-9);
+}
+
+#[test]
+fn funm_jordan() {
+    // exp(A) exp(-A) = I for the matrix with repeated eigenvalues
+    let a: Array2<f64> = arr2(&[[2.0, 1.0, 0.0], [0.0, 2.0, 1.0], [0.0, 0.0, 2.0]]);
+    let e = a.funm(|z, _| z.exp()).unwrap();
+    let e_inv = a.mapv(|x| -x).funm(|z, _| z.exp()).unwrap();
+    let i: Array2<c64> = Array::eye(3);
+    assert_close_l2!(&e.dot(&e_inv), &i, 1e-9);
+
+    // exp(J) = e^2 [[1, 1, 1/2], [0, 1, 1], [0, 0, 1]]
+    let expected = arr2(&[[1.0, 1.0, 0.5], [0.0, 1.0, 1.0], [0.0, 0.0, 1.0]])
+        .mapv(|x: f64| c64::new(x * 2.0_f64.exp(), 0.0));
+    assert_close_l2!(&e, &expected, 1e-9);
+}
+
+#[test]
+fn funm_taylor_not_converged() {
+    // The pole of f(z) = 1 / (c - z) is closer to the mean of the clustered eigenvalues than the eigenvalues,
+    // so that the Taylor series around the mean diverges
+    let a: Array2<f64> = arr2(&[[0.0, 1.0], [0.0, 0.05]]);
+    let c = c64::new(0.03, 0.0);
+    let f = |z: c64, k: usize| {
+        let factorial: f64 = (1..=k).map(|i| i as f64).product();
+        c64::new(factorial, 0.0) / (c - z).powi(k as i32 + 1)
+    };
+    assert!(a.funm(f).is_err());
+}
+
+#[test]
+fn signm() {
+    let a: Array2<f64> = random((5, 5));
+    let s = a.signm().unwrap();
+    assert_close_l2!(&s.dot(&s), &Array::eye(5), 1e-7);
+    assert_close_l2!(&s.dot(&a), &a.dot(&s), 1e-7);
+
+    let a: Array2<c64> = random_hermite(4);
+    let s = a.signm().unwrap();
+    let s_h = a.funh(UPLO::Upper, |e| e.signum()).unwrap();
+    assert_close_l2!(&s, &s_h, 1e-7);
+}
+
+#[test]
+fn powm_integer() {
+    let a: Array2<f64> = random((4, 4));
+    let a_c = a.mapv(|x| c64::new(x, 0.0));
+    assert_close_l2!(&a.powm(3.0).unwrap(), &a_c.dot(&a_c).dot(&a_c), 1e-7);
+    assert_close_l2!(&a.powm(-1.0).unwrap(), &a_c.inv().unwrap(), 1e-7);
+    assert_close_l2!(&a.powm(0.0).unwrap(), &Array::eye(4), 1e-7);
+}
+
+#[test]
+fn powm_fractional() {
+    let a: Array2<c64> = random((4, 4));
+    let a = a + Array2::<c64>::eye(4) * c64::new(4.0, 0.0);
+    let a_half = a.powm(0.5).unwrap();
+    assert_close_l2!(&a_half.dot(&a_half), &a, 1e-7);
+
+    let a_third = a.powm(-1.0 / 3.0).unwrap();
+    let a_inv = a.inv().unwrap();
+    assert_close_l2!(&a_third.dot(&a_third).dot(&a_third), &a_inv, 1e-7);
+
+    let a_p = a.powm(1.7).unwrap();
+    assert_close_l2!(&a_p, &a.dot(&a.powm(0.7).unwrap()), 1e-7);
+
+    let a: Array2<f64> = random_hpd(3);
+    let a_p = a.powm(0.3).unwrap();
+    let a_h = a.powh(UPLO::Upper, 0.3).unwrap().mapv(|x| c64::new(x, 0.0));
+    assert_close_l2!(&a_p, &a_h, 1e-7);
+}
+
+#[test]
+fn powm_invalid() {
+    let a: Array2<f64> = random((3, 3));
+    assert!(a.powm(f64::NAN).is_err());
+    assert!(a.powm(f64::INFINITY).is_err());
+    assert!(a.powm(-1e10).is_err());
+}
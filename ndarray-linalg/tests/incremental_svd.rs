@@ -0,0 +1,93 @@
+use ndarray::*;
+use ndarray_linalg::krylov::*;
+use ndarray_linalg::*;
+
+fn reconstruct<T: Scalar + Lapack>(svd: &IncrementalSvd<T>) -> Array2<T> {
+    let mut us = svd.u().clone();
+    for (mut col, &s) in us.axis_iter_mut(Axis(1)).zip(svd.singular_values()) {
+        col.mapv_inplace(|x| x.mul_real(s));
+    }
+    us.dot(svd.vt())
+}
+
+fn check_orthonormal<T: Scalar + Lapack>(svd: &IncrementalSvd<T>, rtol: T::Real) {
+    let k = svd.singular_values().len();
+    let u = svd.u();
+    let vt = svd.vt();
+    let uh = u.t().mapv(|x| x.conj());
+    let v = vt.t().mapv(|x| x.conj());
+    assert_close_l2!(&uh.dot(u), &Array2::eye(k), rtol);
+    assert_close_l2!(&vt.dot(&v), &Array2::eye(k), rtol);
+}
+
+macro_rules! incremental_svd {
+    ($elem:ty, $rtol:expr) => {
+        paste::item! {
+            #[test]
+            fn [<incremental_svd_ $elem>]() {
+                let a: Array2<$elem> = random((12, 9));
+                let mut svd = IncrementalSvd::empty(12);
+                for j in (0..9).step_by(3) {
+                    svd.append_columns(&a.slice(s![.., j..j + 3])).unwrap();
+                }
+                let (_, s, _) = a.svd(false, false).unwrap();
+                assert_close_l2!(svd.singular_values(), &s, $rtol);
+                assert_close_l2!(&reconstruct(&svd), &a, $rtol);
+                check_orthonormal(&svd, $rtol);
+
+                svd.reorthogonalize().unwrap();
+                assert_close_l2!(svd.singular_values(), &s, $rtol);
+                assert_close_l2!(&reconstruct(&svd), &a, $rtol);
+                check_orthonormal(&svd, $rtol);
+            }
+
+            #[test]
+            fn [<incremental_svd_truncated_ $elem>]() {
+                // Rank 3 matrix is exactly represented with the truncation rank 3
+                let x: Array2<$elem> = random((12, 3));
+                let y: Array2<$elem> = random((3, 10));
+                let a = x.dot(&y);
+                let (u, s, vt) = a.slice(s![.., ..4]).svd(true, true).unwrap();
+                let (u, vt) = (u.unwrap(), vt.unwrap());
+                let mut svd = IncrementalSvd::new(
+                    u.slice(s![.., ..3]).to_owned(),
+                    s.slice(s![..3]).to_owned(),
+                    vt.slice(s![..3, ..]).to_owned(),
+                )
+                .rank(3);
+                for j in 4..10 {
+                    svd.append_columns(&a.slice(s![.., j..j + 1])).unwrap();
+                    assert_eq!(svd.singular_values().len(), 3);
+                }
+                assert_eq!(svd.vt().dim(), (3, 10));
+                assert_close_l2!(&reconstruct(&svd), &a, $rtol);
+                check_orthonormal(&svd, $rtol);
+            }
+
+            #[test]
+            fn [<incremental_svd_householder_ $elem>]() {
+                let a: Array2<$elem> = random((8, 6));
+                let mut svd = IncrementalSvd::empty(8);
+                svd.append_columns_with(&a.slice(s![.., ..2]), Householder::new(8, 1e-12))
+                    .unwrap();
+                svd.append_columns_with(&a.slice(s![.., 2..]), Householder::new(8, 1e-12))
+                    .unwrap();
+                let (_, s, _) = a.svd(false, false).unwrap();
+                assert_close_l2!(svd.singular_values(), &s, $rtol);
+                assert_close_l2!(&reconstruct(&svd), &a, $rtol);
+            }
+        }
+    };
+}
+
+incremental_svd!(f64, 1e-9);
+incremental_svd!(c64, 1e-9);
+
+#[test]
+fn incremental_svd_shape_error() {
+    let mut svd = IncrementalSvd::<f64>::empty(5);
+    let c: Array2<f64> = random((4, 2));
+    assert!(svd.append_columns(&c).is_err());
+    let c: Array2<f64> = random((5, 2));
+    assert!(svd.append_columns_with(&c, MGS::new(4, 1e-12)).is_err());
+}
@@ -0,0 +1,135 @@
+use ndarray::*;
+use ndarray_linalg::{krylov::*, *};
+
+fn diagonally_dominant<A: Scalar>(n: usize) -> Array2<A> {
+    let a: Array2<A> = random((n, n));
+    a + Array2::<A>::eye(n).mapv(|x| x.mul_real(A::real(n)))
+}
+
+macro_rules! gmres {
+    ($elem:ty) => {
+        paste::item! {
+            #[test]
+            fn [<gmres_ $elem>]() {
+                let a: Array2<$elem> = diagonally_dominant(30);
+                let b: Array1<$elem> = random(30);
+                for &restart in &[5, 30] {
+                    let result = Gmres::new(restart).tolerance(1e-10).solve(&a, &b).unwrap();
+                    assert!(result.is_converged());
+                    assert_eq!(result.residual_history.len(), result.iterations + 1);
+                    assert!(result.residual_norm <= 1e-10 * b.norm_l2() * (1.0 + 1e-6));
+                    assert_close_l2!(&a.dot(&result.solution), &b, 1e-9);
+                }
+            }
+
+            #[test]
+            fn [<gmres_preconditioned_ $elem>]() {
+                // Badly scaled rows, which the Jacobi preconditioner fixes
+                let n = 30;
+                let scale = Array1::from_shape_fn(n, |i| 10.0_f64.powi((i % 3) as i32));
+                let mut a: Array2<$elem> = diagonally_dominant(n);
+                for (mut row, &s) in a.axis_iter_mut(Axis(0)).zip(&scale) {
+                    row.mapv_inplace(|x| x.mul_real(s));
+                }
+                let b: Array1<$elem> = random(n);
+                let jacobi = a.diag().mapv(|x| <$elem>::from_real(1.0) / x).into_diagonal();
+                for &side in &[PreconditionerSide::Left, PreconditionerSide::Right] {
+                    let result = Gmres::new(n)
+                        .tolerance(1e-10)
+                        .solve_preconditioned(&a, &jacobi, side, &b)
+                        .unwrap();
+                    assert!(result.is_converged());
+                    assert_close_l2!(&a.dot(&result.solution), &b, 1e-8);
+                }
+            }
+
+            #[test]
+            fn [<gmres_max_iterations_ $elem>]() {
+                let a: Array2<$elem> = random((20, 20));
+                let b: Array1<$elem> = random(20);
+                let result = Gmres::new(5).max_iterations(3).solve(&a, &b).unwrap();
+                assert_eq!(result.status, SolverStatus::MaxIterationsReached);
+                assert_eq!(result.iterations, 3);
+                assert_eq!(result.residual_history.len(), 4);
+            }
+
+            #[test]
+            fn [<gmres_initial_guess_ $elem>]() {
+                let a: Array2<$elem> = diagonally_dominant(10);
+                let x: Array1<$elem> = random(10);
+                let b = a.dot(&x);
+                let result = Gmres::new(10).initial_guess(x.clone()).solve(&a, &b).unwrap();
+                assert!(result.is_converged());
+                assert_eq!(result.iterations, 0);
+                assert_eq!(result.solution, x);
+
+                let result = Gmres::new(10).initial_guess(Array1::zeros(9)).solve(&a, &b);
+                assert!(result.is_err());
+            }
+        }
+    };
+}
+
+gmres!(f64);
+gmres!(c64);
+
+macro_rules! short_recurrence {
+    ($solver:ident, $name:ident, $elem:ty) => {
+        paste::item! {
+            #[test]
+            fn [<$name _ $elem>]() {
+                let a: Array2<$elem> = diagonally_dominant(30);
+                let b: Array1<$elem> = random(30);
+                let result = $solver::new().tolerance(1e-10).solve(&a, &b).unwrap();
+                assert!(result.is_converged());
+                assert!(result.residual_norm <= 1e-9 * b.norm_l2());
+                assert_close_l2!(&a.dot(&result.solution), &b, 1e-9);
+            }
+
+            #[test]
+            fn [<$name _preconditioned_ $elem>]() {
+                // Badly scaled columns, which the right Jacobi preconditioner fixes
+                let n = 30;
+                let scale = Array1::from_shape_fn(n, |i| 10.0_f64.powi((i % 3) as i32));
+                let mut a: Array2<$elem> = diagonally_dominant(n);
+                for (mut col, &s) in a.axis_iter_mut(Axis(1)).zip(&scale) {
+                    col.mapv_inplace(|x| x.mul_real(s));
+                }
+                let b: Array1<$elem> = random(n);
+                let jacobi = a.diag().mapv(|x| <$elem>::from_real(1.0) / x).into_diagonal();
+                let result = $solver::new()
+                    .tolerance(1e-10)
+                    .solve_preconditioned(&a, &jacobi, &b)
+                    .unwrap();
+                assert!(result.is_converged());
+                assert_close_l2!(&a.dot(&result.solution), &b, 1e-9);
+            }
+
+            #[test]
+            fn [<$name _max_iterations_ $elem>]() {
+                let a: Array2<$elem> = random((20, 20));
+                let b: Array1<$elem> = random(20);
+                let result = $solver::new().max_iterations(2).solve(&a, &b).unwrap();
+                assert_eq!(result.status, SolverStatus::MaxIterationsReached);
+                assert_eq!(result.iterations, 2);
+                assert_eq!(result.residual_history.len(), 3);
+            }
+
+            #[test]
+            fn [<$name _initial_guess_ $elem>]() {
+                let a: Array2<$elem> = diagonally_dominant(10);
+                let x: Array1<$elem> = random(10);
+                let b = a.dot(&x);
+                let result = $solver::new().initial_guess(x.clone()).solve(&a, &b).unwrap();
+                assert!(result.is_converged());
+                assert_eq!(result.iterations, 0);
+                assert_eq!(result.solution, x);
+            }
+        }
+    };
+}
+
+short_recurrence!(Bicgstab, bicgstab, f64);
+short_recurrence!(Bicgstab, bicgstab, c64);
+short_recurrence!(Tfqmr, tfqmr, f64);
+short_recurrence!(Tfqmr, tfqmr, c64);
@@ -0,0 +1,112 @@
+use ndarray::*;
+use ndarray_linalg::{krylov::*, *};
+
+fn dense<A: Scalar>(t: &Tridiagonal<A>) -> Array2<A> {
+    let n = t.d.len();
+    let mut a = Array2::from_diag(&Array1::from(t.d.clone()));
+    for i in 0..n - 1 {
+        a[(i + 1, i)] = t.dl[i];
+        a[(i, i + 1)] = t.du[i];
+    }
+    a
+}
+
+macro_rules! lanczos_full {
+    ($elem:ty, $method:ident) => {
+        paste::item! {
+            #[test]
+            fn [<lanczos_ $method _ $elem>]() {
+                let a: Array2<$elem> = random_hermite(6);
+                let v: Array1<$elem> = random(6);
+                let (q, t) = [<lanczos_ $method>](a.clone(), v, 1e-9);
+                assert_eq!(q.dim(), (6, 6));
+                let qh = q.t().mapv(|x| x.conj());
+                assert_close_l2!(&qh.dot(&q), &Array2::eye(6), 1e-9);
+                assert_close_l2!(&qh.dot(&a).dot(&q), &dense(&t), 1e-9);
+            }
+        }
+    };
+}
+
+lanczos_full!(f64, mgs);
+lanczos_full!(f64, householder);
+lanczos_full!(c64, mgs);
+lanczos_full!(c64, householder);
+
+macro_rules! lanczos {
+    ($elem:ty) => {
+        paste::item! {
+            #[test]
+            fn [<lanczos_ritz_ $elem>]() {
+                let a: Array2<$elem> = random_hermite(8);
+                let v: Array1<$elem> = random(8);
+                let mut lanczos =
+                    Lanczos::new(a.view(), v, 1e-9, Reorthogonalization::Full(MGS::new(8, 1e-9)));
+                for _ in 0..4 {
+                    assert!(lanczos.next().is_some());
+                }
+                assert_eq!(lanczos.dim(), 4);
+                let (e, x) = lanczos.ritz_pairs().unwrap();
+                assert_eq!(x.dim(), (8, 4));
+                let bounds = lanczos.residual_bounds().unwrap();
+                for i in 0..4 {
+                    let x = x.column(i);
+                    let r = a.dot(&x) - &x.mapv(|x| x.mul_real(e[i]));
+                    assert_rclose!(r.norm_l2(), bounds[i], 1e-7);
+                }
+
+                // Complete to the whole space
+                for _ in &mut lanczos {}
+                let (e_ref, _) = a.eigh(UPLO::Upper).unwrap();
+                assert_close_l2!(&lanczos.ritz_values().unwrap(), &e_ref, 1e-9);
+            }
+
+            #[test]
+            fn [<lanczos_selective_ $elem>]() {
+                // Eigenvalues spread over several orders so that Ritz values converge early
+                let n = 60;
+                let d = Array1::from_shape_fn(n, |i| <$elem>::from_real(1.1_f64.powi(i as i32)));
+                let a = Array2::from_diag(&d);
+                let v: Array1<$elem> = random(n);
+                let mut lanczos = Lanczos::new(
+                    a.view(),
+                    v,
+                    1e-12,
+                    Reorthogonalization::Selective(MGS::new(n, 1e-9)),
+                );
+                for _ in 0..40 {
+                    lanczos.next();
+                }
+                let k = lanczos.dim();
+                let (e, x) = lanczos.ritz_pairs().unwrap();
+                assert_eq!(x.dim(), (n, k));
+                // Lanczos vectors keep orthogonality up to sqrt(eps)
+                let xh = x.t().mapv(|x| x.conj());
+                assert_close_l2!(&xh.dot(&x), &Array2::eye(k), 1e-5);
+                let e_max = e[k - 1];
+                assert_rclose!(e_max, 1.1_f64.powi(n as i32 - 1), 1e-9);
+                // No ghost copies of the largest eigenvalue
+                assert!(e[k - 2] < e_max * (1.0 - 1e-6));
+            }
+
+            #[test]
+            fn [<lanczos_without_reorthogonalization_ $elem>]() {
+                let a: Array2<$elem> = random_hpd(20);
+                let v: Array1<$elem> = random(20);
+                let mut lanczos = Lanczos::without_reorthogonalization(a.view(), v, 1e-12);
+                for _ in 0..15 {
+                    lanczos.next();
+                }
+                let (e_ref, _) = a.eigh(UPLO::Upper).unwrap();
+                let e = lanczos.ritz_values().unwrap();
+                assert_rclose!(e[e.len() - 1], e_ref[19], 1e-9);
+                let t = lanczos.tridiagonal();
+                assert_eq!(t.d.len(), lanczos.dim());
+                assert_eq!(t.dl.len(), lanczos.dim() - 1);
+            }
+        }
+    };
+}
+
+lanczos!(f64);
+lanczos!(c64);
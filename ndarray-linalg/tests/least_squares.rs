@@ -127,3 +127,145 @@ impl_underdetermined!(f32);
 impl_underdetermined!(f64);
 impl_underdetermined!(c32);
 impl_underdetermined!(c64);
+
+/// QR and complete orthogonal drivers give the same solution as SVD
+fn test_drivers<T: Scalar + Lapack>(a: Array2<T>) {
+    let (m, n) = a.dim();
+    let b: Array1<T> = random(m);
+    let expected = a.least_squares(&b).unwrap();
+    for &driver in &[
+        LeastSquaresDriver::Qr,
+        LeastSquaresDriver::CompleteOrthogonal,
+    ] {
+        let result = a.least_squares_with(&b, driver).unwrap();
+        assert_eq!(result.rank as usize, m.min(n));
+        assert!(result.singular_values.is_empty());
+        assert_close_l2!(&result.solution, &expected.solution, T::real(1.0e-4));
+        match (
+            &result.residual_sum_of_squares,
+            &expected.residual_sum_of_squares,
+        ) {
+            (Some(r), Some(e)) => assert_aclose!(r[()], e[()], T::real(1.0e-4)),
+            (None, None) => {}
+            _ => panic!("Residual sum of squares must be computed when m >= n"),
+        }
+    }
+}
+
+macro_rules! impl_drivers {
+    ($scalar:ty) => {
+        paste::item! {
+            #[test]
+            fn [<least_squares_ $scalar _drivers>]() {
+                for &(m, n) in &[(3, 3), (4, 3), (3, 4)] {
+                    test_drivers::<$scalar>(random((m, n)));
+                    test_drivers::<$scalar>(random((m, n).f()));
+                }
+            }
+
+            #[test]
+            fn [<least_squares_ $scalar _rank_deficient>]() {
+                // The minimum norm solution does not use the zero column
+                let mut a: Array2<$scalar> = random((5, 3));
+                a.column_mut(2).fill(Default::default());
+                let b: Array1<$scalar> = random(5);
+                let expected = a.slice(s![.., ..2]).least_squares(&b).unwrap();
+                let result = a
+                    .least_squares_with(&b, LeastSquaresDriver::CompleteOrthogonal)
+                    .unwrap();
+                assert_eq!(result.rank, 2);
+                assert_close_l2!(&result.solution.slice(s![..2]), &expected.solution, 1e-3);
+                assert!(result.solution[2].abs() < 1e-6);
+            }
+        }
+    };
+}
+
+impl_drivers!(f32);
+impl_drivers!(f64);
+impl_drivers!(c32);
+impl_drivers!(c64);
+
+/// Compare with the solution of the KKT system
+///
+/// ```text
+/// [A^H A  B^H] [x]   [A^H c]
+/// [B      0  ] [l] = [d    ]
+/// ```
+fn test_constrained<T: Scalar + Lapack>(a: Array2<T>, b: Array2<T>) {
+    let (m, n) = a.dim();
+    let p = b.nrows();
+    let c: Array1<T> = random(m);
+    let d: Array1<T> = random(p);
+    let result = a.least_squares_constrained(&c, &b, &d).unwrap();
+    let x = &result.solution;
+    assert_close_l2!(&b.dot(x), &d, T::real(1.0e-4));
+    let residual = &c - &a.dot(x);
+    assert_aclose!(
+        result.residual_sum_of_squares,
+        residual.norm_l2().powi(2),
+        T::real(1.0e-4)
+    );
+
+    let ah = a.t().mapv(|x| x.conj());
+    let mut kkt = Array2::<T>::zeros((n + p, n + p));
+    kkt.slice_mut(s![..n, ..n]).assign(&ah.dot(&a));
+    kkt.slice_mut(s![..n, n..])
+        .assign(&b.t().mapv(|x| x.conj()));
+    kkt.slice_mut(s![n.., ..n]).assign(&b);
+    let mut rhs = Array1::<T>::zeros(n + p);
+    rhs.slice_mut(s![..n]).assign(&ah.dot(&c));
+    rhs.slice_mut(s![n..]).assign(&d);
+    let expected = kkt.solve_into(rhs).unwrap();
+    assert_close_l2!(x, &expected.slice(s![..n]), T::real(1.0e-3));
+}
+
+/// For square `B`, `x` is the solution of `min |B^{-1} (d - Ax)|`
+fn test_generalized_linear_model<T: Scalar + Lapack>(a: Array2<T>, b: Array2<T>) {
+    let d: Array1<T> = random(a.nrows());
+    let result = a.generalized_linear_model(&b, &d).unwrap();
+    let x = &result.solution;
+    let y = &result.noise;
+    assert_close_l2!(&(a.dot(x) + b.dot(y)), &d, T::real(1.0e-4));
+
+    let b_inv = b.inv().unwrap();
+    let expected = b_inv.dot(&a).least_squares(&b_inv.dot(&d)).unwrap();
+    assert_close_l2!(x, &expected.solution, T::real(1.0e-3));
+}
+
+macro_rules! impl_generalized {
+    ($scalar:ty) => {
+        paste::item! {
+            #[test]
+            fn [<least_squares_ $scalar _constrained>]() {
+                test_constrained::<$scalar>(random((5, 3)), random((2, 3)));
+                test_constrained::<$scalar>(random((5, 3).f()), random((2, 3).f()));
+                // underdetermined without the constraints
+                test_constrained::<$scalar>(random((2, 4)), random((2, 4)));
+            }
+
+            #[test]
+            fn [<least_squares_ $scalar _constrained_shape_error>]() {
+                let a: Array2<$scalar> = random((5, 3));
+                let b: Array2<$scalar> = random((4, 3));
+                let c: Array1<$scalar> = random(5);
+                let d: Array1<$scalar> = random(4);
+                assert!(a.least_squares_constrained(&c, &b, &d).is_err());
+            }
+
+            #[test]
+            fn [<least_squares_ $scalar _generalized_linear_model>]() {
+                test_generalized_linear_model::<$scalar>(random((5, 3)), random_hpd(5));
+                test_generalized_linear_model::<$scalar>(
+                    random((5, 3).f()),
+                    random_regular(5),
+                );
+            }
+        }
+    };
+}
+
+impl_generalized!(f32);
+impl_generalized!(f64);
+impl_generalized!(c32);
+impl_generalized!(c64);
@@ -188,3 +188,38 @@ impl_underdetermined!(f32);
 impl_underdetermined!(f64);
 impl_underdetermined!(c32);
 impl_underdetermined!(c64);
+
+/// QR and complete orthogonal drivers give the same solution as SVD
+fn test_drivers<T: Scalar + Lapack>(a: Array2<T>, b: Array2<T>) {
+    let expected = a.least_squares(&b).unwrap();
+    for &driver in &[
+        LeastSquaresDriver::Qr,
+        LeastSquaresDriver::CompleteOrthogonal,
+    ] {
+        let result = a.least_squares_with(&b, driver).unwrap();
+        assert_close_l2!(&result.solution, &expected.solution, T::real(1.0e-4));
+        let mut a_ = a.clone();
+        let mut b_ = b.clone();
+        let result = a_.least_squares_in_place_with(&mut b_, driver).unwrap();
+        assert_close_l2!(&result.solution, &expected.solution, T::real(1.0e-4));
+    }
+}
+
+macro_rules! impl_drivers {
+    ($scalar:ty) => {
+        paste::item! {
+            #[test]
+            fn [<least_squares_ $scalar _drivers>]() {
+                for &(m, n) in &[(4, 3), (3, 4)] {
+                    test_drivers::<$scalar>(random((m, n)), random((m, 2)));
+                    test_drivers::<$scalar>(random((m, n).f()), random((m, 2).f()));
+                }
+            }
+        }
+    };
+}
+
+impl_drivers!(f32);
+impl_drivers!(f64);
+impl_drivers!(c32);
+impl_drivers!(c64);
@@ -0,0 +1,73 @@
+use ndarray::*;
+use ndarray_linalg::{krylov::*, *};
+
+macro_rules! minres {
+    ($elem:ty) => {
+        paste::item! {
+            #[test]
+            fn [<minres_ $elem>]() {
+                // Hermitian indefinite
+                let a: Array2<$elem> = random_hermite(30);
+                let a = a - Array2::<$elem>::eye(30).mapv(|x| x.mul_real(5.0));
+                let b: Array1<$elem> = random(30);
+                let result = Minres::new().tolerance(1e-10).solve(&a, &b).unwrap();
+                assert!(result.is_converged());
+                assert_eq!(result.residual_history.len(), result.iterations + 1);
+                // The residual norm never increases
+                for r in result.residual_history.windows(2) {
+                    assert!(r[1] <= r[0] * (1.0 + 1e-12));
+                }
+                assert_close_l2!(&a.dot(&result.solution), &b, 1e-8);
+            }
+
+            #[test]
+            fn [<minres_indefinite_diagonal_ $elem>]() {
+                // CG breaks down for this system
+                let d: Array1<$elem> = array![1.0, -1.0, 2.0].mapv(<$elem>::from_real);
+                let b: Array1<$elem> = array![1.0, 1.0, 0.0].mapv(<$elem>::from_real);
+                let result = Minres::new().tolerance(1e-12).solve(&d.as_diagonal(), &b).unwrap();
+                assert!(result.is_converged());
+                let x: Array1<$elem> = array![1.0, -1.0, 0.0].mapv(<$elem>::from_real);
+                assert_close_l2!(&result.solution, &x, 1e-10);
+            }
+
+            #[test]
+            fn [<minres_preconditioned_ $elem>]() {
+                // Badly scaled Hermitian indefinite D A D with the positive Jacobi preconditioner
+                let n = 30;
+                let d = Array1::from_shape_fn(n, |i| <$elem>::from_real(10.0_f64.powi((i % 3) as i32)));
+                let a: Array2<$elem> = random_hermite(n);
+                let a = a - Array2::<$elem>::eye(n).mapv(|x| x.mul_real(5.0));
+                let a = &d.view().insert_axis(Axis(1)) * &a * &d.view().insert_axis(Axis(0));
+                let b: Array1<$elem> = random(n);
+                let jacobi = d.mapv(|x| <$elem>::from_real(1.0) / (x * x)).into_diagonal();
+                let minres = Minres::new().tolerance(1e-12);
+                let result = minres.solve_preconditioned(&a, &jacobi, &b).unwrap();
+                assert!(result.is_converged());
+                assert_close_l2!(&a.dot(&result.solution), &b, 1e-8);
+            }
+
+            #[test]
+            fn [<minres_not_positive_preconditioner_ $elem>]() {
+                let a: Array2<$elem> = random_hpd(5);
+                let b: Array1<$elem> = random(5);
+                let m = Array1::from_elem(5, <$elem>::from_real(-1.0)).into_diagonal();
+                let result = Minres::new().solve_preconditioned(&a, &m, &b).unwrap();
+                assert_eq!(result.status, SolverStatus::Breakdown);
+                assert_eq!(result.iterations, 0);
+            }
+
+            #[test]
+            fn [<minres_max_iterations_ $elem>]() {
+                let a: Array2<$elem> = random_hpd(30);
+                let b: Array1<$elem> = random(30);
+                let result = Minres::new().max_iterations(2).solve(&a, &b).unwrap();
+                assert_eq!(result.status, SolverStatus::MaxIterationsReached);
+                assert_eq!(result.iterations, 2);
+            }
+        }
+    };
+}
+
+minres!(f64);
+minres!(c64);
@@ -0,0 +1,62 @@
+use ndarray::*;
+use ndarray_linalg::*;
+
+macro_rules! impl_test {
+    ($scalar:ty) => {
+        paste::item! {
+            #[test]
+            fn [<solve_mixed_precision_ $scalar>]() {
+                let a: Array2<$scalar> = random_regular(10);
+                let x: Array1<$scalar> = random(10);
+                let b = a.dot(&x);
+                let result = a.solve_mixed_precision(&b).unwrap();
+                assert_close_l2!(&result.solution, &x, 1e-12);
+                assert!(result.refined);
+                assert!(result.iterations > 0);
+            }
+
+            #[test]
+            fn [<solve_mixed_precision_nrhs_ $scalar>]() {
+                let a: Array2<$scalar> = random_regular(10);
+                let x: Array2<$scalar> = random((10, 3));
+                let b = a.dot(&x);
+                let result = a.solve_mixed_precision(&b).unwrap();
+                assert_close_l2!(&result.solution, &x, 1e-12);
+                // F-continuous matrices
+                let a = a.t().to_owned().reversed_axes();
+                let b = b.t().to_owned().reversed_axes();
+                let result = a.solve_mixed_precision(&b).unwrap();
+                assert_close_l2!(&result.solution, &x, 1e-12);
+            }
+
+            #[test]
+            fn [<solvec_mixed_precision_ $scalar>]() {
+                let a: Array2<$scalar> = random_hpd(10);
+                let x: Array1<$scalar> = random(10);
+                let b = a.dot(&x);
+                let result = a.solvec_mixed_precision(&b).unwrap();
+                assert_close_l2!(&result.solution, &x, 1e-12);
+                assert!(result.refined);
+            }
+
+            #[test]
+            fn [<solve_mixed_precision_fallback_ $scalar>]() {
+                // ill-conditioned in single precision
+                let mut a: Array2<$scalar> = random_regular(10);
+                for (i, mut row) in a.axis_iter_mut(Axis(0)).enumerate() {
+                    row.mapv_inplace(|x| x.mul_real(10.0_f64.powi(-(i as i32))));
+                }
+                let row = &a.row(8) + &a.row(9);
+                a.row_mut(9).assign(&row);
+                let x: Array1<$scalar> = random(10);
+                let b = a.dot(&x);
+                let result = a.solve_mixed_precision(&b).unwrap();
+                assert!(!result.refined);
+                assert_close_l2!(&a.dot(&result.solution), &b, 1e-9);
+            }
+        }
+    };
+}
+
+impl_test!(f64);
+impl_test!(c64);
@@ -0,0 +1,139 @@
+use ndarray::*;
+use ndarray_linalg::*;
+
+/// Check the optimality conditions of `min |b - Ax|` subject to `lower <= x <= upper`
+fn check_kkt<T: Scalar<Real = T> + PartialOrd>(
+    result: &BoundedLeastSquaresResult<T>,
+    lower: &Array1<T>,
+    upper: &Array1<T>,
+    tol: T,
+) {
+    for i in 0..result.solution.len() {
+        let (x, w) = (result.solution[i], result.dual[i]);
+        assert!(x >= lower[i] && x <= upper[i]);
+        if x == lower[i] {
+            assert!(w <= tol);
+        } else if x == upper[i] {
+            assert!(w >= -tol);
+        } else {
+            assert!(w.abs() <= tol);
+        }
+    }
+}
+
+macro_rules! nnls {
+    ($elem:ty, $rtol:expr) => {
+        paste::item! {
+            #[test]
+            fn [<nnls_identity_ $elem>]() {
+                let a: Array2<$elem> = Array2::eye(3);
+                let b: Array1<$elem> = array![1.0, -2.0, 3.0];
+                let result = a.least_squares_nonnegative(&b).unwrap();
+                assert_close_l2!(&result.solution, &array![1.0, 0.0, 3.0], $rtol);
+                assert_rclose!(result.residual_norm, 2.0, $rtol);
+            }
+
+            #[test]
+            fn [<nnls_interior_ $elem>]() {
+                // Positive exact solution is the unconstrained least squares
+                let a: Array2<$elem> = random((8, 4));
+                let x = array![1.0, 0.5, 2.0, 0.25];
+                let b = a.dot(&x);
+                let result = a.least_squares_nonnegative(&b).unwrap();
+                assert_close_l2!(&result.solution, &x, $rtol);
+                assert!(result.residual_norm < $rtol);
+            }
+
+            #[test]
+            fn [<nnls_kkt_ $elem>]() {
+                let a: Array2<$elem> = random((10, 6));
+                let b: Array1<$elem> = random(10);
+                let result = a.least_squares_nonnegative(&b).unwrap();
+                let lower = Array1::zeros(6);
+                let upper = Array1::from_elem(6, <$elem>::INFINITY);
+                check_kkt(&result, &lower, &upper, $rtol);
+                let residual = &b - &a.dot(&result.solution);
+                assert_close_l2!(&result.dual, &a.t().dot(&residual), $rtol);
+
+                // Underdetermined systems also have the solution
+                let a: Array2<$elem> = random((4, 7));
+                let b: Array1<$elem> = random(4);
+                let result = a.least_squares_nonnegative(&b).unwrap();
+                let lower = Array1::zeros(7);
+                let upper = Array1::from_elem(7, <$elem>::INFINITY);
+                check_kkt(&result, &lower, &upper, $rtol);
+            }
+
+            #[test]
+            fn [<bvls_ $elem>]() {
+                let a: Array2<$elem> = Array2::eye(3);
+                let b: Array1<$elem> = array![-1.0, 0.5, 5.0];
+                let lower = Array1::zeros(3);
+                let upper = Array1::ones(3);
+                let result = a.least_squares_bounded(&b, &lower, &upper).unwrap();
+                assert_close_l2!(&result.solution, &array![0.0, 0.5, 1.0], $rtol);
+
+                let a: Array2<$elem> = random((10, 6));
+                let b: Array1<$elem> = random(10);
+                let b = b.mapv(|x| 4.0 * x - 2.0);
+                let lower = array![-0.5, 0.0, <$elem>::NEG_INFINITY, -1.0, 0.1, -0.2];
+                let upper = array![0.5, <$elem>::INFINITY, 0.2, 1.0, 0.1, 0.2];
+                let result = a.least_squares_bounded(&b, &lower, &upper).unwrap();
+                check_kkt(&result, &lower, &upper, $rtol);
+                assert_eq!(result.solution[4], 0.1);
+            }
+
+            #[test]
+            fn [<bvls_unbounded_ $elem>]() {
+                let a: Array2<$elem> = random((8, 4));
+                let b: Array1<$elem> = random(8);
+                let lower = Array1::from_elem(4, <$elem>::NEG_INFINITY);
+                let upper = Array1::from_elem(4, <$elem>::INFINITY);
+                let result = a.least_squares_bounded(&b, &lower, &upper).unwrap();
+                let expected = a.least_squares(&b).unwrap();
+                assert_close_l2!(&result.solution, &expected.solution, $rtol);
+            }
+        }
+    };
+}
+
+nnls!(f32, 1e-3);
+nnls!(f64, 1e-9);
+
+#[test]
+fn nnls_shape_error() {
+    let a: Array2<f64> = random((6, 4));
+    let b: Array1<f64> = random(5);
+    assert!(a.least_squares_nonnegative(&b).is_err());
+    let b: Array1<f64> = random(6);
+    assert!(a
+        .least_squares_bounded(&b, &Array1::zeros(3), &Array1::ones(4))
+        .is_err());
+}
+
+#[test]
+fn bvls_inconsistent_bounds() {
+    let a: Array2<f64> = random((6, 4));
+    let b: Array1<f64> = random(6);
+    assert!(a
+        .least_squares_bounded(&b, &Array1::ones(4), &Array1::zeros(4))
+        .is_err());
+    let lower = array![0.0, f64::NAN, 0.0, 0.0];
+    assert!(a
+        .least_squares_bounded(&b, &lower, &Array1::ones(4))
+        .is_err());
+}
+
+#[test]
+fn bvls_ill_conditioned() {
+    // The condition number 2e9 is beyond the square root of 1/epsilon,
+    // so that both columns are accepted only without the normal equations
+    let delta = 1e-9;
+    let a: Array2<f64> = array![[1.0, 1.0], [0.0, delta], [0.0, 0.0]];
+    let b = array![0.0, 1.0, 0.0];
+    let lower = Array1::from_elem(2, f64::NEG_INFINITY);
+    let upper = Array1::from_elem(2, f64::INFINITY);
+    let result = a.least_squares_bounded(&b, &lower, &upper).unwrap();
+    assert_close_l2!(&result.solution, &array![-1.0 / delta, 1.0 / delta], 1e-5);
+    assert!(result.residual_norm < 1e-6);
+}
@@ -51,3 +51,29 @@ fn opnorm_4x3() {
 fn opnorm_4x3_t() {
     test(gen(4, 3, true), 42.0, 24.0, 650.0.sqrt());
 }
+
+#[test]
+fn opnorm_spectral_diag() {
+    // singular values are 3, 2, 1
+    let a: Array2<f64> = array![[0.0, -2.0, 0.0], [3.0, 0.0, 0.0], [0.0, 0.0, 1.0]];
+    assert_rclose!(a.opnorm_2().unwrap(), 3.0, 1e-9);
+    assert_rclose!(a.opnorm_nuclear().unwrap(), 6.0, 1e-9);
+    assert_rclose!(a.opnorm_schatten(3.0).unwrap(), 36.0_f64.cbrt(), 1e-9);
+    assert_rclose!(a.opnorm_schatten(f64::INFINITY).unwrap(), 3.0, 1e-9);
+    assert!(a.opnorm_schatten(0.5).is_err());
+    assert!(a.opnorm_schatten(f64::NAN).is_err());
+}
+
+#[test]
+fn opnorm_spectral_random() {
+    let a: Array2<c64> = random((4, 3));
+    let (_, s, _) = a.svd(false, false).unwrap();
+    assert_rclose!(a.opnorm_2().unwrap(), s[0], 1e-9);
+    assert_rclose!(a.opnorm_nuclear().unwrap(), s.sum(), 1e-9);
+    // Schatten 2-norm is the Frobenius norm
+    assert_rclose!(
+        a.opnorm_schatten(2.0).unwrap(),
+        a.opnorm_fro().unwrap(),
+        1e-9
+    );
+}
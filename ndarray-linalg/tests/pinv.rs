@@ -0,0 +1,79 @@
+use ndarray::*;
+use ndarray_linalg::*;
+
+fn test_penrose<T: Scalar + Lapack>(a: &Array2<T>, a_pinv: &Array2<T>, rtol: T::Real) {
+    let ap = a.dot(a_pinv);
+    let pa = a_pinv.dot(a);
+    assert_close_l2!(&ap.dot(a), a, rtol);
+    assert_close_l2!(&pa.dot(a_pinv), a_pinv, rtol);
+    assert_close_l2!(&ap.t().mapv(|x| x.conj()), &ap, rtol);
+    assert_close_l2!(&pa.t().mapv(|x| x.conj()), &pa, rtol);
+}
+
+macro_rules! impl_test {
+    ($scalar:ty, $rtol:expr) => {
+        paste::item! {
+            #[test]
+            fn [<pinv_square_ $scalar>]() {
+                let a: Array2<$scalar> = random((3, 3));
+                let (a_pinv, rank) = a.pinv(Cutoff::Auto).unwrap();
+                assert_eq!(rank, 3);
+                assert_close_l2!(&a_pinv, &a.inv().unwrap(), $rtol);
+            }
+
+            #[test]
+            fn [<pinv_tall_ $scalar>]() {
+                let a: Array2<$scalar> = random((4, 2));
+                let (a_pinv, rank) = a.pinv(Cutoff::Auto).unwrap();
+                assert_eq!(rank, 2);
+                test_penrose(&a, &a_pinv, $rtol);
+            }
+
+            #[test]
+            fn [<pinv_wide_t_ $scalar>]() {
+                let a: Array2<$scalar> = random((2, 4).f());
+                let (a_pinv, rank) = a.pinv_into(Cutoff::Relative(1e-3)).unwrap();
+                assert_eq!(rank, 2);
+                assert_eq!(a_pinv.dim(), (4, 2));
+            }
+
+            #[test]
+            fn [<pinv_rank_deficient_ $scalar>]() {
+                let b: Array2<$scalar> = random((4, 2));
+                let c: Array2<$scalar> = random((2, 3));
+                let a = b.dot(&c);
+                let (a_pinv, rank) = a.pinv(Cutoff::Relative(1e-4)).unwrap();
+                assert_eq!(rank, 2);
+                test_penrose(&a, &a_pinv, $rtol);
+            }
+
+            #[test]
+            fn [<pinvh_ $scalar>]() {
+                let b: Array2<$scalar> = random((4, 2));
+                let a = b.dot(&b.t().mapv(|x| x.conj()));
+                let (a_pinv, rank) = a.pinvh(UPLO::Upper, Cutoff::Relative(1e-4)).unwrap();
+                assert_eq!(rank, 2);
+                test_penrose(&a, &a_pinv, $rtol);
+                let (a_pinv_svd, _) = a.pinv(Cutoff::Relative(1e-4)).unwrap();
+                assert_close_l2!(&a_pinv, &a_pinv_svd, $rtol);
+            }
+        }
+    };
+}
+
+impl_test!(f32, 1e-3);
+impl_test!(f64, 1e-9);
+impl_test!(c32, 1e-3);
+impl_test!(c64, 1e-9);
+
+#[test]
+fn pinv_cutoff() {
+    let a: Array2<f64> = arr2(&[[1.0, 0.0], [0.0, 1e-6]]);
+    let (_, rank) = a.pinv(Cutoff::Auto).unwrap();
+    assert_eq!(rank, 2);
+    let (a_pinv, rank) = a.pinv(Cutoff::Relative(1e-3)).unwrap();
+    assert_eq!(rank, 1);
+    assert_close_l2!(&a_pinv, &arr2(&[[1.0, 0.0], [0.0, 0.0]]), 1e-9);
+    let (_, rank) = a.pinv(Cutoff::Absolute(2.0)).unwrap();
+    assert_eq!(rank, 0);
+}
@@ -0,0 +1,99 @@
+use ndarray::*;
+use ndarray_linalg::*;
+
+/// Check `A = QR` with unitary `Q` and upper triangular `R`
+fn check<T: Scalar + Lapack>(qr: &QRFactorized<T>, a: &Array2<T>, rtol: T::Real) {
+    let (m, n) = a.dim();
+    let q = qr.q_full();
+    let r = qr.r_full();
+    assert_eq!(q.dim(), (m, m));
+    assert_eq!(r.dim(), (m, n));
+    let qh = q.t().mapv(|x| x.conj());
+    assert_close_l2!(&qh.dot(q), &Array2::eye(m), rtol);
+    assert_close_l2!(&q.dot(r), a, rtol);
+    for i in 0..m {
+        for j in 0..i.min(n) {
+            assert_eq!(r[(i, j)], T::zero());
+        }
+    }
+
+    // Compatible with the reduced decomposition of `QR`
+    let (q, r) = qr.qr();
+    let (q_ref, r_ref): (Array2<T>, Array2<T>) = a.qr().unwrap();
+    assert_eq!(q.dim(), q_ref.dim());
+    assert_eq!(r.dim(), r_ref.dim());
+    assert_close_l2!(&q.dot(&r), a, rtol);
+}
+
+macro_rules! qr_update {
+    ($elem:ty, $rtol:expr) => {
+        paste::item! {
+            #[test]
+            fn [<qr_update_rows_ $elem>]() {
+                for &(m, n) in &[(5, 3), (3, 5), (4, 4)] {
+                    let mut a: Array2<$elem> = random((m, n));
+                    let mut qr = a.factorize_qr().unwrap();
+                    check(&qr, &a, $rtol);
+
+                    for &index in &[0, 2, m + 1] {
+                        let row: Array1<$elem> = random(n);
+                        qr.insert_row(index, &row).unwrap();
+                        let mut rows: Vec<_> = a.outer_iter().map(|r| r.to_owned()).collect();
+                        rows.insert(index, row);
+                        let views: Vec<_> = rows.iter().map(|r| r.view()).collect();
+                        a = stack(Axis(0), &views).unwrap();
+                        check(&qr, &a, $rtol);
+                    }
+
+                    for &index in &[1, 0, a.nrows() - 1] {
+                        qr.delete_row(index).unwrap();
+                        a = a.select(Axis(0), &(0..a.nrows()).filter(|&i| i != index).collect::<Vec<_>>());
+                        check(&qr, &a, $rtol);
+                    }
+                }
+            }
+
+            #[test]
+            fn [<qr_update_columns_ $elem>]() {
+                for &(m, n) in &[(5, 3), (3, 5), (4, 4)] {
+                    let mut a: Array2<$elem> = random((m, n));
+                    let mut qr = a.factorize_qr().unwrap();
+
+                    for &index in &[0, 2, n + 1] {
+                        let column: Array1<$elem> = random(m);
+                        qr.insert_column(index, &column).unwrap();
+                        let mut columns: Vec<_> = a.axis_iter(Axis(1)).map(|c| c.to_owned()).collect();
+                        columns.insert(index, column);
+                        let views: Vec<_> = columns.iter().map(|c| c.view()).collect();
+                        a = stack(Axis(1), &views).unwrap();
+                        check(&qr, &a, $rtol);
+                    }
+
+                    for &index in &[1, 0, a.ncols() - 1] {
+                        qr.delete_column(index).unwrap();
+                        a = a.select(Axis(1), &(0..a.ncols()).filter(|&j| j != index).collect::<Vec<_>>());
+                        check(&qr, &a, $rtol);
+                    }
+                }
+            }
+        }
+    };
+}
+
+qr_update!(f64, 1e-9);
+qr_update!(f32, 1e-4);
+qr_update!(c64, 1e-9);
+qr_update!(c32, 1e-4);
+
+#[test]
+fn qr_update_out_of_bounds() {
+    let a: Array2<f64> = random((4, 3));
+    let mut qr = a.factorize_qr().unwrap();
+    assert!(qr.insert_row(5, &Array1::zeros(3)).is_err());
+    assert!(qr.insert_row(0, &Array1::zeros(2)).is_err());
+    assert!(qr.delete_row(4).is_err());
+    assert!(qr.insert_column(4, &Array1::zeros(4)).is_err());
+    assert!(qr.insert_column(0, &Array1::zeros(3)).is_err());
+    assert!(qr.delete_column(3).is_err());
+    check(&qr, &a, 1e-9);
+}
@@ -0,0 +1,126 @@
+use ndarray::*;
+use ndarray_linalg::*;
+use rand::{rngs::StdRng, thread_rng, SeedableRng};
+
+fn reconstruct<T: Scalar>(u: &Array2<T>, s: &Array1<T::Real>, vt: &Array2<T>) -> Array2<T> {
+    let mut us = u.clone();
+    for (mut col, &s) in us.axis_iter_mut(Axis(1)).zip(s) {
+        col.mapv_inplace(|x| x.mul_real(s));
+    }
+    us.dot(vt)
+}
+
+macro_rules! randomized_svd {
+    ($elem:ty, $rtol:expr) => {
+        paste::item! {
+            #[test]
+            fn [<randomized_svd_low_rank_ $elem>]() {
+                let x: Array2<$elem> = random((20, 4));
+                let y: Array2<$elem> = random((4, 15));
+                let a = x.dot(&y);
+                let (u, s, vt) = RandomizedSvd::new(4).decompose(&a).unwrap();
+                let s_ref = a.svd(false, false).unwrap().1;
+                assert_close_l2!(&s, &s_ref.slice(s![..4]), $rtol);
+                assert_close_l2!(&reconstruct(&u, &s, &vt), &a, $rtol);
+                let uh = u.t().mapv(|x| x.conj());
+                assert_close_l2!(&uh.dot(&u), &Array2::eye(4), $rtol);
+            }
+
+            #[test]
+            fn [<randomized_svd_decay_ $elem>]() {
+                // Singular values decaying as 10^{-i}
+                let n = 30;
+                let u: Array2<$elem> = random_unitary(n);
+                let v: Array2<$elem> = random_unitary(n);
+                let s_ref = Array1::from_shape_fn(n, |i| 10.0_f64.powi(-(i as i32)));
+                let mut us = u.clone();
+                for (mut col, &s) in us.axis_iter_mut(Axis(1)).zip(&s_ref) {
+                    col.mapv_inplace(|x| x.mul_real(s));
+                }
+                let a = us.dot(&v.t().mapv(|x| x.conj()));
+                let (_, s, _) = RandomizedSvd::new(5)
+                    .oversampling(10)
+                    .power_iterations(1)
+                    .decompose(&a)
+                    .unwrap();
+                assert_close_l2!(&s, &s_ref.slice(s![..5]), $rtol);
+            }
+
+            #[test]
+            fn [<randomized_svd_operator_ $elem>]() {
+                let d: Array1<$elem> = array![1.0, 5.0, 0.0, 3.0, 4.0, 2.0].mapv(<$elem>::from_real);
+                // The samples span the whole space
+                let (u, s, vt) = RandomizedSvd::new(2)
+                    .oversampling(4)
+                    .decompose_operator(&d.as_diagonal(), 6)
+                    .unwrap();
+                assert_close_l2!(&s, &array![5.0, 4.0], $rtol);
+                assert_eq!(u.dim(), (6, 2));
+                assert_eq!(vt.dim(), (2, 6));
+            }
+
+            #[test]
+            fn [<randomized_svd_seed_ $elem>]() {
+                let a: Array2<$elem> = random((12, 10));
+                let svd = RandomizedSvd::new(3).oversampling(2).power_iterations(0).seed(7);
+                let (u1, s1, vt1) = svd.decompose(&a).unwrap();
+                let (u2, s2, vt2) = svd.decompose(&a).unwrap();
+                assert_eq!(u1, u2);
+                assert_eq!(s1, s2);
+                assert_eq!(vt1, vt2);
+            }
+
+            #[test]
+            fn [<range_finder_ $elem>]() {
+                let x: Array2<$elem> = random((15, 3));
+                let y: Array2<$elem> = random((3, 10));
+                let a = x.dot(&y);
+                let mut rng = StdRng::seed_from_u64(0);
+                let q = range_finder(&a, 10, 5, 1, &mut rng).unwrap();
+                assert_eq!(q.dim(), (15, 5));
+                let qh = q.t().mapv(|x| x.conj());
+                assert_close_l2!(&qh.dot(&q), &Array2::eye(5), $rtol);
+                // The range of A is contained in the span of Q
+                assert_close_l2!(&q.dot(&qh.dot(&a)), &a, $rtol);
+            }
+        }
+    };
+}
+
+randomized_svd!(f64, 1e-9);
+randomized_svd!(c64, 1e-9);
+
+#[test]
+fn range_finder_empty() {
+    let a: Array2<f64> = random((4, 3));
+    let q = range_finder(&a, 3, 0, 0, &mut thread_rng()).unwrap();
+    assert_eq!(q.dim(), (4, 0));
+}
+
+#[test]
+fn randomized_svd_no_rows() {
+    let a: Array2<f64> = Array2::zeros((0, 4));
+    let (u, s, vt) = RandomizedSvd::new(2).decompose(&a).unwrap();
+    assert_eq!(u.dim(), (0, 0));
+    assert_eq!(s.len(), 0);
+    assert_eq!(vt.dim(), (0, 4));
+}
+
+#[test]
+fn randomized_svd_no_columns() {
+    let a: Array2<f64> = Array2::zeros((4, 0));
+    let (u, s, vt) = RandomizedSvd::new(2).decompose(&a).unwrap();
+    assert_eq!(u.dim(), (4, 0));
+    assert_eq!(s.len(), 0);
+    assert_eq!(vt.dim(), (0, 0));
+}
+
+#[test]
+fn randomized_svd_no_samples() {
+    let a: Array2<f64> = random((4, 3));
+    let svd = RandomizedSvd::new(0).oversampling(0);
+    let (u, s, vt) = svd.decompose(&a).unwrap();
+    assert_eq!(u.dim(), (4, 0));
+    assert_eq!(s.len(), 0);
+    assert_eq!(vt.dim(), (0, 3));
+}
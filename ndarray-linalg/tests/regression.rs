@@ -0,0 +1,136 @@
+use ndarray::*;
+use ndarray_linalg::*;
+
+/// Design matrix with an intercept column
+fn design<T: Scalar>(m: usize, n: usize) -> Array2<T> {
+    let mut a: Array2<T> = random((m, n));
+    a.column_mut(0).fill(T::one());
+    a
+}
+
+macro_rules! regression {
+    ($elem:ty, $rtol:expr) => {
+        paste::item! {
+            #[test]
+            fn [<ordinary_least_squares_ $elem>]() {
+                let a: Array2<$elem> = design(8, 3);
+                let b: Array1<$elem> = random(8);
+                let result = a.ordinary_least_squares(&b).unwrap();
+                let expected = a.least_squares(&b).unwrap();
+                assert_close_l2!(&result.solution, &expected.solution, $rtol);
+                assert_eq!(result.rank, 3);
+                assert_eq!(result.degrees_of_freedom, 5);
+
+                let residual = &b - &a.dot(&result.solution);
+                let rss = residual.norm_l2().square();
+                assert_rclose!(result.residual_sum_of_squares, rss, $rtol);
+
+                // sigma^2 (A^H A)^{-1}
+                let sigma2 = <$elem>::from_real(rss / 5.0);
+                let ah: Array2<$elem> = conjugate(&a);
+                let covariance = ah.dot(&a).inv().unwrap().mapv(|x| x * sigma2);
+                assert_close_l2!(&result.covariance, &covariance, $rtol);
+                let standard_errors = covariance.diag().mapv(|c| c.re().sqrt());
+                assert_close_l2!(&result.standard_errors, &standard_errors, $rtol);
+
+                let mean = b.sum() / <$elem>::from_real(8.0);
+                let tss = b.mapv(|x| (x - mean).square()).sum();
+                assert_rclose!(result.r_squared, 1.0 - rss / tss, $rtol);
+            }
+
+            #[test]
+            fn [<weighted_least_squares_ $elem>]() {
+                let a: Array2<$elem> = design(8, 3);
+                let b: Array1<$elem> = random(8);
+                let w = Array1::from_shape_fn(8, |i| 1.0 + i as <$elem as Scalar>::Real);
+
+                // Unit weights are the ordinary least squares
+                let ols = a.ordinary_least_squares(&b).unwrap();
+                let result = a.weighted_least_squares(&b, &Array1::ones(8)).unwrap();
+                assert_close_l2!(&result.solution, &ols.solution, $rtol);
+                assert_close_l2!(&result.covariance, &ols.covariance, $rtol);
+
+                // (A^H W A)^{-1} A^H W b
+                let result = a.weighted_least_squares(&b, &w).unwrap();
+                let aw = Array2::from_shape_fn((8, 3), |(i, j)| a[(i, j)].mul_real(w[i]));
+                let ah: Array2<$elem> = conjugate(&a);
+                let awh: Array2<$elem> = conjugate(&aw);
+                let normal = ah.dot(&aw);
+                let expected = normal.solve(&awh.dot(&b)).unwrap();
+                assert_close_l2!(&result.solution, &expected, $rtol);
+
+                // Diagonal covariance is the inverse of the weights
+                let covariance = Array2::from_diag(&w.mapv(|w| <$elem>::from_real(w.recip())));
+                let gls = a.generalized_least_squares(&b, &covariance).unwrap();
+                assert_close_l2!(&gls.solution, &result.solution, $rtol);
+                assert_close_l2!(&gls.standard_errors, &result.standard_errors, $rtol);
+                assert_rclose!(gls.r_squared, result.r_squared, $rtol);
+            }
+
+            #[test]
+            fn [<generalized_least_squares_ $elem>]() {
+                let a: Array2<$elem> = design(8, 3);
+                let b: Array1<$elem> = random(8);
+                let covariance: Array2<$elem> = random_hpd(8);
+                let result = a.generalized_least_squares(&b, &covariance).unwrap();
+
+                // (A^H S^{-1} A)^{-1} A^H S^{-1} b
+                let s_inv_a = covariance.solve(&a).unwrap();
+                let ah: Array2<$elem> = conjugate(&a);
+                let s_inv_ah: Array2<$elem> = conjugate(&s_inv_a);
+                let normal = ah.dot(&s_inv_a);
+                let expected = normal.solve(&s_inv_ah.dot(&b)).unwrap();
+                assert_close_l2!(&result.solution, &expected, $rtol);
+
+                let residual = &b - &a.dot(&result.solution);
+                let rss = residual.inner(&covariance.solve(&residual).unwrap()).re();
+                assert_rclose!(result.residual_sum_of_squares, rss, $rtol);
+                let sigma2 = <$elem>::from_real(rss / 5.0);
+                let param_covariance = normal.inv().unwrap().mapv(|x| x * sigma2);
+                assert_close_l2!(&result.covariance, &param_covariance, $rtol);
+            }
+        }
+    };
+}
+
+regression!(f64, 1e-7);
+regression!(c64, 1e-7);
+
+#[test]
+fn weighted_least_squares_invalid_weights() {
+    let a: Array2<f64> = design(5, 2);
+    let b: Array1<f64> = random(5);
+    for &w in &[-1.0, f64::NAN, f64::INFINITY] {
+        let mut weights = Array1::ones(5);
+        weights[2] = w;
+        assert!(a.weighted_least_squares(&b, &weights).is_err());
+    }
+}
+
+#[test]
+fn r_squared_constant_observations() {
+    let a: Array2<f64> = design(5, 2);
+    let b = Array1::from_elem(5, 2.0);
+    let result = a.ordinary_least_squares(&b).unwrap();
+    assert!(result.r_squared.is_nan());
+}
+
+#[test]
+fn weighted_least_squares_zero_weight() {
+    // An observation of zero weight is the same as removing it
+    let a: Array2<f64> = design(6, 2);
+    let b: Array1<f64> = random(6);
+    let mut weights = Array1::from_shape_fn(6, |i| 1.0 + i as f64);
+    weights[2] = 0.0;
+    let result = a.weighted_least_squares(&b, &weights).unwrap();
+
+    let rows = [0, 1, 3, 4, 5];
+    let expected = a
+        .select(Axis(0), &rows)
+        .weighted_least_squares(&b.select(Axis(0), &rows), &weights.select(Axis(0), &rows))
+        .unwrap();
+    assert_eq!(result.degrees_of_freedom, 3);
+    assert_eq!(result.degrees_of_freedom, expected.degrees_of_freedom);
+    assert_close_l2!(&result.solution, &expected.solution, 1e-9);
+    assert_close_l2!(&result.covariance, &expected.covariance, 1e-9);
+}
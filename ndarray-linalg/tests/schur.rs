@@ -0,0 +1,50 @@
+use ndarray::*;
+use ndarray_linalg::*;
+
+fn test<T: Scalar + Lapack>(a: &Array2<T>) {
+    let (t, z) = a.schur().unwrap();
+    let zh = z.t().mapv(|x| x.conj());
+    let n = a.nrows();
+    assert_close_l2!(&zh.dot(&z), &Array::eye(n), T::real(1e-5));
+    assert_close_l2!(&z.dot(&t).dot(&zh), a, T::real(1e-5));
+    // T is (quasi-)upper triangular
+    for i in 0..n {
+        for j in 0..i.saturating_sub(1) {
+            assert!(t[(i, j)].abs() < T::real(1e-5));
+        }
+    }
+}
+
+macro_rules! impl_test {
+    ($scalar:ty) => {
+        paste::item! {
+            #[test]
+            fn [<schur_ $scalar>]() {
+                let a: Array2<$scalar> = random((4, 4));
+                test(&a);
+            }
+
+            #[test]
+            fn [<schur_t_ $scalar>]() {
+                let a: Array2<$scalar> = random((4, 4).f());
+                test(&a);
+            }
+        }
+    };
+}
+
+impl_test!(f32);
+impl_test!(f64);
+impl_test!(c32);
+impl_test!(c64);
+
+#[test]
+fn schur_complex_triangular() {
+    let a: Array2<c64> = random((4, 4));
+    let (t, _) = a.schur().unwrap();
+    for i in 0..4 {
+        for j in 0..i {
+            assert!(t[(i, j)].abs() < 1e-9);
+        }
+    }
+}
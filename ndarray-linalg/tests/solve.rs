@@ -125,6 +125,44 @@ fn solve_random_complex() {
     }
 }
 
+#[test]
+fn solve_nrhs() {
+    macro_rules! solve_nrhs {
+        ($elem:ty, $rtol:expr) => {
+            for &set_f in &[false, true] {
+                let a: Array2<$elem> = random([4; 2].set_f(set_f));
+                let x: Array2<$elem> = random((4, 3));
+                let b = a.dot(&x);
+                assert_close_l2!(&a.solve(&b).unwrap(), &x, $rtol);
+                assert_close_l2!(&a.factorize().unwrap().solve(&b).unwrap(), &x, $rtol);
+                assert_close_l2!(
+                    &a.factorize_equilibrated().unwrap().solve(&b).unwrap(),
+                    &x,
+                    $rtol
+                );
+
+                // F-contiguous and non-contiguous right-hand sides
+                let mut b_f = Array2::zeros((4, 3).f());
+                b_f.assign(&b);
+                assert_close_l2!(&a.solve_inplace(&mut b_f).unwrap(), &x, $rtol);
+                let mut b_wide = Array2::zeros((4, 6));
+                b_wide.slice_mut(s![.., ..;2]).assign(&b);
+                let mut b_view = b_wide.slice_mut(s![.., ..;2]);
+                assert_close_l2!(&a.solve_inplace(&mut b_view).unwrap(), &x, $rtol);
+
+                let b = a.t().dot(&x);
+                assert_close_l2!(&a.solve_t(&b).unwrap(), &x, $rtol);
+                let b = a.t().mapv(|x| x.conj()).dot(&x);
+                assert_close_l2!(&a.solve_h_into(b).unwrap(), &x, $rtol);
+            }
+        };
+    }
+    solve_nrhs!(f64, 1e-9);
+    solve_nrhs!(f32, 1e-3);
+    solve_nrhs!(c64, 1e-9);
+    solve_nrhs!(c32, 1e-3);
+}
+
 #[should_panic]
 #[test]
 fn solve_shape_mismatch() {
@@ -62,3 +62,54 @@ fn solveh_random_t() {
     let y = f.solveh_into(b).unwrap();
     assert_close_l2!(&x, &y, 1e-7);
 }
+
+#[test]
+fn solveh_nrhs() {
+    macro_rules! solveh_nrhs {
+        ($elem:ty, $rtol:expr) => {
+            let a: Array2<$elem> = random_hermite(4);
+            let x: Array2<$elem> = random((4, 3));
+            let b = a.dot(&x);
+            assert_close_l2!(&a.solveh(&b).unwrap(), &x, $rtol);
+            let f = a.factorizeh().unwrap();
+            assert_close_l2!(&f.solveh(&b).unwrap(), &x, $rtol);
+            let mut b_f = Array2::zeros((4, 3).f());
+            b_f.assign(&b);
+            assert_close_l2!(&f.solveh_inplace(&mut b_f).unwrap(), &x, $rtol);
+        };
+    }
+    solveh_nrhs!(f64, 1e-7);
+    solveh_nrhs!(f32, 1e-3);
+    solveh_nrhs!(c64, 1e-7);
+    solveh_nrhs!(c32, 1e-3);
+}
+
+#[test]
+fn rcondh() {
+    macro_rules! rcondh {
+        ($elem:ty, $rows:expr, $atol:expr) => {
+            let a: Array2<$elem> = random_hermite($rows);
+            let anorm = a.opnorm_one().unwrap();
+            let rcond = 1. / (anorm * a.inv().unwrap().opnorm_one().unwrap());
+            assert_aclose!(a.rcondh().unwrap(), rcond, $atol);
+            assert_aclose!(a.clone().rcondh_into().unwrap(), rcond, $atol);
+            assert_aclose!(
+                a.factorizeh().unwrap().rcondh_with_norm(anorm).unwrap(),
+                rcond,
+                $atol
+            );
+        };
+    }
+    for rows in 1..6 {
+        rcondh!(f64, rows, 0.2);
+        rcondh!(f32, rows, 0.5);
+        rcondh!(c64, rows, 0.2);
+        rcondh!(c32, rows, 0.5);
+    }
+}
+
+#[test]
+fn rcondh_identity() {
+    let a = Array2::<c64>::eye(4);
+    assert_aclose!(a.rcondh().unwrap(), 1., 1e-9);
+}
@@ -0,0 +1,88 @@
+use ndarray::*;
+use ndarray_linalg::*;
+
+macro_rules! impl_test {
+    ($scalar:ty, $rtol:expr) => {
+        paste::item! {
+            #[test]
+            fn [<solve_expert_ $scalar>]() {
+                let a: Array2<$scalar> = random((4, 4));
+                let x: Array1<$scalar> = random(4);
+                let b = a.dot(&x);
+                for &equilibrate in &[false, true] {
+                    let result = a.solve_expert(&b, equilibrate).unwrap();
+                    assert_close_l2!(&result.solution, &x, $rtol);
+                    assert_aclose!(result.rcond, a.rcond().unwrap(), 0.5);
+                    assert!(*result.backward_error.first().unwrap() < $rtol);
+                }
+            }
+
+            #[test]
+            fn [<solve_expert_nrhs_ $scalar>]() {
+                let a: Array2<$scalar> = random((4, 4).f());
+                let x: Array2<$scalar> = random((4, 3));
+                let b = a.dot(&x);
+                let result = a.solve_expert(&b, true).unwrap();
+                assert_close_l2!(&result.solution, &x, $rtol);
+                assert_eq!(result.forward_error.len(), 3);
+                assert_eq!(result.backward_error.len(), 3);
+            }
+
+            #[test]
+            fn [<solvec_expert_ $scalar>]() {
+                let a: Array2<$scalar> = random_hpd(4);
+                let x: Array2<$scalar> = random((4, 2).f());
+                let b = a.dot(&x);
+                let result = a.solvec_expert(&b, true).unwrap();
+                assert_close_l2!(&result.solution, &x, $rtol);
+                assert_aclose!(result.rcond, a.rcondc().unwrap(), 0.5);
+                let x = x.column(0).to_owned();
+                let b = a.dot(&x);
+                let result = a.solvec_expert(&b, false).unwrap();
+                assert_close_l2!(&result.solution, &x, $rtol);
+                assert!(result.row_scale.is_none());
+            }
+
+            #[test]
+            fn [<solveh_expert_ $scalar>]() {
+                let a: Array2<$scalar> = random_hermite(4);
+                let x: Array1<$scalar> = random(4);
+                let b = a.dot(&x);
+                let result = a.solveh_expert(&b).unwrap();
+                assert_close_l2!(&result.solution, &x, $rtol);
+                assert_aclose!(result.rcond, a.rcondh().unwrap(), 0.5);
+                assert!(result.row_scale.is_none() && result.col_scale.is_none());
+            }
+        }
+    };
+}
+
+impl_test!(f64, 1e-9);
+impl_test!(f32, 1e-3);
+impl_test!(c64, 1e-9);
+impl_test!(c32, 1e-3);
+
+#[test]
+fn solve_expert_badly_scaled() {
+    let a: Array2<f64> = array![[1e10, 2e10], [3.0, 4.0]];
+    let x: Array1<f64> = array![1.0, -1.0];
+    let b = a.dot(&x);
+    let result = a.solve_expert(&b, true).unwrap();
+    assert_close_l2!(&result.solution, &x, 1e-9);
+    let r = result.row_scale.unwrap();
+    assert!(r[0] < r[1]);
+}
+
+#[test]
+fn solve_expert_singular() {
+    let a: Array2<f64> = array![[1.0, 2.0], [2.0, 4.0]];
+    let b: Array1<f64> = array![1.0, 2.0];
+    assert!(a.solve_expert(&b, false).is_err());
+}
+
+#[test]
+fn solvec_expert_not_positive_definite() {
+    let a: Array2<f64> = array![[1.0, 2.0], [2.0, 1.0]];
+    let b: Array1<f64> = array![1.0, 2.0];
+    assert!(a.solvec_expert(&b, false).is_err());
+}
@@ -0,0 +1,66 @@
+use ndarray::*;
+use ndarray_linalg::*;
+
+fn assert_orthonormal<T: Scalar + Lapack>(q: &Array2<T>, rtol: T::Real) {
+    let k = q.ncols();
+    let qhq = q.t().mapv(|x| x.conj()).dot(q);
+    assert_close_l2!(&qhq, &Array::eye(k), rtol);
+}
+
+fn test<T: Scalar + Lapack>(a: &Array2<T>, rank: usize, method: SubspaceMethod, rtol: T::Real) {
+    let (m, n) = a.dim();
+
+    let (o, r) = a.orth(method, Cutoff::Relative(T::real(1e-4))).unwrap();
+    assert_eq!(r, rank);
+    assert_eq!(o.dim(), (m, rank));
+    assert_orthonormal(&o, rtol);
+    // A = O O^H A
+    let oh = o.t().mapv(|x| x.conj());
+    assert_close_l2!(&o.dot(&oh).dot(a), a, rtol);
+
+    let (z, r) = a
+        .null_space(method, Cutoff::Relative(T::real(1e-4)))
+        .unwrap();
+    assert_eq!(r, rank);
+    assert_eq!(z.dim(), (n, n - rank));
+    assert_orthonormal(&z, rtol);
+    assert!(a.dot(&z).norm_max() < rtol * a.norm_max());
+
+    let (y, r) = a
+        .left_null_space(method, Cutoff::Relative(T::real(1e-4)))
+        .unwrap();
+    assert_eq!(r, rank);
+    assert_eq!(y.dim(), (m, m - rank));
+    assert_orthonormal(&y, rtol);
+    assert!(y.t().mapv(|x| x.conj()).dot(a).norm_max() < rtol * a.norm_max());
+}
+
+macro_rules! impl_test {
+    ($scalar:ty, $rtol:expr) => {
+        paste::item! {
+            #[test]
+            fn [<subspace_full_rank_ $scalar>]() {
+                let a: Array2<$scalar> = random((4, 3));
+                test(&a, 3, SubspaceMethod::SVD, $rtol);
+                test(&a, 3, SubspaceMethod::QR, $rtol);
+            }
+
+            #[test]
+            fn [<subspace_rank_deficient_ $scalar>]() {
+                let b: Array2<$scalar> = random((5, 2));
+                let c: Array2<$scalar> = random((2, 4));
+                let a = b.dot(&c);
+                test(&a, 2, SubspaceMethod::SVD, $rtol);
+                test(&a, 2, SubspaceMethod::QR, $rtol);
+                let a_t = a.t().to_owned();
+                test(&a_t, 2, SubspaceMethod::SVD, $rtol);
+                test(&a_t, 2, SubspaceMethod::QR, $rtol);
+            }
+        }
+    };
+}
+
+impl_test!(f32, 1e-3);
+impl_test!(f64, 1e-9);
+impl_test!(c32, 1e-3);
+impl_test!(c64, 1e-9);
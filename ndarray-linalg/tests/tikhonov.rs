@@ -0,0 +1,160 @@
+use ndarray::*;
+use ndarray_linalg::*;
+
+/// First-order difference operator of size `(n - 1) x n`
+fn difference<T: Scalar>(n: usize) -> Array2<T> {
+    Array2::from_shape_fn((n - 1, n), |(i, j)| {
+        if j == i {
+            -T::one()
+        } else if j == i + 1 {
+            T::one()
+        } else {
+            T::zero()
+        }
+    })
+}
+
+/// Ill-conditioned matrix of the discretized integral operator, and noisy data of a smooth solution
+fn ill_posed(n: usize) -> (Array2<f64>, Array1<f64>) {
+    let a = Array2::from_shape_fn((n, n), |(i, j)| 1.0 / (i + j + 1) as f64);
+    let x = Array1::from_shape_fn(n, |i| (i as f64 / n as f64 * 3.0).sin());
+    let noise: Array1<f64> = random(n);
+    let b = a.dot(&x) + noise * 1e-4;
+    (a, b)
+}
+
+macro_rules! tikhonov {
+    ($elem:ty, $rtol:expr) => {
+        paste::item! {
+            #[test]
+            fn [<tikhonov_ $elem>]() {
+                let a: Array2<$elem> = random((10, 4));
+                let b: Array1<$elem> = random(10);
+                let tikhonov = a.factorize_tikhonov().unwrap();
+
+                // (A^H A + lambda^2 I) x = A^H b
+                let lambda = 0.5;
+                let result = tikhonov.solve(&b, lambda).unwrap();
+                let ah: Array2<$elem> = conjugate(&a);
+                let mut normal = ah.dot(&a);
+                for i in 0..4 {
+                    normal[(i, i)] += <$elem>::from_real(lambda * lambda);
+                }
+                let expected = normal.solve(&ah.dot(&b)).unwrap();
+                assert_close_l2!(&result.solution, &expected, $rtol);
+                assert_rclose!(result.residual_norm, (&b - &a.dot(&expected)).norm_l2(), $rtol);
+                assert_rclose!(result.solution_norm, expected.norm_l2(), $rtol);
+
+                // No regularization is the least squares
+                let result = tikhonov.solve(&b, 0.0).unwrap();
+                let expected = a.least_squares(&b).unwrap();
+                assert_close_l2!(&result.solution, &expected.solution, $rtol);
+            }
+
+            #[test]
+            fn [<tikhonov_path_ $elem>]() {
+                let a: Array2<$elem> = random((6, 8));
+                let b: Array1<$elem> = random(6);
+                let tikhonov = a.factorize_tikhonov().unwrap();
+                let lambdas = [1e-3, 1e-1, 1.0, 10.0];
+                let path = tikhonov.solve_path(&b, &lambdas).unwrap();
+                assert_eq!(path.len(), lambdas.len());
+                for (result, &lambda) in path.iter().zip(&lambdas) {
+                    let expected = tikhonov.solve(&b, lambda).unwrap();
+                    assert_eq!(result.lambda, lambda);
+                    assert_close_l2!(&result.solution, &expected.solution, $rtol);
+                }
+                // The residual increases and the solution norm decreases along the path
+                for pair in path.windows(2) {
+                    assert!(pair[0].residual_norm < pair[1].residual_norm);
+                    assert!(pair[0].solution_norm > pair[1].solution_norm);
+                }
+            }
+
+            #[test]
+            fn [<tikhonov_general_ $elem>]() {
+                let a: Array2<$elem> = random((10, 5));
+                let b: Array1<$elem> = random(10);
+                let l: Array2<$elem> = difference(5);
+                let tikhonov = a.factorize_tikhonov_general(&l).unwrap();
+
+                // Least squares of the stacked system [A; lambda L] x = [b; 0]
+                let lambda = 0.7;
+                let result = tikhonov.solve(&b, lambda).unwrap();
+                let mut stacked = Array2::zeros((14, 5));
+                stacked.slice_mut(s![..10, ..]).assign(&a);
+                stacked
+                    .slice_mut(s![10.., ..])
+                    .assign(&l.mapv(|x| x * <$elem>::from_real(lambda)));
+                let mut rhs = Array1::zeros(14);
+                rhs.slice_mut(s![..10]).assign(&b);
+                let expected = stacked.least_squares(&rhs).unwrap().solution;
+                assert_close_l2!(&result.solution, &expected, $rtol);
+                assert_rclose!(result.residual_norm, (&b - &a.dot(&expected)).norm_l2(), $rtol);
+                assert_rclose!(result.solution_norm, l.dot(&expected).norm_l2(), $rtol);
+
+                // Identity is the standard form
+                let eye: Array2<$elem> = Array2::eye(5);
+                let general = a.factorize_tikhonov_general(&eye).unwrap();
+                let standard = a.factorize_tikhonov().unwrap();
+                assert_close_l2!(
+                    &general.solve(&b, lambda).unwrap().solution,
+                    &standard.solve(&b, lambda).unwrap().solution,
+                    $rtol
+                );
+            }
+        }
+    };
+}
+
+tikhonov!(f64, 1e-7);
+tikhonov!(c64, 1e-7);
+
+#[test]
+fn tikhonov_gcv() {
+    let (a, b) = ill_posed(12);
+    let tikhonov = a.factorize_tikhonov().unwrap();
+    let result = tikhonov
+        .solve_auto(&b, TikhonovParameterChoice::GeneralizedCrossValidation)
+        .unwrap();
+    let gcv = tikhonov.gcv(&b, result.lambda).unwrap();
+    for k in -12..=0 {
+        let lambda = 10f64.powi(k);
+        assert!(gcv <= tikhonov.gcv(&b, lambda).unwrap() * (1.0 + 1e-2));
+    }
+}
+
+#[test]
+fn tikhonov_l_curve() {
+    let (a, b) = ill_posed(12);
+    let l: Array2<f64> = difference(12);
+    for tikhonov in &[
+        a.factorize_tikhonov().unwrap(),
+        a.factorize_tikhonov_general(&l).unwrap(),
+    ] {
+        let result = tikhonov
+            .solve_auto(&b, TikhonovParameterChoice::LCurve)
+            .unwrap();
+        let s = tikhonov.singular_values();
+        assert!(result.lambda <= s[0]);
+        assert!(result.lambda >= s[s.len() - 1]);
+        let curvature = tikhonov.l_curve_curvature(&b, result.lambda).unwrap();
+        for k in -12..=0 {
+            let lambda = 10f64.powi(k);
+            if lambda >= s[s.len() - 1] {
+                let other = tikhonov.l_curve_curvature(&b, lambda).unwrap();
+                assert!(curvature >= other - 1e-2 * other.abs());
+            }
+        }
+    }
+}
+
+#[test]
+fn tikhonov_shape_error() {
+    let a: Array2<f64> = random((6, 4));
+    let b: Array1<f64> = random(5);
+    let tikhonov = a.factorize_tikhonov().unwrap();
+    assert!(tikhonov.solve(&b, 1.0).is_err());
+    let l: Array2<f64> = random((3, 5));
+    assert!(a.factorize_tikhonov_general(&l).is_err());
+}
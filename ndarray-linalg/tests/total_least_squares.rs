@@ -0,0 +1,86 @@
+use ndarray::*;
+use ndarray_linalg::error::LinalgError;
+use ndarray_linalg::*;
+
+macro_rules! total_least_squares {
+    ($elem:ty, $rtol:expr) => {
+        paste::item! {
+            #[test]
+            fn [<total_least_squares_exact_ $elem>]() {
+                let a: Array2<$elem> = random((6, 3));
+                let x: Array1<$elem> = random(3);
+                let result = a.total_least_squares(&a.dot(&x)).unwrap();
+                assert_close_l2!(&result.solution, &x, $rtol);
+                assert!(result.correction_norm < $rtol);
+                assert_eq!(result.rank, 3);
+
+                let x: Array2<$elem> = random((3, 2));
+                let result = a.total_least_squares(&a.dot(&x)).unwrap();
+                assert_close_l2!(&result.solution, &x, $rtol);
+            }
+
+            #[test]
+            fn [<total_least_squares_ $elem>]() {
+                let a: Array2<$elem> = random((8, 3));
+                let b: Array1<$elem> = random(8);
+                let result = a.total_least_squares(&b).unwrap();
+
+                // x = (A^H A - s_{n+1}^2 I)^{-1} A^H b
+                let s = result.singular_values[3];
+                let ah: Array2<$elem> = conjugate(&a);
+                let mut normal = ah.dot(&a);
+                for i in 0..3 {
+                    normal[(i, i)] -= <$elem>::from_real(s * s);
+                }
+                let expected = normal.solve(&ah.dot(&b)).unwrap();
+                assert_close_l2!(&result.solution, &expected, $rtol);
+                assert_rclose!(result.correction_norm, s, $rtol);
+
+                // A single column of the matrix right-hand side is the same
+                let b2 = b.clone().insert_axis(Axis(1));
+                let result2 = a.total_least_squares(&b2).unwrap();
+                assert_close_l2!(&result2.solution.column(0), &result.solution, $rtol);
+            }
+
+            #[test]
+            fn [<total_least_squares_truncated_ $elem>]() {
+                // The third column is the sum of the others
+                let mut a: Array2<$elem> = random((6, 3));
+                let col = &a.column(0) + &a.column(1);
+                a.column_mut(2).assign(&col);
+                let x: Array1<$elem> = random(3);
+                let b = a.dot(&x);
+
+                // The minimum norm solution of the consistent system
+                let result = a.total_least_squares_truncated(&b, 2).unwrap();
+                let expected = a.least_squares(&b).unwrap().solution;
+                assert_close_l2!(&result.solution, &expected, $rtol);
+                assert!(result.correction_norm < $rtol);
+                assert_close_l2!(&a.dot(&result.solution), &b, $rtol);
+            }
+        }
+    };
+}
+
+total_least_squares!(f64, 1e-9);
+total_least_squares!(c64, 1e-9);
+
+#[test]
+fn total_least_squares_not_exist() {
+    // The column of b is orthogonal to A, and the smallest singular vector has no b component
+    let a: Array2<f64> = array![[1.0, 0.0], [0.0, 0.0], [0.0, 0.0]];
+    let b = array![0.0, 1.0, 0.0];
+    match a.total_least_squares(&b) {
+        Err(LinalgError::TotalLeastSquaresNotExist) => {}
+        _ => panic!("Total least squares solution must not exist"),
+    }
+}
+
+#[test]
+fn total_least_squares_shape_error() {
+    let a: Array2<f64> = random((6, 3));
+    let b: Array1<f64> = random(5);
+    assert!(a.total_least_squares(&b).is_err());
+    let b: Array1<f64> = random(6);
+    assert!(a.total_least_squares_truncated(&b, 4).is_err());
+}
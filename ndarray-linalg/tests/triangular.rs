@@ -118,3 +118,40 @@ fn triangular_2d_upper_t_bt() {
     let a: Array2<f64> = random((3, 3).f()).into_triangular(UPLO::Upper);
     test2d(UPLO::Upper, &a, &b, 1e-7);
 }
+
+#[test]
+fn rcond_triangular() {
+    macro_rules! rcond_triangular {
+        ($elem:ty, $rows:expr, $atol:expr) => {
+            let a: Array2<$elem> = random(($rows, $rows));
+            let a = a + Array2::<$elem>::eye($rows);
+            for &uplo in &[UPLO::Upper, UPLO::Lower] {
+                let t = a.clone().into_triangular(uplo);
+                let rcond = 1. / (t.opnorm_one().unwrap() * t.inv().unwrap().opnorm_one().unwrap());
+                assert_aclose!(
+                    t.rcond_triangular(uplo, Diag::NonUnit).unwrap(),
+                    rcond,
+                    $atol
+                );
+                // only the portion specified by `uplo` is used
+                assert_aclose!(
+                    a.rcond_triangular(uplo, Diag::NonUnit).unwrap(),
+                    rcond,
+                    $atol
+                );
+                let t_t = t.t().as_standard_layout().to_owned();
+                assert_aclose!(
+                    t_t.rcond_triangular(uplo.t(), Diag::NonUnit).unwrap(),
+                    1. / (t_t.opnorm_one().unwrap() * t_t.inv().unwrap().opnorm_one().unwrap()),
+                    $atol
+                );
+            }
+        };
+    }
+    for rows in 1..6 {
+        rcond_triangular!(f64, rows, 0.2);
+        rcond_triangular!(f32, rows, 0.5);
+        rcond_triangular!(c64, rows, 0.2);
+        rcond_triangular!(c32, rows, 0.5);
+    }
+}